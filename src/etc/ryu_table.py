@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+"""
+Generate the tables of powers of five used by the Ryu shortest-representation
+float formatting strategy (`libcore/num/flt2dec/strategy/ryu.rs`).
+
+Two tables are emitted, both holding 125-bit approximations as `u128`:
+
+- `POW5_SPLIT[i]` holds the 125 most significant bits of `5^i`, rounded down.
+  It is used for negative binary exponents.
+
+- `POW5_INV_SPLIT[q]` holds `floor(2^(pow5bits(q) - 1 + 125) / 5^q) + 1`,
+  a slightly overestimated inverse of `5^q`. It is used for non-negative
+  binary exponents.
+
+The table sizes cover the full range of finite `f64` values, which is a
+superset of the `f32` range.
+"""
+from __future__ import print_function
+
+POW5_BITCOUNT = 125
+POW5_INV_BITCOUNT = 125
+
+# See `ryu.rs` for the derivation of these bounds.
+POW5_SPLIT_LEN = 326
+POW5_INV_SPLIT_LEN = 292
+
+
+HEADER = """
+//! Tables of approximations of powers of five.
+//! DO NOT MODIFY: Generated by `src/etc/ryu_table.py`
+"""
+
+
+def main():
+    print(HEADER.strip())
+    print()
+    print("pub const POW5_BITCOUNT: i32 = {};".format(POW5_BITCOUNT))
+    print("pub const POW5_INV_BITCOUNT: i32 = {};".format(POW5_INV_BITCOUNT))
+    print()
+    print_pow5_split()
+    print()
+    print_pow5_inv_split()
+
+
+def print_pow5_split():
+    print("#[rustfmt::skip]")
+    print("pub const POW5_SPLIT: [u128; {}] = [".format(POW5_SPLIT_LEN))
+    for i in range(POW5_SPLIT_LEN):
+        pow5 = 5 ** i
+        shift = pow5.bit_length() - POW5_BITCOUNT
+        value = pow5 >> shift if shift >= 0 else pow5 << -shift
+        assert value.bit_length() == POW5_BITCOUNT
+        print("    0x{:032x},".format(value))
+    print("];")
+
+
+def print_pow5_inv_split():
+    print("#[rustfmt::skip]")
+    print("pub const POW5_INV_SPLIT: [u128; {}] = [".format(POW5_INV_SPLIT_LEN))
+    for i in range(POW5_INV_SPLIT_LEN):
+        pow5 = 5 ** i
+        shift = pow5.bit_length() - 1 + POW5_INV_BITCOUNT
+        value = (1 << shift) // pow5 + 1
+        assert value.bit_length() <= POW5_INV_BITCOUNT + 1
+        print("    0x{:032x},".format(value))
+    print("];")
+
+
+if __name__ == '__main__':
+    main()
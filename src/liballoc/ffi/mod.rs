@@ -0,0 +1,15 @@
+//! Utilities related to FFI bindings.
+//!
+//! This module provides the owned counterpart of [`core::ffi::CStr`],
+//! [`CString`], along with the errors produced when building one. They are
+//! re-exported from `std::ffi`, which is where most users will find them.
+//!
+//! [`core::ffi::CStr`]: ../../core/ffi/struct.CStr.html
+//! [`CString`]: struct.CString.html
+
+#![unstable(feature = "alloc_c_string", issue = "none")]
+
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use self::c_str::{CString, IntoStringError, NulError};
+
+mod c_str;
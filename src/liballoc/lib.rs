@@ -77,6 +77,7 @@
 #![feature(allocator_api)]
 #![feature(allow_internal_unstable)]
 #![feature(arbitrary_self_types)]
+#![feature(async_stream)]
 #![feature(box_patterns)]
 #![feature(box_syntax)]
 #![feature(cfg_sanitize)]
@@ -92,6 +93,10 @@
 #![feature(core_intrinsics)]
 #![feature(container_error_extra)]
 #![feature(dropck_eyepatch)]
+#![feature(error_generic_member_access)]
+#![feature(error_in_core)]
+#![feature(c_char_in_core)]
+#![feature(c_str_in_core)]
 #![feature(exact_size_is_empty)]
 #![feature(extend_one)]
 #![feature(fmt_internals)]
@@ -121,6 +126,7 @@
 #![feature(unsize)]
 #![feature(unsized_locals)]
 #![feature(allocator_internals)]
+#![feature(slice_internals)]
 #![feature(slice_partition_dedup)]
 #![feature(maybe_uninit_extra, maybe_uninit_slice)]
 #![feature(alloc_layout_extra)]
@@ -156,6 +162,7 @@ mod boxed {
 }
 pub mod borrow;
 pub mod collections;
+pub mod ffi;
 pub mod fmt;
 pub mod prelude;
 pub mod raw_vec;
@@ -0,0 +1,700 @@
+//! Interfaces for working with Errors.
+//!
+//! The [`Error`] trait lives here rather than in `std` so that `#![no_std]`
+//! crates can define and consume errors too. `std::error` re-exports
+//! everything in this module.
+//!
+//! [`Error`]: trait.Error.html
+
+#![unstable(feature = "error_in_core", issue = "none")]
+
+// A note about crates and the facade:
+//
+// For a long time the `Error` trait was defined in libstd, because the
+// blanket `From` impls for `Box<dyn Error>` in liballoc need to know that
+// `&str: !Error`, and coherence does not allow that kind of negative
+// reasoning about a trait from another crate. `#[rustc_strict_coherence]`
+// turns the negative impl for `&str` below into a promise that libcore will
+// never implement the trait for it, which coherence lets liballoc rely on.
+// The impls that need a heap (`Box`, `String`, ...) live next to those types
+// in liballoc.
+
+use crate::alloc::{AllocErr, LayoutErr};
+use crate::any::TypeId;
+use crate::array;
+use crate::cell;
+use crate::char;
+use crate::convert::Infallible;
+use crate::fmt::{self, Debug, Display};
+use crate::num;
+use crate::str;
+
+/// `Error` is a trait representing the basic expectations for error values,
+/// i.e., values of type `E` in [`Result<T, E>`]. Errors must describe
+/// themselves through the [`Display`] and [`Debug`] traits, and may provide
+/// cause chain information:
+///
+/// The [`source`] method is generally used when errors cross "abstraction
+/// boundaries". If one module must report an error that is caused by an error
+/// from a lower-level module, it can allow access to that error via the
+/// [`source`] method. This makes it possible for the high-level module to
+/// provide its own errors while also revealing some of the implementation for
+/// debugging via [`source`] chains.
+///
+/// [`Result<T, E>`]: ../result/enum.Result.html
+/// [`Display`]: ../fmt/trait.Display.html
+/// [`Debug`]: ../fmt/trait.Debug.html
+/// [`source`]: trait.Error.html#method.source
+#[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), lang = "error")]
+#[cfg_attr(not(bootstrap), rustc_strict_coherence)]
+#[cfg_attr(bootstrap, fundamental)]
+pub trait Error: Debug + Display {
+    /// The lower-level source of this error, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct SuperError {
+    ///     side: SuperErrorSideKick,
+    /// }
+    ///
+    /// impl fmt::Display for SuperError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "SuperError is here!")
+    ///     }
+    /// }
+    ///
+    /// impl Error for SuperError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.side)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct SuperErrorSideKick;
+    ///
+    /// impl fmt::Display for SuperErrorSideKick {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "SuperErrorSideKick is here!")
+    ///     }
+    /// }
+    ///
+    /// impl Error for SuperErrorSideKick {}
+    ///
+    /// fn get_super_error() -> Result<(), SuperError> {
+    ///     Err(SuperError { side: SuperErrorSideKick })
+    /// }
+    ///
+    /// fn main() {
+    ///     match get_super_error() {
+    ///         Err(e) => {
+    ///             println!("Error: {}", e);
+    ///             println!("Caused by: {}", e.source().unwrap());
+    ///         }
+    ///         _ => println!("No error"),
+    ///     }
+    /// }
+    /// ```
+    #[stable(feature = "error_source", since = "1.30.0")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    /// Gets the `TypeId` of `self`.
+    #[doc(hidden)]
+    #[unstable(
+        feature = "error_type_id",
+        reason = "this is memory-unsafe to override in user code",
+        issue = "60784"
+    )]
+    fn type_id(&self, _: private::Internal) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<Self>()
+    }
+
+    /// Provides type based access to context intended for error reports.
+    ///
+    /// Used in conjunction with [`request_ref`] and [`request_value`] to
+    /// extract references to member variables from `dyn Error` trait
+    /// objects, or values computed from them, without downcasting to the
+    /// concrete error type.
+    ///
+    /// Implementations should call one of the `provide_*` methods of
+    /// [`Request`] for each kind of value they can supply. Only the first
+    /// value provided for a given type is kept.
+    ///
+    /// [`request_ref`]: trait.Error.html#method.request_ref
+    /// [`request_value`]: trait.Error.html#method.request_value
+    /// [`Request`]: struct.Request.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(backtrace, error_generic_member_access)]
+    /// use std::backtrace::Backtrace;
+    /// use std::error::{Error, Request};
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct MyError {
+    ///     status: u16,
+    ///     backtrace: Backtrace,
+    /// }
+    ///
+    /// impl fmt::Display for MyError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "request failed with status {}", self.status)
+    ///     }
+    /// }
+    ///
+    /// impl Error for MyError {
+    ///     fn provide<'a>(&'a self, request: &mut Request<'a>) {
+    ///         request.provide_ref::<Backtrace>(&self.backtrace).provide_value::<u16>(self.status);
+    ///     }
+    /// }
+    ///
+    /// let err = MyError { status: 404, backtrace: Backtrace::capture() };
+    /// let dyn_err = &err as &dyn Error;
+    /// assert_eq!(dyn_err.request_value::<u16>(), Some(404));
+    /// assert!(dyn_err.request_ref::<Backtrace>().is_some());
+    /// assert!(dyn_err.request_ref::<str>().is_none());
+    /// ```
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    #[allow(unused_variables)]
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {}
+
+    /// ```
+    /// if let Err(e) = "xc".parse::<u32>() {
+    ///     // Print `e` itself, no need for description().
+    ///     eprintln!("Error: {}", e);
+    /// }
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_deprecated(since = "1.42.0", reason = "use the Display impl or to_string()")]
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_deprecated(
+        since = "1.33.0",
+        reason = "replaced by Error::source, which can support downcasting"
+    )]
+    #[allow(missing_docs)]
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+mod private {
+    // This is a hack to prevent `type_id` from being overridden by `Error`
+    // implementations, since that can enable unsound downcasting.
+    #[unstable(feature = "error_type_id", issue = "60784")]
+    #[derive(Debug)]
+    pub struct Internal;
+}
+
+#[unstable(feature = "never_type", issue = "35121")]
+impl Error for ! {}
+
+// So that liballoc can rely on `&str: !Error`.
+#[unstable(feature = "error_in_core", issue = "none")]
+impl !Error for &str {}
+
+#[unstable(
+    feature = "allocator_api",
+    reason = "the precise API and guarantees it provides may be tweaked.",
+    issue = "32838"
+)]
+impl Error for AllocErr {}
+
+#[unstable(
+    feature = "allocator_api",
+    reason = "the precise API and guarantees it provides may be tweaked.",
+    issue = "32838"
+)]
+impl Error for LayoutErr {}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Error for str::ParseBoolError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "failed to parse bool"
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Error for str::Utf8Error {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "invalid utf-8: corrupt contents"
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Error for num::ParseIntError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+#[stable(feature = "try_from", since = "1.34.0")]
+impl Error for num::TryFromIntError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+#[stable(feature = "try_from", since = "1.34.0")]
+impl Error for array::TryFromSliceError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Error for num::ParseFloatError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+#[stable(feature = "str_parse_error2", since = "1.8.0")]
+impl Error for Infallible {
+    fn description(&self) -> &str {
+        match *self {}
+    }
+}
+
+#[stable(feature = "decode_utf16", since = "1.9.0")]
+impl Error for char::DecodeUtf16Error {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "unpaired surrogate found"
+    }
+}
+
+#[stable(feature = "fmt_error", since = "1.11.0")]
+impl Error for fmt::Error {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "an error occurred when formatting an argument"
+    }
+}
+
+#[stable(feature = "try_borrow", since = "1.13.0")]
+impl Error for cell::BorrowError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "already mutably borrowed"
+    }
+}
+
+#[stable(feature = "try_borrow", since = "1.13.0")]
+impl Error for cell::BorrowMutError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "already borrowed"
+    }
+}
+
+#[stable(feature = "try_from", since = "1.34.0")]
+impl Error for char::CharTryFromError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "converted integer out of range for `char`"
+    }
+}
+
+#[stable(feature = "char_from_str", since = "1.20.0")]
+impl Error for char::ParseCharError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+// Copied from `any.rs`.
+// Copied from `any.rs`.
+impl dyn Error + 'static {
+    /// Returns `true` if the boxed type is the same as `T`
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn is<T: Error + 'static>(&self) -> bool {
+        // Get `TypeId` of the type this function is instantiated with.
+        let t = TypeId::of::<T>();
+
+        // Get `TypeId` of the type in the trait object.
+        let boxed = self.type_id(private::Internal);
+
+        // Compare both `TypeId`s on equality.
+        t == boxed
+    }
+
+    /// Returns some reference to the boxed value if it is of type `T`, or
+    /// `None` if it isn't.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        if self.is::<T>() {
+            unsafe { Some(&*(self as *const dyn Error as *const T)) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns some mutable reference to the boxed value if it is of type `T`, or
+    /// `None` if it isn't.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn downcast_mut<T: Error + 'static>(&mut self) -> Option<&mut T> {
+        if self.is::<T>() {
+            unsafe { Some(&mut *(self as *mut dyn Error as *mut T)) }
+        } else {
+            None
+        }
+    }
+
+    /// Requests a reference of type `T` from this error through
+    /// [`provide`], returning `None` if it does not provide one.
+    ///
+    /// Only this error is asked. To search its sources as well, combine
+    /// this with [`chain`], as in `err.chain().find_map(|e| e.request_ref::<T>())`.
+    ///
+    /// [`provide`]: trait.Error.html#method.provide
+    /// [`chain`]: trait.Error.html#method.chain
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        request_by_type_tag::<tags::Ref<T>, Self>(self)
+    }
+
+    /// Requests a value of type `T` from this error through [`provide`],
+    /// returning `None` if it does not provide one.
+    ///
+    /// [`provide`]: trait.Error.html#method.provide
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        request_by_type_tag::<tags::Value<T>, Self>(self)
+    }
+}
+
+impl dyn Error + 'static + Send {
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn is<T: Error + 'static>(&self) -> bool {
+        <dyn Error + 'static>::is::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        <dyn Error + 'static>::downcast_ref::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn downcast_mut<T: Error + 'static>(&mut self) -> Option<&mut T> {
+        <dyn Error + 'static>::downcast_mut::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        <dyn Error + 'static>::request_ref::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        <dyn Error + 'static>::request_value::<T>(self)
+    }
+}
+
+impl dyn Error + 'static + Send + Sync {
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn is<T: Error + 'static>(&self) -> bool {
+        <dyn Error + 'static>::is::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        <dyn Error + 'static>::downcast_ref::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[stable(feature = "error_downcast", since = "1.3.0")]
+    #[inline]
+    pub fn downcast_mut<T: Error + 'static>(&mut self) -> Option<&mut T> {
+        <dyn Error + 'static>::downcast_mut::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        <dyn Error + 'static>::request_ref::<T>(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        <dyn Error + 'static>::request_value::<T>(self)
+    }
+}
+
+impl dyn Error {
+    /// Returns an iterator starting with the current error and continuing with
+    /// recursively calling [`source`].
+    ///
+    /// If you want to omit the current error and only use its sources,
+    /// use `skip(1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(error_iter)]
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct A;
+    ///
+    /// #[derive(Debug)]
+    /// struct B(Option<Box<dyn Error + 'static>>);
+    ///
+    /// impl fmt::Display for A {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "A")
+    ///     }
+    /// }
+    ///
+    /// impl fmt::Display for B {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "B")
+    ///     }
+    /// }
+    ///
+    /// impl Error for A {}
+    ///
+    /// impl Error for B {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         self.0.as_ref().map(|e| e.as_ref())
+    ///     }
+    /// }
+    ///
+    /// let b = B(Some(Box::new(A)));
+    ///
+    /// // let err : Box<Error> = b.into(); // or
+    /// let err = &b as &(dyn Error);
+    ///
+    /// let mut iter = err.chain();
+    ///
+    /// assert_eq!("B".to_string(), iter.next().unwrap().to_string());
+    /// assert_eq!("A".to_string(), iter.next().unwrap().to_string());
+    /// assert!(iter.next().is_none());
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// [`source`]: trait.Error.html#method.source
+    #[unstable(feature = "error_iter", issue = "58520")]
+    #[inline]
+    pub fn chain(&self) -> Chain<'_> {
+        Chain { current: Some(self) }
+    }
+}
+
+/// An iterator over an [`Error`] and its sources.
+///
+/// If you want to omit the initial error and only process
+/// its sources, use `skip(1)`.
+///
+/// [`Error`]: trait.Error.html
+#[unstable(feature = "error_iter", issue = "58520")]
+#[derive(Clone, Debug)]
+pub struct Chain<'a> {
+    current: Option<&'a (dyn Error + 'static)>,
+}
+
+#[unstable(feature = "error_iter", issue = "58520")]
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current;
+        self.current = self.current.and_then(Error::source);
+        current
+    }
+}
+
+fn request_by_type_tag<'a, I, E>(err: &'a E) -> Option<I::Reified>
+where
+    I: tags::Type<'a>,
+    E: Error + ?Sized,
+{
+    let mut tagged = TaggedOption::<'a, I>(None);
+    err.provide(Request::new(&mut tagged));
+    tagged.0
+}
+
+/// A request for a value or reference of a single type from an error.
+///
+/// A `Request` is passed to [`Error::provide`]. It is type-erased: the
+/// `provide_*` methods accept values of any type, but only a value of the
+/// type that was actually requested is kept, and the rest are ignored.
+///
+/// [`Error::provide`]: trait.Error.html#method.provide
+#[unstable(feature = "error_generic_member_access", issue = "none")]
+#[repr(transparent)]
+pub struct Request<'a>(dyn Erased<'a> + 'a);
+
+impl<'a> Request<'a> {
+    fn new<'b>(erased: &'b mut (dyn Erased<'a> + 'a)) -> &'b mut Request<'a> {
+        // SAFETY: `Request` is `repr(transparent)` over `dyn Erased<'a>`, so
+        // the two have the same layout and metadata.
+        unsafe { &mut *(erased as *mut dyn Erased<'a> as *mut Request<'a>) }
+    }
+
+    /// Provides a reference of type `T`, if one was requested.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn provide_ref<T: ?Sized + 'static>(&mut self, value: &'a T) -> &mut Self {
+        self.provide::<tags::Ref<T>>(value)
+    }
+
+    /// Provides a reference of type `T` computed by `fulfil`, if one was
+    /// requested.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn provide_ref_with<T: ?Sized + 'static>(
+        &mut self,
+        fulfil: impl FnOnce() -> &'a T,
+    ) -> &mut Self {
+        self.provide_with::<tags::Ref<T>, _>(fulfil)
+    }
+
+    /// Provides a value of type `T`, if one was requested.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn provide_value<T: 'static>(&mut self, value: T) -> &mut Self {
+        self.provide::<tags::Value<T>>(value)
+    }
+
+    /// Provides a value of type `T` computed by `fulfil`, if one was
+    /// requested. `fulfil` is not called otherwise, so this should be
+    /// preferred when computing the value is expensive.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn provide_value_with<T: 'static>(&mut self, fulfil: impl FnOnce() -> T) -> &mut Self {
+        self.provide_with::<tags::Value<T>, _>(fulfil)
+    }
+
+    /// Returns whether this request still wants a reference of type `T`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn would_be_satisfied_by_ref_of<T: ?Sized + 'static>(&self) -> bool {
+        self.would_be_satisfied_by::<tags::Ref<T>>()
+    }
+
+    /// Returns whether this request still wants a value of type `T`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn would_be_satisfied_by_value_of<T: 'static>(&self) -> bool {
+        self.would_be_satisfied_by::<tags::Value<T>>()
+    }
+
+    fn provide<I: tags::Type<'a>>(&mut self, value: I::Reified) -> &mut Self {
+        self.provide_with::<I, _>(|| value)
+    }
+
+    fn provide_with<I, F>(&mut self, fulfil: F) -> &mut Self
+    where
+        I: tags::Type<'a>,
+        F: FnOnce() -> I::Reified,
+    {
+        if let Some(res) = self.0.downcast_mut::<I>() {
+            if res.0.is_none() {
+                res.0 = Some(fulfil());
+            }
+        }
+        self
+    }
+
+    fn would_be_satisfied_by<I: tags::Type<'a>>(&self) -> bool {
+        self.0.downcast::<I>().map_or(false, |res| res.0.is_none())
+    }
+}
+
+#[unstable(feature = "error_generic_member_access", issue = "none")]
+impl fmt::Debug for Request<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Request").finish()
+    }
+}
+
+// Type tags identify the type a `Request` was made for. Tags are always
+// `'static`, so their `TypeId` can be compared, while `Reified` names the
+// actual (possibly borrowed) type carried for the lifetime `'a`.
+mod tags {
+    use crate::marker::PhantomData;
+
+    pub(super) trait Type<'a>: Sized + 'static {
+        type Reified: 'a;
+    }
+
+    pub(super) struct Ref<T: ?Sized + 'static>(PhantomData<T>);
+
+    impl<'a, T: ?Sized + 'static> Type<'a> for Ref<T> {
+        type Reified = &'a T;
+    }
+
+    pub(super) struct Value<T: 'static>(PhantomData<T>);
+
+    impl<'a, T: 'static> Type<'a> for Value<T> {
+        type Reified = T;
+    }
+}
+
+// The slot a requested value is written into, tagged with the type it is for.
+#[repr(transparent)]
+struct TaggedOption<'a, I: tags::Type<'a>>(Option<I::Reified>);
+
+trait Erased<'a>: 'a {
+    fn tag_id(&self) -> TypeId;
+}
+
+impl<'a, I: tags::Type<'a>> Erased<'a> for TaggedOption<'a, I> {
+    fn tag_id(&self) -> TypeId {
+        TypeId::of::<I>()
+    }
+}
+
+impl<'a> dyn Erased<'a> + 'a {
+    fn downcast<I: tags::Type<'a>>(&self) -> Option<&TaggedOption<'a, I>> {
+        if self.tag_id() == TypeId::of::<I>() {
+            // SAFETY: The tag matches, so `self` is a `TaggedOption<'a, I>`.
+            Some(unsafe { &*(self as *const Self as *const TaggedOption<'a, I>) })
+        } else {
+            None
+        }
+    }
+
+    fn downcast_mut<I: tags::Type<'a>>(&mut self) -> Option<&mut TaggedOption<'a, I>> {
+        if self.tag_id() == TypeId::of::<I>() {
+            // SAFETY: The tag matches, so `self` is a `TaggedOption<'a, I>`.
+            Some(unsafe { &mut *(self as *mut Self as *mut TaggedOption<'a, I>) })
+        } else {
+            None
+        }
+    }
+}
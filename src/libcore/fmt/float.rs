@@ -1,6 +1,7 @@
 use crate::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
 use crate::mem::MaybeUninit;
 use crate::num::flt2dec;
+use crate::str;
 
 // Don't inline this so callers don't use the stack space this function
 // requires unless they have to.
@@ -54,7 +55,7 @@ where
         let mut parts = MaybeUninit::<[flt2dec::Part<'_>; 4]>::uninit();
         // FIXME(#53491)
         let formatted = flt2dec::to_shortest_str(
-            flt2dec::strategy::grisu::format_shortest,
+            flt2dec::strategy::ryu::format_shortest,
             *num,
             sign,
             precision,
@@ -140,7 +141,7 @@ where
         let mut parts = MaybeUninit::<[flt2dec::Part<'_>; 6]>::uninit();
         // FIXME(#53491)
         let formatted = flt2dec::to_shortest_exp_str(
-            flt2dec::strategy::grisu::format_shortest,
+            flt2dec::strategy::ryu::format_shortest,
             *num,
             sign,
             (0, 0),
@@ -205,3 +206,77 @@ macro_rules! floating {
 
 floating! { f32 }
 floating! { f64 }
+
+/// A stack buffer for formatting floating point numbers without a [`Formatter`].
+///
+/// The output is identical to the [`Debug`] output of the number: the shortest
+/// decimal representation that parses back to the same value, with at least one
+/// fractional digit. This is meant for hot logging and serialization paths where
+/// going through `write!` and a `Formatter` is undesirable.
+///
+/// [`Formatter`]: struct.Formatter.html
+/// [`Debug`]: trait.Debug.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(float_buffer)]
+/// use std::fmt::FloatBuffer;
+///
+/// let mut buf = FloatBuffer::new();
+/// assert_eq!(buf.format(0.3f64), "0.3");
+/// assert_eq!(buf.format(-1.0f32), "-1.0");
+/// assert_eq!(buf.format(f64::NAN), "NaN");
+/// ```
+#[unstable(feature = "float_buffer", issue = "none")]
+#[derive(Clone)]
+pub struct FloatBuffer {
+    bytes: [u8; FloatBuffer::CAPACITY],
+}
+
+impl FloatBuffer {
+    /// The longest possible output, `-0.000...0005` for the negative
+    /// smallest subnormal `f64`, which has 323 zeroes after the decimal point.
+    const CAPACITY: usize = 327;
+
+    /// Creates a new buffer.
+    #[unstable(feature = "float_buffer", issue = "none")]
+    #[inline]
+    pub const fn new() -> FloatBuffer {
+        FloatBuffer { bytes: [0; FloatBuffer::CAPACITY] }
+    }
+
+    /// Formats `v` into the buffer and returns the formatted string,
+    /// which borrows from the buffer.
+    #[unstable(feature = "float_buffer", issue = "none")]
+    pub fn format<T: flt2dec::DecodableFloat>(&mut self, v: T) -> &str {
+        let mut buf = [0; flt2dec::MAX_SIG_DIGITS];
+        let mut parts = [flt2dec::Part::Zero(0); 4];
+        let formatted = flt2dec::to_shortest_str(
+            flt2dec::strategy::ryu::format_shortest,
+            v,
+            flt2dec::Sign::MinusRaw,
+            1,
+            &mut buf,
+            &mut parts,
+        );
+        let len = formatted.write(&mut self.bytes).expect("`CAPACITY` is large enough");
+        // SAFETY: formatted parts only ever consist of ASCII bytes.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+}
+
+#[unstable(feature = "float_buffer", issue = "none")]
+impl Default for FloatBuffer {
+    #[inline]
+    fn default() -> FloatBuffer {
+        FloatBuffer::new()
+    }
+}
+
+#[unstable(feature = "float_buffer", issue = "none")]
+impl Debug for FloatBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad("FloatBuffer { .. }")
+    }
+}
@@ -1939,6 +1939,18 @@ extern "rust-intrinsic" {
     #[rustc_const_unstable(feature = "const_ptr_offset_from", issue = "none")]
     pub fn ptr_offset_from<T>(ptr: *const T, base: *const T) -> isize;
 
+    /// Allocates a block of memory at compile time.
+    /// At runtime, just returns a null pointer.
+    ///
+    /// # Safety
+    ///
+    /// - The `align` argument must be a power of two.
+    ///    - At compile time, a compile error occurs if this constraint is violated.
+    ///    - At runtime, it is not checked.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_heap", issue = "none")]
+    pub fn const_allocate(size: usize, align: usize) -> *mut u8;
+
     /// Internal hook used by Miri to implement unwinding.
     /// ICEs when encountered during non-Miri codegen.
     ///
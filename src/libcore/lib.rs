@@ -75,6 +75,8 @@
 #![feature(const_discriminant)]
 #![feature(const_if_match)]
 #![feature(const_loop)]
+#![feature(const_cstr_unchecked)]
+#![feature(const_raw_ptr_deref)]
 #![feature(const_checked_int_methods)]
 #![feature(const_euclidean_int_methods)]
 #![feature(const_overflowing_int_methods)]
@@ -118,6 +120,7 @@
 #![feature(std_internals)]
 #![feature(stmt_expr_attributes)]
 #![feature(track_caller)]
+#![feature(trait_alias)]
 #![feature(transparent_unions)]
 #![feature(unboxed_closures)]
 #![feature(unsized_locals)]
@@ -230,9 +233,11 @@ pub mod array;
 pub mod ascii;
 pub mod cell;
 pub mod char;
+pub mod error;
 pub mod ffi;
 #[cfg(not(test))] // See #65860
 pub mod iter;
+pub mod net;
 pub mod option;
 pub mod panic;
 pub mod panicking;
@@ -256,6 +261,7 @@ pub mod unicode;
 /* Async */
 #[cfg(not(test))] // See #65860
 pub mod future;
+pub mod stream;
 pub mod task;
 
 /* Heap memory allocator trait */
@@ -66,12 +66,18 @@ There are two classes of algorithms widely known to be correct.
   representation which is at least guaranteed to be shortest. The variant,
   Grisu3, actively detects if the resulting representation is incorrect.
 
-We implement both algorithms with necessary tweaks to suit our requirements.
+- The "Ryu" algorithm is described by Ulf Adams. It only handles the shortest
+  mode, but it computes the shortest representation directly from a table of
+  125-bit approximations of powers of five and never needs a fallback.
+
+We implement the first two algorithms with necessary tweaks to suit our requirements.
 In particular, published literatures are short of the actual implementation
 difficulties like how to avoid arithmetic overflows. Each implementation,
 available in `strategy::dragon` and `strategy::grisu` respectively,
 extensively describes all necessary justifications and many proofs for them.
 (It is still difficult to follow though. You have been warned.)
+Ryu is available in `strategy::ryu` and is what `Display` and `Debug` use
+for the shortest mode.
 
 Both implementations expose two public functions:
 
@@ -83,7 +89,8 @@ Both implementations expose two public functions:
 
 They try to fill the `u8` buffer with digits and returns the number of digits
 written and the exponent `k`. They are total for all finite `f32` and `f64`
-inputs (Grisu internally falls back to Dragon if necessary).
+inputs (Grisu internally falls back to Dragon if necessary). Ryu exposes
+a `format_shortest(decoded, buf)` function with the same contract.
 
 The rendered digits are formatted into the actual string form with
 four functions:
@@ -131,6 +138,7 @@ pub mod estimator;
 pub mod strategy {
     pub mod dragon;
     pub mod grisu;
+    pub mod ryu;
 }
 
 /// The minimum size of buffer necessary for the shortest mode.
@@ -446,7 +454,7 @@ fn determine_sign(sign: Sign, decoded: &FullDecoded, negative: bool) -> &'static
 /// (which can be an empty string if no sign is rendered).
 ///
 /// `format_shortest` should be the underlying digit-generation function.
-/// You probably would want `strategy::grisu::format_shortest` for this.
+/// You probably would want `strategy::ryu::format_shortest` for this.
 ///
 /// `frac_digits` can be less than the number of actual fractional digits in `v`;
 /// it will be ignored and full digits will be printed. It is only used to print
@@ -509,7 +517,7 @@ where
 /// an empty string if no sign is rendered).
 ///
 /// `format_shortest` should be the underlying digit-generation function.
-/// You probably would want `strategy::grisu::format_shortest` for this.
+/// You probably would want `strategy::ryu::format_shortest` for this.
 ///
 /// The `dec_bounds` is a tuple `(lo, hi)` such that the number is formatted
 /// as decimal only when `10^lo <= V < 10^hi`. Note that this is the *apparent* `V`
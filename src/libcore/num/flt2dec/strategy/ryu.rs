@@ -0,0 +1,209 @@
+//! Rust adaptation of the Ryu algorithm described in "Ryū: Fast Float-to-String
+//! Conversion"[^1]. It uses about 10KB of precomputed table and never needs a bignum
+//! fallback, so it is both faster and smaller in code than Grisu with Dragon.
+//!
+//! Only the shortest mode is provided; the exact and fixed modes still use Grisu.
+//!
+//! [^1]: Ulf Adams. 2018. Ryū: fast float-to-string conversion.
+//!   SIGPLAN Not. 53, 4 (June 2018), 270-282.
+
+use crate::num::flt2dec::{Decoded, MAX_SIG_DIGITS};
+
+use self::table::{POW5_BITCOUNT, POW5_INV_BITCOUNT, POW5_INV_SPLIT, POW5_SPLIT};
+
+#[doc(hidden)]
+pub mod table;
+
+/// Returns `floor(log_10(2^e))` for `0 <= e <= 1650`.
+#[doc(hidden)]
+pub fn log10_pow2(e: i32) -> u32 {
+    debug_assert!(0 <= e && e <= 1650);
+    ((e as u32) * 78913) >> 18
+}
+
+/// Returns `floor(log_10(5^e))` for `0 <= e <= 2620`.
+#[doc(hidden)]
+pub fn log10_pow5(e: i32) -> u32 {
+    debug_assert!(0 <= e && e <= 2620);
+    ((e as u32) * 732923) >> 20
+}
+
+/// Returns `ceil(log_2(5^e))` for `1 <= e <= 3528`, and 1 for `e == 0`.
+#[doc(hidden)]
+pub fn pow5bits(e: i32) -> i32 {
+    debug_assert!(0 <= e && e <= 3528);
+    (((e as u32) * 1217359) >> 19) as i32 + 1
+}
+
+fn pow5_factor(mut v: u64) -> u32 {
+    let mut count = 0;
+    loop {
+        if v % 5 != 0 {
+            return count;
+        }
+        v /= 5;
+        count += 1;
+    }
+}
+
+/// Returns true if `v` is divisible by `5^p`.
+fn multiple_of_power_of_5(v: u64, p: u32) -> bool {
+    pow5_factor(v) >= p
+}
+
+/// Returns true if `v` is divisible by `2^p`.
+fn multiple_of_power_of_2(v: u64, p: u32) -> bool {
+    debug_assert!(p < 64);
+    v & ((1u64 << p) - 1) == 0
+}
+
+/// Computes `(m * mul) >> j` where `mul` has at most 126 significant bits.
+/// The caller guarantees `j > 64` and that the result fits in 64 bits.
+fn mul_shift(m: u64, mul: u128, j: i32) -> u64 {
+    debug_assert!(j > 64);
+    let lo = (m as u128) * (mul as u64 as u128);
+    let hi = (m as u128) * (mul >> 64);
+    (((lo >> 64) + hi) >> (j - 64)) as u64
+}
+
+/// Writes the decimal digits of `v` into the start of `buf`,
+/// returning the number of digits written.
+fn write_digits(mut v: u64, buf: &mut [u8]) -> usize {
+    let mut tmp = [0u8; 20];
+    let mut i = tmp.len();
+    loop {
+        i -= 1;
+        tmp[i] = b'0' + (v % 10) as u8;
+        v /= 10;
+        if v == 0 {
+            break;
+        }
+    }
+    let len = tmp.len() - i;
+    buf[..len].copy_from_slice(&tmp[i..]);
+    len
+}
+
+/// The shortest mode implementation for Ryu.
+pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    // the number `v` to format is known to be equal to `mant * 2^exp`, and
+    // anything in `(mant - minus) * 2^exp ..= (mant + plus) * 2^exp` rounds to it
+    // (the bounds themselves only when `inclusive` is true).
+    //
+    // Ryu works on the interval `[mm, mp] * 2^e2` around `mv * 2^e2` where
+    // `mv = 4 * m2` and `mp = mv + 2`, and where `mm` is either `mv - 2` or,
+    // at the boundary between binades, `mv - 1`. the decoder hands us twice
+    // the mantissa in the common case and four times it at the boundary, so
+    // doubling the former gives exactly the same interval. all three values
+    // are then below 2^55, which is what the table precision is tuned for.
+    assert!(d.mant > 0);
+    assert!(d.minus > 0);
+    assert!(d.plus > 0);
+    assert!(d.mant.checked_add(d.plus).is_some());
+    assert!(d.mant.checked_sub(d.minus).is_some());
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    let shift = if d.plus == d.minus { 1 } else { 0 };
+    let mv = d.mant << shift;
+    let mp = (d.mant + d.plus) << shift;
+    let mm = (d.mant - d.minus) << shift;
+    let e2 = d.exp as i32 - shift;
+    debug_assert!(mp < 1 << 55);
+    let accept_bounds = d.inclusive;
+
+    // step 1: convert the interval to a decimal power base, yielding
+    // `[vm, vp] * 10^e10` around `vr * 10^e10`, and determine whether the
+    // digits of `vm` dropped by the conversion were all zeroes.
+    //
+    // unlike the original algorithm we do not track the trailing zeroes of `vr`:
+    // they only matter for breaking exact ties to even, while Dragon and Grisu
+    // (and hence our existing output) always break ties upwards.
+    let mut vr;
+    let mut vp;
+    let mut vm;
+    let e10;
+    let mut vm_is_trailing_zeros = false;
+    if e2 >= 0 {
+        // we need one more digit than strictly necessary for `e2 > 3`,
+        // so that the last removed digit is available for rounding.
+        let q = log10_pow2(e2) - (e2 > 3) as u32;
+        e10 = q as i32;
+        let k = POW5_INV_BITCOUNT + pow5bits(q as i32) - 1;
+        let i = -e2 + q as i32 + k;
+        let mul = POW5_INV_SPLIT[q as usize];
+        vr = mul_shift(mv, mul, i);
+        vp = mul_shift(mp, mul, i);
+        vm = mul_shift(mm, mul, i);
+        if q <= 21 {
+            if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(mm, q);
+            } else {
+                // the upper bound is excluded, so step below it if it is exact.
+                vp -= multiple_of_power_of_5(mp, q) as u64;
+            }
+        }
+    } else {
+        let q = log10_pow5(-e2) - (-e2 > 1) as u32;
+        e10 = q as i32 + e2;
+        let i = -e2 - q as i32;
+        let k = pow5bits(i) - POW5_BITCOUNT;
+        let j = q as i32 - k;
+        let mul = POW5_SPLIT[i as usize];
+        vr = mul_shift(mv, mul, j);
+        vp = mul_shift(mp, mul, j);
+        vm = mul_shift(mm, mul, j);
+        if q < 64 {
+            if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_2(mm, q);
+            } else {
+                vp -= multiple_of_power_of_2(mp, q) as u64;
+            }
+        }
+    }
+
+    // step 2: remove as many digits as the interval allows. this finds the
+    // shortest representation; the last removed digit decides the rounding.
+    let mut removed = 0i32;
+    let mut last_removed_digit = 0u8;
+    let output;
+    if vm_is_trailing_zeros {
+        // the general case, which happens rarely.
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm % 10 == 0;
+            last_removed_digit = (vr % 10) as u8;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        if vm_is_trailing_zeros {
+            while vm % 10 == 0 {
+                last_removed_digit = (vr % 10) as u8;
+                vr /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+        }
+        // we need to take `vr + 1` if `vr` is outside the bounds
+        // or we need to round up.
+        let outside_bounds = vr == vm && (!accept_bounds || !vm_is_trailing_zeros);
+        output = vr + (outside_bounds || last_removed_digit >= 5) as u64;
+    } else {
+        // the specialized common case, where the lower bound needs no care.
+        let mut round_up = false;
+        while vp / 10 > vm / 10 {
+            round_up = vr % 10 >= 5;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        output = vr + (vr == vm || round_up) as u64;
+    }
+
+    // step 3: render the digits. `output * 10^exp` becomes `0.<digits> * 10^k`.
+    let len = write_digits(output, buf);
+    debug_assert!(len <= MAX_SIG_DIGITS);
+    let k = e10 + removed + len as i32;
+    (len, k as i16)
+}
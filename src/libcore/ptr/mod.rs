@@ -96,6 +96,12 @@ pub use unique::Unique;
 mod const_ptr;
 mod mut_ptr;
 
+#[cfg(not(bootstrap))]
+mod metadata;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "ptr_metadata", issue = "none")]
+pub use metadata::{from_raw_parts, from_raw_parts_mut, metadata, DynMetadata, Pointee, Thin};
+
 /// Executes the destructor (if any) of the pointed-to value.
 ///
 /// This is semantically equivalent to calling [`ptr::read`] and discarding
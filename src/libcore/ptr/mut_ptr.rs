@@ -36,6 +36,19 @@ impl<T: ?Sized> *mut T {
         self as _
     }
 
+    /// Decompose a (possibly wide) pointer into its address and metadata components.
+    ///
+    /// The pointer can be later reconstructed with [`from_raw_parts_mut`].
+    ///
+    /// [`from_raw_parts_mut`]: ../std/ptr/fn.from_raw_parts_mut.html
+    #[cfg(not(bootstrap))]
+    #[unstable(feature = "ptr_metadata", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_metadata", issue = "none")]
+    #[inline]
+    pub const fn to_raw_parts(self) -> (*mut (), <T as super::Pointee>::Metadata) {
+        (self.cast(), super::metadata(self))
+    }
+
     /// Returns `None` if the pointer is null, or else returns a reference to
     /// the value wrapped in `Some`.
     ///
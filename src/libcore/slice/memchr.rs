@@ -21,19 +21,19 @@ const HI_USIZE: usize = HI_U64 as usize;
 /// bytes where the borrow propagated all the way to the most significant
 /// bit."
 #[inline]
-fn contains_zero_byte(x: usize) -> bool {
+const fn contains_zero_byte(x: usize) -> bool {
     x.wrapping_sub(LO_USIZE) & !x & HI_USIZE != 0
 }
 
 #[cfg(target_pointer_width = "16")]
 #[inline]
-fn repeat_byte(b: u8) -> usize {
+const fn repeat_byte(b: u8) -> usize {
     (b as usize) << 8 | b as usize
 }
 
 #[cfg(not(target_pointer_width = "16"))]
 #[inline]
-fn repeat_byte(b: u8) -> usize {
+const fn repeat_byte(b: u8) -> usize {
     (b as usize) * (usize::MAX / 255)
 }
 
@@ -82,6 +82,74 @@ pub fn memchr(x: u8, text: &[u8]) -> Option<usize> {
     text[offset..].iter().position(|elt| *elt == x).map(|i| offset + i)
 }
 
+/// Returns the first index matching the byte `x` in `text`.
+///
+/// Unlike [`memchr`], this can be used in constant expressions. It still scans
+/// a `usize` at a time, but assembles each word from its bytes instead of
+/// loading it through a pointer, which the optimizer turns back into a load.
+///
+/// [`memchr`]: fn.memchr.html
+pub(crate) const fn memchr_const(x: u8, text: &[u8]) -> Option<usize> {
+    let usize_bytes = mem::size_of::<usize>();
+    let repeated_x = repeat_byte(x);
+
+    let mut offset = 0;
+    while offset + usize_bytes <= text.len() {
+        let mut word = 0;
+        let mut i = 0;
+        while i < usize_bytes {
+            word |= (text[offset + i] as usize) << (8 * i);
+            i += 1;
+        }
+        if contains_zero_byte(word ^ repeated_x) {
+            break;
+        }
+        offset += usize_bytes;
+    }
+
+    // Find the byte after the point the word loop stopped.
+    while offset < text.len() {
+        if text[offset] == x {
+            return Some(offset);
+        }
+        offset += 1;
+    }
+    None
+}
+
+/// Returns the index of the first nul byte at `ptr`, like the C `strlen`.
+///
+/// The length is not known up front, so this cannot split a slice like
+/// [`memchr`] does. It scans bytes up to an aligned address and then a `usize`
+/// at a time. An aligned word never straddles a page boundary, so reading past
+/// the terminator within the last word cannot fault.
+///
+/// [`memchr`]: fn.memchr.html
+pub(crate) unsafe fn strlen(ptr: *const u8) -> usize {
+    let usize_bytes = mem::size_of::<usize>();
+
+    // Miri rejects the reads past the terminator, so it gets the bytewise scan.
+    let mut offset = if cfg!(miri) { usize::MAX } else { ptr.align_offset(usize_bytes) };
+    let mut len = 0;
+    while len < offset {
+        if *ptr.add(len) == 0 {
+            return len;
+        }
+        len += 1;
+    }
+
+    offset = len;
+    while !contains_zero_byte(*(ptr.add(offset) as *const usize)) {
+        offset += usize_bytes;
+    }
+
+    // Find the nul byte in the word the loop stopped at.
+    while *ptr.add(offset) != 0 {
+        offset += 1;
+    }
+    offset
+}
+
 /// Returns the last index matching the byte `x` in `text`.
 pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
     // Scan for a single byte value by reading two `usize` words at a time.
@@ -0,0 +1,51 @@
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// A stream that was created from an iterator.
+///
+/// This stream is created by the [`from_iter`] function.
+/// See its documentation for more.
+///
+/// [`from_iter`]: fn.from_iter.html
+#[unstable(feature = "async_stream", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+#[unstable(feature = "async_stream", issue = "none")]
+impl<I> Unpin for FromIter<I> {}
+
+/// Converts an iterator into a stream.
+///
+/// The returned stream is always ready: every call to `poll_next` yields the
+/// next item of the iterator, and `Poll::Ready(None)` once it is exhausted.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_stream)]
+/// use core::stream::{self, Stream};
+///
+/// let stream = stream::from_iter(vec![1, 2, 3]);
+/// assert_eq!(stream.size_hint(), (3, Some(3)));
+/// ```
+#[unstable(feature = "async_stream", issue = "none")]
+pub fn from_iter<I: IntoIterator>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter { iter: iter.into_iter() }
+}
+
+#[unstable(feature = "async_stream", issue = "none")]
+impl<I: Iterator> Stream for FromIter<I> {
+    type Item = I::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,50 @@
+//! Composable asynchronous iteration.
+//!
+//! If futures are asynchronous values, then streams are asynchronous
+//! iterators. If you've found yourself with an asynchronous collection of some
+//! kind, and needed to perform an operation on the elements of said collection,
+//! you'll quickly run into 'streams'. Streams are heavily used in idiomatic
+//! asynchronous Rust code, so it's worth becoming familiar with them.
+//!
+//! # Stream
+//!
+//! The heart and soul of this module is the [`Stream`] trait. The core of
+//! [`Stream`] looks like this:
+//!
+//! ```
+//! # use core::task::{Context, Poll};
+//! # use core::pin::Pin;
+//! trait Stream {
+//!     type Item;
+//!     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+//! }
+//! ```
+//!
+//! Unlike `Iterator`, `Stream` makes a distinction between the [`poll_next`]
+//! method which is used when implementing a `Stream`, and higher-level methods
+//! which are provided by library crates. Each call to [`poll_next`] returns
+//! `Poll::Pending` if no element is available yet, `Poll::Ready(Some(item))`
+//! for the next element, or `Poll::Ready(None)` once the stream is exhausted.
+//!
+//! [`Stream`]: trait.Stream.html
+//! [`poll_next`]: trait.Stream.html#tymethod.poll_next
+//!
+//! # Creating streams
+//!
+//! Implementing [`Stream`] yourself involves writing a type and implementing
+//! [`poll_next`] for it. For the common cases this module provides adapters:
+//!
+//! * [`from_iter`] converts any `IntoIterator` into a stream that is always ready.
+//! * [`poll_fn`] creates a stream out of a closure with the signature of `poll_next`.
+//!
+//! [`from_iter`]: fn.from_iter.html
+//! [`poll_fn`]: fn.poll_fn.html
+#![unstable(feature = "async_stream", issue = "none")]
+
+mod from_iter;
+mod poll_fn;
+mod stream;
+
+pub use from_iter::{from_iter, FromIter};
+pub use poll_fn::{poll_fn, PollFn};
+pub use stream::Stream;
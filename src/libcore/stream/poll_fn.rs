@@ -0,0 +1,64 @@
+use crate::fmt;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// A stream that wraps a function returning `Poll<Option<T>>`.
+///
+/// This stream is created by the [`poll_fn`] function.
+/// See its documentation for more.
+///
+/// [`poll_fn`]: fn.poll_fn.html
+#[unstable(feature = "async_stream", issue = "none")]
+#[must_use = "streams do nothing unless polled"]
+pub struct PollFn<F> {
+    f: F,
+}
+
+#[unstable(feature = "async_stream", issue = "none")]
+impl<F> Unpin for PollFn<F> {}
+
+#[unstable(feature = "async_stream", issue = "none")]
+impl<F> fmt::Debug for PollFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PollFn").finish()
+    }
+}
+
+/// Creates a new stream wrapping a function returning `Poll<Option<T>>`.
+///
+/// Polling the returned stream calls the wrapped function.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_stream)]
+/// use core::stream::{self, Stream};
+/// use core::task::Poll;
+///
+/// let mut counter = 0;
+/// let stream = stream::poll_fn(move |_cx| {
+///     counter += 1;
+///     Poll::Ready(if counter <= 3 { Some(counter) } else { None })
+/// });
+/// assert_eq!(stream.size_hint(), (0, None));
+/// ```
+#[unstable(feature = "async_stream", issue = "none")]
+pub fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+{
+    PollFn { f }
+}
+
+#[unstable(feature = "async_stream", issue = "none")]
+impl<T, F> Stream for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        (&mut self.f)(cx)
+    }
+}
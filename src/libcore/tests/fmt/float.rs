@@ -29,3 +29,19 @@ fn test_format_f32() {
     assert_eq!("0.0", format!("{:?}", 0.0f32));
     assert_eq!("1.01", format!("{:?}", 1.01f32));
 }
+
+#[test]
+fn test_float_buffer() {
+    use core::fmt::FloatBuffer;
+
+    let mut buf = FloatBuffer::new();
+    for &v in &[0.0f64, -0.0, 1.0, 0.1, 0.3, 1e21, 1e-7, f64::MAX, f64::MIN, 5e-324, -5e-324] {
+        assert_eq!(buf.format(v), format!("{:?}", v));
+    }
+    for &v in &[0.0f32, -0.0, 1.0, 0.1, 0.3, 1e21, 1e-7, f32::MAX, f32::MIN, 1e-45, -1e-45] {
+        assert_eq!(buf.format(v), format!("{:?}", v));
+    }
+    assert_eq!(buf.format(f64::NAN), "NaN");
+    assert_eq!(buf.format(f64::INFINITY), "inf");
+    assert_eq!(buf.format(f32::NEG_INFINITY), "-inf");
+}
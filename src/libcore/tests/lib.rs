@@ -8,13 +8,16 @@
 #![feature(debug_non_exhaustive)]
 #![feature(dec2flt)]
 #![feature(exact_size_is_empty)]
+#![feature(extern_types)]
 #![feature(fixed_size_array)]
+#![feature(float_buffer)]
 #![feature(flt2dec)]
 #![feature(fmt_internals)]
 #![feature(hashmap_internals)]
 #![feature(try_find)]
 #![feature(is_sorted)]
 #![feature(pattern)]
+#![feature(ptr_metadata)]
 #![feature(range_is_empty)]
 #![feature(raw)]
 #![feature(sort_internals)]
@@ -31,6 +34,7 @@
 #![feature(slice_partition_dedup)]
 #![feature(int_error_matching)]
 #![feature(array_value_iter)]
+#![feature(async_stream)]
 #![feature(iter_partition_in_place)]
 #![feature(iter_is_partitioned)]
 #![feature(iter_order_by)]
@@ -73,5 +77,6 @@ mod result;
 mod slice;
 mod str;
 mod str_lossy;
+mod stream;
 mod time;
 mod tuple;
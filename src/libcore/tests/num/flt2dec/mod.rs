@@ -12,6 +12,7 @@ mod estimator;
 mod strategy {
     mod dragon;
     mod grisu;
+    mod ryu;
 }
 mod random;
 
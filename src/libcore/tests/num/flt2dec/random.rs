@@ -145,6 +145,18 @@ fn shortest_random_equivalence_test() {
     f32_random_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS, n);
 }
 
+#[test]
+fn ryu_shortest_random_equivalence_test() {
+    use core::num::flt2dec::strategy::dragon::format_shortest as fallback;
+    use core::num::flt2dec::strategy::ryu::format_shortest;
+    // Miri is too slow
+    let n = if cfg!(miri) { 10 } else { 10_000 };
+
+    let ryu = |d: &Decoded, buf: &mut [u8]| Some(format_shortest(d, buf));
+    f64_random_equivalence_test(ryu, fallback, MAX_SIG_DIGITS, n);
+    f32_random_equivalence_test(ryu, fallback, MAX_SIG_DIGITS, n);
+}
+
 #[test]
 #[ignore] // it is too expensive
 fn shortest_f32_exhaustive_equivalence_test() {
@@ -0,0 +1,68 @@
+use super::super::*;
+use core::num::flt2dec::strategy::ryu::table::*;
+use core::num::flt2dec::strategy::ryu::*;
+
+#[test]
+fn test_log_approximations() {
+    // compare against the exact values computed with big integers.
+    let mut pow5 = 1u128;
+    let mut nbits = 1;
+    for e in 0..55 {
+        assert_eq!(pow5bits(e), nbits, "pow5bits({})", e);
+        pow5 *= 5;
+        nbits = 128 - pow5.leading_zeros() as i32;
+    }
+
+    let mut pow10 = 1u128;
+    let mut k = 0;
+    for e in 0..127 {
+        while pow10 * 10 <= 1u128 << e {
+            pow10 *= 10;
+            k += 1;
+        }
+        assert_eq!(log10_pow2(e), k, "log10_pow2({})", e);
+    }
+
+    let mut pow5 = 1u128;
+    let mut pow10 = 1u128;
+    let mut k = 0;
+    for e in 0..55 {
+        while pow10 * 10 <= pow5 {
+            pow10 *= 10;
+            k += 1;
+        }
+        assert_eq!(log10_pow5(e as i32), k, "log10_pow5({})", e);
+        pow5 *= 5;
+    }
+}
+
+#[test]
+fn test_pow5_tables() {
+    // the first entries are exact, and we can check them directly.
+    let mut pow5 = 1u128;
+    for i in 0..54 {
+        let bits = 128 - pow5.leading_zeros() as i32;
+        assert_eq!(POW5_SPLIT[i], pow5 << (POW5_BITCOUNT - bits), "POW5_SPLIT[{}]", i);
+        pow5 *= 5;
+    }
+    assert_eq!(POW5_INV_SPLIT[0], (1 << POW5_INV_BITCOUNT) + 1);
+    assert_eq!(POW5_INV_SPLIT[1], (1 << (POW5_INV_BITCOUNT + 2)) / 5 + 1);
+}
+
+#[cfg_attr(all(target_arch = "wasm32", target_os = "emscripten"), ignore)] // issue 42630
+#[test]
+fn shortest_sanity_test() {
+    f64_shortest_sanity_test(format_shortest);
+    f32_shortest_sanity_test(format_shortest);
+    more_shortest_sanity_test(format_shortest);
+}
+
+#[test]
+fn test_to_shortest_str() {
+    to_shortest_str_test(format_shortest);
+}
+
+#[test]
+fn test_to_shortest_exp_str() {
+    to_shortest_exp_str_test(format_shortest);
+}
@@ -401,3 +401,116 @@ fn align_offset_weird_strides() {
     }
     assert!(!x);
 }
+
+#[test]
+#[cfg(not(bootstrap))]
+fn ptr_metadata() {
+    struct Unit;
+    struct Pair<A, B: ?Sized>(A, B);
+
+    extern "C" {
+        type Extern;
+    }
+    let () = metadata(&());
+    let () = metadata(&Unit);
+    let () = metadata(&4_u32);
+    let () = metadata(&String::new());
+    let () = metadata(&Some(4_u32));
+    let () = metadata(&ptr_metadata);
+    let () = metadata(&|| {});
+    let () = metadata(&[4, 7]);
+    let () = metadata(&(4, String::new()));
+    let () = metadata(&Pair(4, String::new()));
+    let () = metadata(0 as *const Extern);
+    let () = metadata(0 as *const <&u32 as std::ops::Deref>::Target);
+
+    assert_eq!(metadata("foo"), 3_usize);
+    assert_eq!(metadata(&[4, 7][..]), 2_usize);
+
+    let dst_tuple: &(bool, [u8]) = &(true, [0x66, 0x6F, 0x6F]);
+    let dst_struct: &Pair<bool, [u8]> = &Pair(true, [0x66, 0x6F, 0x6F]);
+    assert_eq!(metadata(dst_tuple), 3_usize);
+    assert_eq!(metadata(dst_struct), 3_usize);
+    unsafe {
+        let dst_tuple: &(bool, str) = std::mem::transmute(dst_tuple);
+        let dst_struct: &Pair<bool, str> = std::mem::transmute(dst_struct);
+        assert_eq!(&dst_tuple.1, "foo");
+        assert_eq!(&dst_struct.1, "foo");
+        assert_eq!(metadata(dst_tuple), 3_usize);
+        assert_eq!(metadata(dst_struct), 3_usize);
+    }
+
+    let vtable_1: DynMetadata<dyn Debug> = metadata(&4_u16 as &dyn Debug);
+    let vtable_2: DynMetadata<dyn Display> = metadata(&4_u16 as &dyn Display);
+    let vtable_3: DynMetadata<dyn Display> = metadata(&4_u32 as &dyn Display);
+    let vtable_4: DynMetadata<dyn Display> = metadata(&(true, 7_u32) as &(bool, dyn Display));
+    let vtable_5: DynMetadata<dyn Display> =
+        metadata(&Pair(true, 7_u32) as &Pair<bool, dyn Display>);
+    assert_ne!(vtable_1.vtable_ptr(), vtable_2.vtable_ptr());
+    assert_ne!(vtable_1.vtable_ptr(), vtable_3.vtable_ptr());
+    assert_ne!(vtable_2, vtable_3);
+    assert_eq!(vtable_3, vtable_4);
+    assert_eq!(vtable_3, vtable_5);
+    assert_eq!(vtable_2.size_of(), 2);
+    assert_eq!(vtable_3.size_of(), 4);
+    assert_eq!(vtable_3.align_of(), std::mem::align_of::<u32>());
+    assert_eq!(vtable_3.layout(), std::alloc::Layout::new::<u32>());
+
+    use std::fmt::{Debug, Display};
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn ptr_metadata_bounds() {
+    fn metadata_eq_method_address<T: ?Sized>() -> usize {
+        // The `Metadata` associated type has an `Ord` bound, so this is valid:
+        <<T as Pointee>::Metadata as PartialEq>::eq as usize
+    }
+    // "Synthetic" trait impls generated by the compiler like those of `Pointee`
+    // are not checked for bounds of associated type.
+    // So with a buggy libcore we could have both:
+    // * `<dyn Display as Pointee>::Metadata == DynMetadata`
+    // * `DynMetadata: !PartialEq`
+    // ... and cause an ICE here:
+    metadata_eq_method_address::<dyn Display>();
+
+    // For this reason, let's check here that bounds are satisfied:
+
+    let _ = static_assert_expected_bounds_for_metadata::<()>;
+    let _ = static_assert_expected_bounds_for_metadata::<usize>;
+    let _ = static_assert_expected_bounds_for_metadata::<DynMetadata<dyn Display>>;
+    fn _static_assert_associated_type<T: ?Sized>() {
+        let _ = static_assert_expected_bounds_for_metadata::<<T as Pointee>::Metadata>;
+    }
+
+    fn static_assert_expected_bounds_for_metadata<Meta>()
+    where
+        // Keep this in sync with the associated type in `src/libcore/ptr/metadata.rs`
+        Meta: Copy + Send + Sync + Ord + std::hash::Hash + Unpin,
+    {
+    }
+
+    use std::fmt::Display;
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn ptr_raw_parts_round_trip() {
+    let array = [1_u16, 2, 3];
+    let slice: &[u16] = &array;
+    let (address, len) = (slice as *const [u16]).to_raw_parts();
+    assert_eq!(address, slice.as_ptr() as *const ());
+    assert_eq!(len, 3);
+    let rebuilt: *const [u16] = from_raw_parts(address, len);
+    assert_eq!(unsafe { &*rebuilt }, slice);
+
+    let mut value = 7_u32;
+    let object: &mut dyn std::fmt::Display = &mut value;
+    let (address, vtable) = (object as *mut dyn std::fmt::Display).to_raw_parts();
+    let rebuilt: *mut dyn std::fmt::Display = from_raw_parts_mut(address, vtable);
+    assert_eq!(unsafe { (*rebuilt).to_string() }, "7");
+
+    let non_null = NonNull::from(&array[..]);
+    let (address, len) = non_null.to_raw_parts();
+    assert_eq!(NonNull::<[u16]>::from_raw_parts(address, len), non_null);
+}
@@ -0,0 +1,61 @@
+use core::pin::Pin;
+use core::ptr;
+use core::stream::{self, Stream};
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+fn noop_raw_waker() -> RawWaker {
+    fn clone(_: *const ()) -> RawWaker {
+        noop_raw_waker()
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    RawWaker::new(ptr::null(), &VTABLE)
+}
+
+fn poll_next<S: Stream + Unpin>(stream: &mut S) -> Poll<Option<S::Item>> {
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    Pin::new(stream).poll_next(&mut cx)
+}
+
+#[test]
+fn test_from_iter() {
+    let mut stream = stream::from_iter(vec![1, 2]);
+    assert_eq!(stream.size_hint(), (2, Some(2)));
+    assert_eq!(poll_next(&mut stream), Poll::Ready(Some(1)));
+    assert_eq!(poll_next(&mut stream), Poll::Ready(Some(2)));
+    assert_eq!(poll_next(&mut stream), Poll::Ready(None));
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_poll_fn() {
+    let mut calls = 0;
+    let mut stream = stream::poll_fn(|_cx| {
+        calls += 1;
+        match calls {
+            1 => Poll::Pending,
+            2 => Poll::Ready(Some("ready")),
+            _ => Poll::Ready(None),
+        }
+    });
+    assert_eq!(poll_next(&mut stream), Poll::Pending);
+    assert_eq!(poll_next(&mut stream), Poll::Ready(Some("ready")));
+    assert_eq!(poll_next(&mut stream), Poll::Ready(None));
+}
+
+#[test]
+fn test_forwarding_impls() {
+    let mut inner = stream::from_iter(0..3);
+    let mut by_ref = &mut inner;
+    assert_eq!(poll_next(&mut by_ref), Poll::Ready(Some(0)));
+    assert_eq!(by_ref.size_hint(), (2, Some(2)));
+
+    let mut pinned = Pin::new(&mut inner);
+    assert_eq!(poll_next(&mut pinned), Poll::Ready(Some(1)));
+
+    let mut boxed: Box<dyn Stream<Item = i32> + Unpin> = Box::new(inner);
+    assert_eq!(boxed.size_hint(), (1, Some(1)));
+    assert_eq!(poll_next(&mut boxed), Poll::Ready(Some(2)));
+    assert_eq!(poll_next(&mut boxed), Poll::Ready(None));
+}
@@ -19,7 +19,8 @@ pub fn expr_requires_semi_to_be_stmt(e: &ast::Expr) -> bool {
         | ast::ExprKind::While(..)
         | ast::ExprKind::Loop(..)
         | ast::ExprKind::ForLoop(..)
-        | ast::ExprKind::TryBlock(..) => false,
+        | ast::ExprKind::TryBlock(..)
+        | ast::ExprKind::ConstBlock(..) => false,
         _ => true,
     }
 }
@@ -275,6 +275,7 @@ pub enum ExprPrecedence {
 
     Array,
     Repeat,
+    ConstBlock,
     Tup,
     Lit,
     Path,
@@ -339,6 +340,7 @@ impl ExprPrecedence {
             // Never need parens
             ExprPrecedence::Array |
             ExprPrecedence::Repeat |
+            ExprPrecedence::ConstBlock |
             ExprPrecedence::Tup |
             ExprPrecedence::Lit |
             ExprPrecedence::Path |
@@ -1,4 +1,4 @@
-use super::{AnonymousLifetimeMode, LoweringContext, ParamMode};
+use super::{AnonymousLifetimeMode, AsyncFnAssocTy, LoweringContext, ParamMode};
 use super::{ImplTraitContext, ImplTraitPosition, ImplTraitTypeIdVisitor};
 use crate::Arena;
 
@@ -11,14 +11,17 @@ use rustc_errors::struct_span_err;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::LocalDefId;
+use rustc_hir::definitions::DefPathData;
+use rustc_span::hygiene::ExpnId;
 use rustc_span::source_map::{respan, DesugaringKind};
-use rustc_span::symbol::{kw, sym, Ident};
+use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::Span;
 use rustc_target::spec::abi;
 
 use log::debug;
 use smallvec::{smallvec, SmallVec};
 use std::collections::BTreeSet;
+use std::iter;
 
 pub(super) struct ItemLowerer<'a, 'lowering, 'hir> {
     pub(super) lctx: &'a mut LoweringContext<'lowering, 'hir>,
@@ -386,11 +389,17 @@ impl<'hir> LoweringContext<'_, 'hir> {
                     },
                 );
 
+                let self_res = trait_ref.as_ref().map(|trait_ref| {
+                    Res::SelfTy(trait_ref.trait_def_id(), Some(def_id.to_def_id()))
+                });
                 let new_impl_items =
                     self.with_in_scope_lifetime_defs(&ast_generics.params, |this| {
-                        this.arena.alloc_from_iter(
-                            impl_items.iter().map(|item| this.lower_impl_item_ref(item)),
-                        )
+                        this.arena.alloc_from_iter(impl_items.iter().flat_map(|item| {
+                            let assoc_ty_ref = self_res.and_then(|self_res| {
+                                this.lower_async_fn_assoc_ty_impl_item_ref(item, self_res)
+                            });
+                            iter::once(this.lower_impl_item_ref(item)).chain(assoc_ty_ref)
+                        }))
                     });
 
                 // `defaultness.has_value()` is never called for an `impl`, always `true` in order
@@ -411,9 +420,12 @@ impl<'hir> LoweringContext<'_, 'hir> {
             }
             ItemKind::Trait(is_auto, unsafety, ref generics, ref bounds, ref items) => {
                 let bounds = self.lower_param_bounds(bounds, ImplTraitContext::disallowed());
-                let items = self
-                    .arena
-                    .alloc_from_iter(items.iter().map(|item| self.lower_trait_item_ref(item)));
+                let trait_def_id = self.resolver.definitions().local_def_id(id).to_def_id();
+                let self_res = Res::SelfTy(Some(trait_def_id), None);
+                let items = self.arena.alloc_from_iter(items.iter().flat_map(|item| {
+                    let assoc_ty_ref = self.lower_async_fn_assoc_ty_trait_item_ref(item, self_res);
+                    iter::once(self.lower_trait_item_ref(item)).chain(assoc_ty_ref)
+                }));
                 hir::ItemKind::Trait(
                     is_auto,
                     self.lower_unsafety(unsafety),
@@ -756,8 +768,15 @@ impl<'hir> LoweringContext<'_, 'hir> {
             }
             AssocItemKind::Fn(_, ref sig, ref generics, None) => {
                 let names = self.lower_fn_params_to_names(&sig.decl);
+                // Only an `async fn` desugared into an associated type by
+                // `lower_async_fn_assoc_ty_trait_item_ref` has a return type to lower as such.
+                let is_async = sig
+                    .header
+                    .asyncness
+                    .opt_return_id()
+                    .filter(|id| self.async_fn_assoc_tys.contains_key(id));
                 let (generics, sig) =
-                    self.lower_method_sig(generics, sig, trait_item_def_id, false, None);
+                    self.lower_method_sig(generics, sig, trait_item_def_id, false, is_async);
                 (generics, hir::TraitItemKind::Fn(sig, hir::TraitFn::Required(names)))
             }
             AssocItemKind::Fn(_, ref sig, ref generics, Some(ref body)) => {
@@ -805,6 +824,79 @@ impl<'hir> LoweringContext<'_, 'hir> {
         hir::TraitItemRef { id, ident: i.ident, span: i.span, defaultness, kind }
     }
 
+    fn lower_async_fn_assoc_ty_trait_item_ref(
+        &mut self,
+        i: &AssocItem,
+        self_res: Res,
+    ) -> Option<hir::TraitItemRef> {
+        let assoc_ty = self.async_fn_assoc_ty(i, AssocCtxt::Trait, self_res)?;
+        Some(hir::TraitItemRef {
+            id: hir::TraitItemId { hir_id: self.lower_node_id(assoc_ty.node_id) },
+            ident: assoc_ty.ident,
+            span: i.span,
+            defaultness: hir::Defaultness::Default { has_value: false },
+            kind: hir::AssocItemKind::Type,
+        })
+    }
+
+    /// Returns the generic associated type that the `async fn` `i` of a trait or trait
+    /// impl is desugared into, creating its definition the first time it is requested.
+    /// The associated type itself is lowered along with the return type of `i`, see
+    /// `lower_async_fn_assoc_ty_ret_ty`.
+    fn async_fn_assoc_ty(
+        &mut self,
+        i: &AssocItem,
+        ctxt: AssocCtxt,
+        self_res: Res,
+    ) -> Option<AsyncFnAssocTy> {
+        if !self.sess.features_untracked().async_fn_in_trait {
+            return None;
+        }
+        let (return_impl_trait_id, generics) = match &i.kind {
+            // Default bodies are rejected during AST validation, so such a function
+            // is lowered like an `async fn` outside of a trait.
+            AssocItemKind::Fn(_, sig, generics, body)
+                if ctxt == AssocCtxt::Impl || body.is_none() =>
+            {
+                (sig.header.asyncness.opt_return_id()?, generics)
+            }
+            _ => return None,
+        };
+        if let Some(&assoc_ty) = self.async_fn_assoc_tys.get(&return_impl_trait_id) {
+            return Some(assoc_ty);
+        }
+        let mut fn_lifetimes = 0;
+        for param in &generics.params {
+            match param.kind {
+                GenericParamKind::Lifetime => fn_lifetimes += 1,
+                // Rejected during AST validation as well: the associated type
+                // could not name these parameters.
+                GenericParamKind::Type { .. } | GenericParamKind::Const { .. } => return None,
+            }
+        }
+
+        // The associated type lives in the type namespace of the trait or impl. It is
+        // named after the function, but in a way that cannot be written in source, so
+        // that it never collides with an associated type declared by the user.
+        let name = Symbol::intern(&format!("{{async fn {}}}", i.ident));
+        let ident = Ident::new(name, i.ident.span);
+        let fn_def_id = self.resolver.definitions().local_def_id(i.id);
+        let parent_def_index = self.resolver.definitions().def_key(fn_def_id).parent.unwrap();
+        let node_id = self.resolver.next_node_id();
+        self.resolver.definitions().create_def_with_parent(
+            LocalDefId { local_def_index: parent_def_index },
+            node_id,
+            DefPathData::TypeNs(name),
+            ExpnId::root(),
+            i.span,
+        );
+        self.allocate_hir_id_counter(node_id);
+
+        let assoc_ty = AsyncFnAssocTy { node_id, ident, ctxt, self_res, fn_lifetimes };
+        self.async_fn_assoc_tys.insert(return_impl_trait_id, assoc_ty);
+        Some(assoc_ty)
+    }
+
     /// Construct `ExprKind::Err` for the given `span`.
     crate fn expr_err(&mut self, span: Span) -> hir::Expr<'hir> {
         self.expr(span, hir::ExprKind::Err, AttrVec::new())
@@ -901,6 +993,22 @@ impl<'hir> LoweringContext<'_, 'hir> {
         }
     }
 
+    fn lower_async_fn_assoc_ty_impl_item_ref(
+        &mut self,
+        i: &AssocItem,
+        self_res: Res,
+    ) -> Option<hir::ImplItemRef<'hir>> {
+        let assoc_ty = self.async_fn_assoc_ty(i, AssocCtxt::Impl, self_res)?;
+        Some(hir::ImplItemRef {
+            id: hir::ImplItemId { hir_id: self.lower_node_id(assoc_ty.node_id) },
+            ident: assoc_ty.ident,
+            span: i.span,
+            vis: respan(i.span.shrink_to_lo(), hir::VisibilityKind::Inherited),
+            defaultness: hir::Defaultness::Final,
+            kind: hir::AssocItemKind::OpaqueTy,
+        })
+    }
+
     /// If an `explicit_owner` is given, this method allocates the `HirId` in
     /// the address space of that item instead of the item currently being
     /// lowered. This can happen during `lower_impl_item_ref()` where we need to
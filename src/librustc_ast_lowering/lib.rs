@@ -166,6 +166,11 @@ struct LoweringContext<'a, 'hir: 'a> {
 
     type_def_lifetime_params: DefIdMap<usize>,
 
+    /// With `#![feature(async_fn_in_trait)]`, an `async fn name` in a trait or trait impl
+    /// returns a generic associated type named `{async fn name}`. This maps the
+    /// `return_impl_trait_id` of such a function to that associated type.
+    async_fn_assoc_tys: NodeMap<AsyncFnAssocTy>,
+
     current_hir_id_owner: Vec<(LocalDefId, u32)>,
     item_local_id_counters: NodeMap<u32>,
     node_id_to_hir_id: IndexVec<NodeId, Option<hir::HirId>>,
@@ -289,6 +294,7 @@ pub fn lower_crate<'a, 'hir>(
         is_in_dyn_type: false,
         anonymous_lifetime_mode: AnonymousLifetimeMode::PassThrough,
         type_def_lifetime_params: Default::default(),
+        async_fn_assoc_tys: Default::default(),
         current_module: hir::CRATE_HIR_ID,
         current_hir_id_owner: vec![(LocalDefId { local_def_index: CRATE_DEF_INDEX }, 0)],
         item_local_id_counters: Default::default(),
@@ -305,6 +311,18 @@ pub fn lower_crate<'a, 'hir>(
     .lower_crate(krate)
 }
 
+/// The generic associated type that an `async fn` in a trait or trait impl is desugared into.
+#[derive(Copy, Clone)]
+struct AsyncFnAssocTy {
+    node_id: NodeId,
+    ident: Ident,
+    ctxt: AssocCtxt,
+    /// The resolution of `Self` in the trait or impl.
+    self_res: Res,
+    /// The number of lifetime parameters declared on the function itself.
+    fn_lifetimes: usize,
+}
+
 #[derive(Copy, Clone, PartialEq)]
 enum ParamMode {
     /// Any path in a type context.
@@ -1791,6 +1809,10 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
             output, fn_def_id, opaque_ty_node_id,
         );
 
+        if let Some(&assoc_ty) = self.async_fn_assoc_tys.get(&opaque_ty_node_id) {
+            return self.lower_async_fn_assoc_ty_ret_ty(output, fn_def_id, assoc_ty);
+        }
+
         let span = output.span();
 
         let opaque_ty_span = self.mark_span_with_reason(DesugaringKind::Async, span, None);
@@ -1949,6 +1971,144 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
         hir::FnRetTy::Return(self.arena.alloc(opaque_ty))
     }
 
+    // Transforms `-> T` for an `async fn` in a trait or trait impl into
+    // `-> Self::foo<...>`, where `foo` is a generic associated type named after
+    // the function and declared in the trait as
+    //
+    //     type foo<generics_from_fn>: Future<Output = T>;
+    //
+    // and defined in each impl as
+    //
+    //     type foo<generics_from_fn> = impl Future<Output = T>;
+    //
+    // The lifetime parameters of `foo` are chosen as in `lower_async_fn_ret_ty`,
+    // except for the lifetimes of the trait or impl: the associated type already
+    // inherits those from its parent.
+    fn lower_async_fn_assoc_ty_ret_ty(
+        &mut self,
+        output: &FnRetTy,
+        fn_def_id: DefId,
+        assoc_ty: AsyncFnAssocTy,
+    ) -> hir::FnRetTy<'hir> {
+        let span = output.span();
+
+        let assoc_ty_span = self.mark_span_with_reason(DesugaringKind::Async, span, None);
+
+        let assoc_ty_def_id = self.resolver.definitions().local_def_id(assoc_ty.node_id);
+
+        let parent_lifetimes_count = self.in_scope_lifetimes.len() - assoc_ty.fn_lifetimes;
+        let input_lifetimes_count = assoc_ty.fn_lifetimes + self.lifetimes_to_define.len();
+
+        let lifetime_params = self.with_hir_id_owner(assoc_ty.node_id, |this| {
+            let future_bound = this
+                .with_anonymous_lifetime_mode(AnonymousLifetimeMode::CreateParameter, |this| {
+                    this.lower_async_fn_output_type_to_future_bound(output, fn_def_id, span)
+                });
+
+            let lifetime_params: Vec<(Span, ParamName)> = this.in_scope_lifetimes
+                [parent_lifetimes_count..]
+                .iter()
+                .cloned()
+                .map(|name| (name.ident().span, name))
+                .chain(this.lifetimes_to_define.iter().cloned())
+                .collect();
+
+            debug!("lower_async_fn_assoc_ty_ret_ty: lifetime_params={:#?}", lifetime_params);
+
+            let generics = hir::Generics {
+                params: this.arena.alloc_from_iter(lifetime_params.iter().map(
+                    |(span, hir_name)| {
+                        this.lifetime_to_generic_param(*span, *hir_name, assoc_ty_def_id)
+                    },
+                )),
+                where_clause: hir::WhereClause { predicates: &[], span },
+                span,
+            };
+            let bounds = arena_vec![this; future_bound];
+            let hir_id = this.lower_node_id(assoc_ty.node_id);
+
+            // Insert the associated type into the item lists, like `generate_opaque_type`
+            // does for opaque types. The trait or impl already refers to it, see
+            // `lower_async_fn_assoc_ty_trait_item_ref`.
+            match assoc_ty.ctxt {
+                AssocCtxt::Trait => {
+                    let id = hir::TraitItemId { hir_id };
+                    let item = hir::TraitItem {
+                        hir_id,
+                        ident: assoc_ty.ident,
+                        attrs: &[],
+                        generics,
+                        kind: hir::TraitItemKind::Type(bounds, None),
+                        span: assoc_ty_span,
+                    };
+                    this.trait_items.insert(id, item);
+                    this.modules.get_mut(&this.current_module).unwrap().trait_items.insert(id);
+                }
+                AssocCtxt::Impl => {
+                    let id = hir::ImplItemId { hir_id };
+                    let item = hir::ImplItem {
+                        hir_id,
+                        ident: assoc_ty.ident,
+                        attrs: &[],
+                        generics,
+                        vis: respan(span.shrink_to_lo(), hir::VisibilityKind::Inherited),
+                        defaultness: hir::Defaultness::Final,
+                        kind: hir::ImplItemKind::OpaqueTy(bounds),
+                        span: assoc_ty_span,
+                    };
+                    this.impl_items.insert(id, item);
+                    this.modules.get_mut(&this.current_module).unwrap().impl_items.insert(id);
+                }
+            }
+
+            lifetime_params
+        });
+
+        // As in `lower_async_fn_ret_ty`, the lifetimes elided in the return type
+        // are passed as `'_` and left to the ordinary elision rules.
+        let mut generic_args: Vec<_> = lifetime_params[..input_lifetimes_count]
+            .iter()
+            .map(|&(span, hir_name)| {
+                GenericArg::Lifetime(hir::Lifetime {
+                    hir_id: self.next_id(),
+                    span,
+                    name: hir::LifetimeName::Param(hir_name),
+                })
+            })
+            .collect();
+        generic_args.extend(lifetime_params[input_lifetimes_count..].iter().map(|&(span, _)| {
+            GenericArg::Lifetime(hir::Lifetime {
+                hir_id: self.next_id(),
+                span,
+                name: hir::LifetimeName::Implicit,
+            })
+        }));
+        let generic_args = self.arena.alloc(hir::GenericArgs {
+            args: self.arena.alloc_from_iter(generic_args),
+            bindings: &[],
+            parenthesized: false,
+        });
+
+        // Create the `Self::foo<...>` reference itself.
+        let self_path = self.arena.alloc(hir::Path {
+            res: assoc_ty.self_res,
+            segments: arena_vec![self; hir::PathSegment::from_ident(
+                Ident::with_dummy_span(kw::SelfUpper)
+            )],
+            span: assoc_ty_span,
+        });
+        let self_ty = hir::TyKind::Path(hir::QPath::Resolved(None, self_path));
+        let self_ty = self.arena.alloc(self.ty(assoc_ty_span, self_ty));
+        let assoc_ty_segment = self.arena.alloc(hir::PathSegment {
+            args: Some(generic_args),
+            infer_args: false,
+            ..hir::PathSegment::from_ident(assoc_ty.ident)
+        });
+        let assoc_ty_ref = hir::TyKind::Path(hir::QPath::TypeRelative(self_ty, assoc_ty_segment));
+        let ret_ty = self.ty(assoc_ty_span, assoc_ty_ref);
+        hir::FnRetTy::Return(self.arena.alloc(ret_ty))
+    }
+
     /// Transforms `-> T` into `Future<Output = T>`
     fn lower_async_fn_output_type_to_future_bound(
         &mut self,
@@ -2126,13 +2286,14 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
 
                 (hir::ParamName::Plain(param.ident), kind)
             }
-            GenericParamKind::Const { ref ty } => {
+            GenericParamKind::Const { ref ty, ref default } => {
                 let ty = self
                     .with_anonymous_lifetime_mode(AnonymousLifetimeMode::ReportError, |this| {
                         this.lower_ty(&ty, ImplTraitContext::disallowed())
                     });
+                let default = default.as_ref().map(|def| self.lower_anon_const(def));
 
-                (hir::ParamName::Plain(param.ident), hir::GenericParamKind::Const { ty })
+                (hir::ParamName::Plain(param.ident), hir::GenericParamKind::Const { ty, default })
             }
         };
 
@@ -2201,24 +2362,11 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
     }
 
     fn lower_block_noalloc(&mut self, b: &Block, targeted_by_break: bool) -> hir::Block<'hir> {
-        let mut stmts = vec![];
-        let mut expr: Option<&'hir _> = None;
-
-        for (index, stmt) in b.stmts.iter().enumerate() {
-            if index == b.stmts.len() - 1 {
-                if let StmtKind::Expr(ref e) = stmt.kind {
-                    expr = Some(self.lower_expr(e));
-                } else {
-                    stmts.extend(self.lower_stmt(stmt));
-                }
-            } else {
-                stmts.extend(self.lower_stmt(stmt));
-            }
-        }
+        let (stmts, expr) = self.lower_stmts(&b.stmts);
 
         hir::Block {
             hir_id: self.lower_node_id(b.id),
-            stmts: self.arena.alloc_from_iter(stmts),
+            stmts,
             expr,
             rules: self.lower_block_check_mode(&b.rules),
             span: b.span,
@@ -2226,6 +2374,34 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
         }
     }
 
+    /// Lowers the statements of a block, splitting off its trailing expression.
+    ///
+    /// A `let`-`else` statement takes the rest of the block with it, see
+    /// `lower_let_else`, so it always becomes the trailing expression.
+    fn lower_stmts(
+        &mut self,
+        mut ast_stmts: &[Stmt],
+    ) -> (&'hir [hir::Stmt<'hir>], Option<&'hir hir::Expr<'hir>>) {
+        let mut stmts = vec![];
+        let mut expr: Option<&'hir _> = None;
+
+        while let [stmt, tail @ ..] = ast_stmts {
+            match stmt.kind {
+                StmtKind::Local(ref local) if local.els.is_some() => {
+                    expr = Some(self.lower_let_else(stmt, local, tail));
+                    break;
+                }
+                StmtKind::Expr(ref e) if tail.is_empty() => {
+                    expr = Some(self.lower_expr(e));
+                }
+                _ => stmts.extend(self.lower_stmt(stmt)),
+            }
+            ast_stmts = tail;
+        }
+
+        (self.arena.alloc_from_iter(stmts), expr)
+    }
+
     /// Lowers a block directly to an expression, presuming that it
     /// has no attributes and is not targeted by a `break`.
     fn lower_block_expr(&mut self, b: &Block) -> hir::Expr<'hir> {
@@ -229,16 +229,57 @@ impl<'a> AstValidator<'a> {
 
     fn check_trait_fn_not_async(&self, fn_span: Span, asyncness: Async) {
         if let Async::Yes { span, .. } = asyncness {
-            struct_span_err!(
+            let mut err = struct_span_err!(
                 self.session,
                 fn_span,
                 E0706,
                 "functions in traits cannot be declared `async`"
-            )
-            .span_label(span, "`async` because of this")
-            .note("`async` trait functions are not currently supported")
-            .note("consider using the `async-trait` crate: https://crates.io/crates/async-trait")
-            .emit();
+            );
+            err.span_label(span, "`async` because of this")
+                .note("`async` trait functions are not currently supported")
+                .note(
+                    "consider using the `async-trait` crate: https://crates.io/crates/async-trait",
+                );
+            if self.session.parse_sess.unstable_features.is_nightly_build() {
+                err.help("add `#![feature(async_fn_in_trait)]` to the crate attributes to enable");
+            }
+            err.emit();
+        }
+    }
+
+    /// Rejects the `async fn`s in traits and trait impls that cannot be desugared into a
+    /// generic associated type under `#![feature(async_fn_in_trait)]`.
+    fn check_async_fn_in_trait(
+        &self,
+        ctxt: AssocCtxt,
+        asyncness: Async,
+        generics: &Generics,
+        body: Option<&Block>,
+    ) {
+        let span = match asyncness {
+            Async::Yes { span, .. } => span,
+            Async::No => return,
+        };
+        if let (AssocCtxt::Trait, Some(body)) = (ctxt, body) {
+            self.err_handler()
+                .struct_span_err(body.span, "`async fn` in traits cannot have a default body")
+                .span_label(span, "`async` because of this")
+                .note("each implementation of the trait has to provide the body of the function")
+                .emit();
+        }
+        let param = generics
+            .params
+            .iter()
+            .find(|param| !matches!(param.kind, GenericParamKind::Lifetime));
+        if let Some(param) = param {
+            self.err_handler()
+                .struct_span_err(
+                    param.ident.span,
+                    "`async fn` in traits cannot have type or const parameters",
+                )
+                .span_label(span, "`async` because of this")
+                .note("only lifetime parameters can be captured by the returned future")
+                .emit();
         }
     }
 
@@ -289,7 +330,7 @@ impl<'a> AstValidator<'a> {
     // ```
     fn check_expr_within_pat(&self, expr: &Expr, allow_paths: bool) {
         match expr.kind {
-            ExprKind::Lit(..) | ExprKind::Err => {}
+            ExprKind::Lit(..) | ExprKind::ConstBlock(..) | ExprKind::Err => {}
             ExprKind::Path(..) if allow_paths => {}
             ExprKind::Unary(UnOp::Neg, ref inner) if matches!(inner.kind, ExprKind::Lit(_)) => {}
             _ => self.err_handler().span_err(
@@ -763,6 +804,7 @@ fn validate_generic_param_order<'a>(
     }
     ordered_params += ">";
 
+    let features = sess.features_untracked();
     for (param_ord, (max_param, spans)) in &out_of_order {
         let mut err =
             handler.struct_span_err(
@@ -776,7 +818,11 @@ fn validate_generic_param_order<'a>(
             span,
             &format!(
                 "reorder the parameters: lifetimes, then types{}",
-                if sess.features_untracked().const_generics { ", then consts" } else { "" },
+                if features.const_generics || features.min_const_generics {
+                    ", then consts"
+                } else {
+                    ""
+                },
             ),
             ordered_params.clone(),
             Applicability::MachineApplicable,
@@ -1129,6 +1175,19 @@ impl<'a> Visitor<'a> for AstValidator<'a> {
             }
         }
 
+        let mut prev_const_default = None;
+        for param in &generics.params {
+            if let GenericParamKind::Const { ref default, .. } = param.kind {
+                if default.is_some() {
+                    prev_const_default = Some(param.ident.span);
+                } else if let Some(span) = prev_const_default {
+                    self.err_handler()
+                        .span_err(span, "const parameters with a default must be trailing");
+                    break;
+                }
+            }
+        }
+
         validate_generic_param_order(
             self.session,
             self.err_handler(),
@@ -1137,9 +1196,18 @@ impl<'a> Visitor<'a> for AstValidator<'a> {
                 let (kind, ident) = match &param.kind {
                     GenericParamKind::Lifetime { .. } => (ParamKindOrd::Lifetime, ident),
                     GenericParamKind::Type { .. } => (ParamKindOrd::Type, ident),
-                    GenericParamKind::Const { ref ty } => {
+                    GenericParamKind::Const { ref ty, ref default } => {
                         let ty = pprust::ty_to_string(ty);
-                        (ParamKindOrd::Const, Some(format!("const {}: {}", param.ident, ty)))
+                        let param = match default {
+                            Some(default) => format!(
+                                "const {}: {} = {}",
+                                param.ident,
+                                ty,
+                                pprust::expr_to_string(&default.value)
+                            ),
+                            None => format!("const {}: {}", param.ident, ty),
+                        };
+                        (ParamKindOrd::Const, Some(param))
                     }
                 };
                 (kind, Some(&*param.bounds), param.ident.span, ident)
@@ -1312,9 +1380,14 @@ impl<'a> Visitor<'a> for AstValidator<'a> {
 
         if ctxt == AssocCtxt::Trait || self.in_trait_impl {
             self.invalid_visibility(&item.vis, None);
-            if let AssocItemKind::Fn(_, sig, _, _) = &item.kind {
+            if let AssocItemKind::Fn(_, sig, generics, body) = &item.kind {
                 self.check_trait_fn_not_const(sig.header.constness);
-                self.check_trait_fn_not_async(item.span, sig.header.asyncness);
+                if self.session.features_untracked().async_fn_in_trait {
+                    let body = body.as_deref();
+                    self.check_async_fn_in_trait(ctxt, sig.header.asyncness, generics, body);
+                } else {
+                    self.check_trait_fn_not_async(item.span, sig.header.asyncness);
+                }
             }
         }
 
@@ -516,11 +516,26 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
     }
 
     fn visit_generic_param(&mut self, param: &'a GenericParam) {
-        if let GenericParamKind::Const { .. } = param.kind {
-            gate_feature_post!(
+        if let GenericParamKind::Const { ref ty, .. } = param.kind {
+            // Only suggest `min_const_generics` if it would accept the type
+            // of this parameter, which has to be an integer, `bool` or `char`.
+            let is_min_ty = match ty.kind {
+                ast::TyKind::Path(None, ref path) => match &path.segments[..] {
+                    [segment] if segment.args.is_none() => matches!(
+                        segment.ident.name,
+                        sym::u8 | sym::u16 | sym::u32 | sym::u64 | sym::u128 | sym::usize
+                            | sym::i8 | sym::i16 | sym::i32 | sym::i64 | sym::i128 | sym::isize
+                            | sym::bool | sym::char
+                    ),
+                    _ => false,
+                },
+                _ => false,
+            };
+            gate_feature_fn!(
                 &self,
-                const_generics,
+                |x: &Features| x.const_generics || x.min_const_generics,
                 param.ident.span,
+                if is_min_ty { sym::min_const_generics } else { sym::const_generics },
                 "const generics are unstable"
             )
         }
@@ -608,6 +623,10 @@ pub fn check_crate(
         };
     }
     gate_all!(let_chains, "`let` expressions in this position are experimental");
+    gate_all!(let_else, "`let...else` statements are unstable");
+    gate_all!(if_let_guard, "`if let` guards are experimental");
+    gate_all!(inline_const, "inline-const is experimental");
+    gate_all!(const_generics_defaults, "const parameter defaults are experimental");
     gate_all!(async_closure, "async closures are unstable");
     gate_all!(generators, "yield syntax is experimental");
     gate_all!(or_patterns, "or-patterns syntax is experimental");
@@ -129,6 +129,7 @@ fn stmt_let_underscore(cx: &mut ExtCtxt<'_>, sp: Span, expr: P<ast::Expr>) -> as
         pat: cx.pat_wild(sp),
         ty: None,
         init: Some(expr),
+        els: None,
         id: ast::DUMMY_NODE_ID,
         span: sp,
         attrs: ast::AttrVec::new(),
@@ -568,7 +568,11 @@ impl<'a> TraitDef<'a> {
 
                 cx.typaram(self.span, param.ident, vec![], bounds, None)
             }
-            GenericParamKind::Const { .. } => param.clone(),
+            GenericParamKind::Const { ref ty, .. } => ast::GenericParam {
+                // Impls cannot have defaults for their const parameters either.
+                kind: GenericParamKind::Const { ty: ty.clone(), default: None },
+                ..param.clone()
+            },
         }));
 
         // and similarly for where clauses
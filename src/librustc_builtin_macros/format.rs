@@ -108,6 +108,9 @@ struct Context<'a, 'b> {
     arg_spans: Vec<Span>,
     /// All the formatting arguments that have formatting flags set, in order for diagnostics.
     arg_with_formatting: Vec<parse::FormatSpec<'a>>,
+    /// Whether this format string is a literal written in the source, as opposed to one
+    /// produced by a macro. Only literals may capture variables from the surrounding scope.
+    is_literal: bool,
 }
 
 /// Parses the arguments from the given list of tokens, returning the diagnostic
@@ -499,10 +502,52 @@ impl<'a, 'b> Context<'a, 'b> {
                         self.verify_arg_type(Exact(idx), ty)
                     }
                     None => {
-                        let msg = format!("there is no argument named `{}`", name);
-                        let sp = *self.arg_spans.get(self.curpiece).unwrap_or(&self.fmtsp);
-                        let mut err = self.ecx.struct_span_err(sp, &msg[..]);
-                        err.emit();
+                        let capture_feature_enabled = self
+                            .ecx
+                            .ecfg
+                            .features
+                            .map_or(false, |features| features.format_args_capture);
+                        // The spans of placeholders are only meaningful when the format string
+                        // is a literal written in the source.
+                        let sp = if self.is_literal {
+                            *self.arg_spans.get(self.curpiece).unwrap_or(&self.fmtsp)
+                        } else {
+                            self.fmtsp
+                        };
+
+                        if capture_feature_enabled && self.is_literal {
+                            // Treat the name as a variable captured from the surrounding scope.
+                            // The identifier takes the span (and thus the hygiene context) of
+                            // the placeholder inside the format string literal.
+                            let idx = self.args.len();
+                            self.arg_types.push(Vec::new());
+                            self.arg_unique_types.push(Vec::new());
+                            self.args.push(self.ecx.expr_ident(sp, Ident::new(name, sp)));
+                            self.names.insert(name, idx);
+                            self.verify_arg_type(Exact(idx), ty)
+                        } else {
+                            let msg = format!("there is no argument named `{}`", name);
+                            let mut err = self.ecx.struct_span_err(sp, &msg[..]);
+                            if capture_feature_enabled {
+                                err.note(&format!(
+                                    "did you intend to capture a variable `{}` from \
+                                     the surrounding scope?",
+                                    name
+                                ));
+                                err.note(
+                                    "to avoid ambiguity, `format_args!` cannot capture variables \
+                                     when the format string is expanded from a macro",
+                                );
+                            } else if self.ecx.parse_sess.unstable_features.is_nightly_build() {
+                                err.help(&format!(
+                                    "if you intended to capture `{}` from the surrounding scope, \
+                                     add `#![feature(format_args_capture)]` to the crate \
+                                     attributes",
+                                    name
+                                ));
+                            }
+                            err.emit();
+                        }
                     }
                 }
             }
@@ -927,6 +972,7 @@ pub fn expand_preparsed_format_args(
     }
 
     let arg_spans = parser.arg_places.iter().map(|span| fmt_span.from_inner(*span)).collect();
+    let is_literal = parser.is_literal;
 
     let named_pos: FxHashSet<usize> = names.values().cloned().collect();
 
@@ -952,6 +998,7 @@ pub fn expand_preparsed_format_args(
         invalid_refs: Vec::new(),
         arg_spans,
         arg_with_formatting: Vec::new(),
+        is_literal,
     };
 
     // This needs to happen *after* the Parser has consumed all pieces to create all the spans
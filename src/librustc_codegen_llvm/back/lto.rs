@@ -1036,7 +1036,8 @@ impl ThinLTOImportMaps {
 
 fn module_name_to_str(c_str: &CStr) -> &str {
     c_str.to_str().unwrap_or_else(|e| {
-        bug!("Encountered non-utf8 LLVM module name `{}`: {}", c_str.to_string_lossy(), e)
+        let lossy = String::from_utf8_lossy(c_str.to_bytes());
+        bug!("Encountered non-utf8 LLVM module name `{}`: {}", lossy, e)
     })
 }
 
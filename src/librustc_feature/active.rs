@@ -574,6 +574,40 @@ declare_features! (
     /// No longer treat an unsafe function as an unsafe block.
     (active, unsafe_block_in_unsafe_fn, "1.45.0", Some(71668), None),
 
+    /// Allows `let PAT = EXPR else { DIVERGING_BLOCK };` statements.
+    (active, let_else, "1.46.0", None, None),
+
+    /// Allows `if let` guards in match arms.
+    (active, if_let_guard, "1.46.0", None, None),
+
+    /// Allows destructuring assignments, e.g. `(a, b) = (b, a)`.
+    (active, destructuring_assignment, "1.46.0", None, None),
+
+    /// Allows implicit named arguments in format strings, e.g. `format!("{x}")`.
+    (active, format_args_capture, "1.46.0", None, None),
+
+    /// Allows closures to capture disjoint fields and other precise paths
+    /// instead of whole variables.
+    (active, capture_disjoint_fields, "1.46.0", None, None),
+
+    /// Allows inline `const { ... }` blocks in expressions and patterns.
+    (active, inline_const, "1.46.0", None, None),
+
+    /// Allows default values for const generic parameters, e.g. `struct Foo<const N: usize = 3>`.
+    (active, const_generics_defaults, "1.46.0", None, None),
+
+    /// Allows generic constant expressions in types, e.g. `[u8; N + 1]`, which are
+    /// required to be evaluatable by bounds like `where [(); N + 1]:`.
+    (active, const_evaluatable_checked, "1.46.0", None, None),
+
+    /// Allows the minimal subset of const generics which is planned for stabilization:
+    /// const parameters of integer, `bool` and `char` type used as standalone arguments.
+    (active, min_const_generics, "1.46.0", Some(44580), None),
+
+    /// Allows `async fn` in traits and trait impls, desugared to a generic associated type
+    /// that is defined by an `impl Future` in each implementation.
+    (active, async_fn_in_trait, "1.46.0", None, None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
@@ -590,4 +624,9 @@ pub const INCOMPLETE_FEATURES: &[Symbol] = &[
     sym::raw_dylib,
     sym::const_trait_impl,
     sym::const_trait_bound_opt_out,
+    sym::capture_disjoint_fields,
+    sym::inline_const,
+    sym::const_generics_defaults,
+    sym::const_evaluatable_checked,
+    sym::async_fn_in_trait,
 ];
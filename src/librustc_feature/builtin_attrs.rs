@@ -530,6 +530,11 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
         rustc_specialization_trait, Normal, template!(Word),
         "the `#[rustc_specialization_trait]` attribute is used to check specializations"
     ),
+    rustc_attr!(
+        rustc_strict_coherence, Normal, template!(Word),
+        "the `#[rustc_strict_coherence]` attribute lets coherence rely on the negative impls \
+         of a trait, which makes them a permanent promise"
+    ),
 
     // ==========================================================================
     // Internal attributes, Testing:
@@ -414,6 +414,8 @@ pub enum GenericParamKind<'hir> {
     },
     Const {
         ty: &'hir Ty<'hir>,
+        /// Optional default value for the const generic param
+        default: Option<AnonConst>,
     },
 }
 
@@ -1159,7 +1161,10 @@ pub struct Arm<'hir> {
 
 #[derive(RustcEncodable, RustcDecodable, Debug, HashStable_Generic)]
 pub enum Guard<'hir> {
+    /// `if <expr>`.
     If(&'hir Expr<'hir>),
+    /// `if let <pat> = <expr>`.
+    IfLet(&'hir Pat<'hir>, &'hir Expr<'hir>),
 }
 
 #[derive(RustcEncodable, RustcDecodable, Debug, HashStable_Generic)]
@@ -1378,6 +1383,7 @@ impl Expr<'_> {
         match self.kind {
             ExprKind::Box(_) => ExprPrecedence::Box,
             ExprKind::Array(_) => ExprPrecedence::Array,
+            ExprKind::ConstBlock(_) => ExprPrecedence::ConstBlock,
             ExprKind::Call(..) => ExprPrecedence::Call,
             ExprKind::MethodCall(..) => ExprPrecedence::MethodCall,
             ExprKind::Tup(_) => ExprPrecedence::Tup,
@@ -1450,6 +1456,7 @@ impl Expr<'_> {
             | ExprKind::Block(..)
             | ExprKind::Repeat(..)
             | ExprKind::Array(..)
+            | ExprKind::ConstBlock(..)
             | ExprKind::Break(..)
             | ExprKind::Continue(..)
             | ExprKind::Ret(..)
@@ -1552,6 +1559,8 @@ pub enum ExprKind<'hir> {
     Box(&'hir Expr<'hir>),
     /// An array (e.g., `[a, b, c, d]`).
     Array(&'hir [Expr<'hir>]),
+    /// An inline `const` block (e.g., `const { 1 + 2 }`), evaluated as an anonymous constant.
+    ConstBlock(AnonConst),
     /// A function call.
     ///
     /// The first field resolves to the function itself (usually an `ExprKind::Path`),
@@ -1700,6 +1709,9 @@ pub enum LocalSource {
     AsyncFn,
     /// A desugared `<expr>.await`.
     AwaitDesugar,
+    /// A desugared destructuring assignment, e.g. `(a, b) = (c, d)`.
+    /// The span is that of the `=` sign.
+    AssignDesugar(Span),
 }
 
 /// Hints at the original code for a `match _ { .. }`.
@@ -1723,6 +1735,8 @@ pub enum MatchSource {
     TryDesugar,
     /// A desugared `<expr>.await`.
     AwaitDesugar,
+    /// A desugared `let _ = _ else { .. };`.
+    LetElseDesugar,
 }
 
 impl MatchSource {
@@ -1735,6 +1749,7 @@ impl MatchSource {
             ForLoopDesugar => "for",
             TryDesugar => "?",
             AwaitDesugar => ".await",
+            LetElseDesugar => "let...else",
         }
     }
 }
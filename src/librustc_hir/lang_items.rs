@@ -133,6 +133,13 @@ language_item_table! {
     StrAllocImplItem,            "str_alloc",          str_alloc_impl,          Target::Impl;
     SliceAllocImplItem,          "slice_alloc",        slice_alloc_impl,        Target::Impl;
     SliceU8AllocImplItem,        "slice_u8_alloc",     slice_u8_alloc_impl,     Target::Impl;
+    CStrAllocImplItem,           "c_str_alloc",        c_str_alloc_impl,        Target::Impl;
+    ErrorAllocImplItem,          "error_alloc",        error_alloc_impl,        Target::Impl;
+    ErrorSendAllocImplItem,      "error_send_alloc",   error_send_alloc_impl,   Target::Impl;
+    ErrorSendSyncAllocImplItem,  "error_send_sync_alloc", error_send_sync_alloc_impl, Target::Impl;
+    ErrorStdImplItem,            "error_std",          error_std_impl,          Target::Impl;
+    ErrorSendStdImplItem,        "error_send_std",     error_send_std_impl,     Target::Impl;
+    ErrorSendSyncStdImplItem,    "error_send_sync_std", error_send_sync_std_impl, Target::Impl;
     ConstPtrImplItem,            "const_ptr",          const_ptr_impl,          Target::Impl;
     MutPtrImplItem,              "mut_ptr",            mut_ptr_impl,            Target::Impl;
     ConstSlicePtrImplItem,       "const_slice_ptr",    const_slice_ptr_impl,    Target::Impl;
@@ -164,6 +171,8 @@ language_item_table! {
     CloneTraitLangItem,          "clone",              clone_trait,             Target::Trait;
     SyncTraitLangItem,           "sync",               sync_trait,              Target::Trait;
     DiscriminantKindTraitLangItem,"discriminant_kind", discriminant_kind_trait, Target::Trait;
+    PointeeTraitLangItem,        "pointee_trait",      pointee_trait,           Target::Trait;
+    DynMetadataLangItem,         "dyn_metadata",       dyn_metadata,            Target::Struct;
     FreezeTraitLangItem,         "freeze",             freeze_trait,            Target::Trait;
 
     DropTraitLangItem,           "drop",               drop_trait,              Target::Trait;
@@ -198,6 +207,8 @@ language_item_table! {
 
     UnsafeCellTypeLangItem,      "unsafe_cell",        unsafe_cell_type,        Target::Struct;
     VaListTypeLangItem,          "va_list",            va_list,                 Target::Struct;
+    CStrTypeLangItem,            "c_str",              c_str_type,              Target::Struct;
+    ErrorTraitLangItem,          "error",              error_trait,             Target::Trait;
 
     DerefTraitLangItem,          "deref",              deref_trait,             Target::Trait;
     DerefMutTraitLangItem,       "deref_mut",          deref_mut_trait,         Target::Trait;
@@ -551,6 +551,11 @@ trait UnusedDelimLint {
     fn check_stmt(&mut self, cx: &EarlyContext<'_>, s: &ast::Stmt) {
         match s.kind {
             StmtKind::Local(ref local) if Self::LINT_EXPR_IN_PATTERN_MATCHING_CTX => {
+                // The delimiters around the initializer of a `let`-`else` may be
+                // required to disambiguate it from the `else` block.
+                if local.els.is_some() {
+                    return;
+                }
                 if let Some(ref value) = local.init {
                     self.check_unused_delims_expr(
                         cx,
@@ -1612,12 +1612,13 @@ impl EncodeContext<'tcx> {
                         default.is_some(),
                     );
                 }
-                GenericParamKind::Const { .. } => {
-                    self.encode_info_for_generic_param(
-                        def_id.to_def_id(),
-                        EntryKind::ConstParam,
-                        true,
-                    );
+                GenericParamKind::Const { ref default, .. } => {
+                    let def_id = def_id.to_def_id();
+                    self.encode_info_for_generic_param(def_id, EntryKind::ConstParam, true);
+                    if default.is_some() {
+                        let default = self.tcx.const_param_default(def_id);
+                        record!(self.tables.const_defaults[def_id] <- default)
+                    }
                 }
             }
         }
@@ -262,6 +262,7 @@ define_tables! {
     const_stability: Table<DefIndex, Lazy<attr::ConstStability>>,
     deprecation: Table<DefIndex, Lazy<attr::Deprecation>>,
     ty: Table<DefIndex, Lazy!(Ty<'tcx>)>,
+    const_defaults: Table<DefIndex, Lazy!(&'tcx ty::Const<'tcx>)>,
     fn_sig: Table<DefIndex, Lazy!(ty::PolyFnSig<'tcx>)>,
     impl_trait_ref: Table<DefIndex, Lazy!(ty::TraitRef<'tcx>)>,
     inherent_impls: Table<DefIndex, Lazy<[DefIndex]>>,
@@ -39,6 +39,7 @@ use std::{iter, mem, option};
 use self::predecessors::{PredecessorCache, Predecessors};
 pub use self::query::*;
 
+pub mod abstract_const;
 pub mod interpret;
 pub mod mono;
 mod predecessors;
@@ -95,6 +95,12 @@ rustc_queries! {
             cache_on_disk_if { key.is_local() }
         }
 
+        /// Given the `DefId` of a const generic parameter with a default, computes
+        /// that default, e.g. `3` for `N` in `struct Buffer<const N: usize = 3>`.
+        query const_param_default(param: DefId) -> &'tcx ty::Const<'tcx> {
+            desc { |tcx| "computing const default for `{}`", tcx.def_path_str(param) }
+        }
+
         query analysis(key: CrateNum) -> Result<(), ErrorReported> {
             eval_always
             desc { "running analysis passes on this crate" }
@@ -190,6 +196,12 @@ rustc_queries! {
             no_hash
         }
 
+        /// Try to build an abstract representation of the given constant, used to
+        /// check generic constant expressions like `N + 1` for equality.
+        query mir_abstract_const(key: DefId) -> Option<&'tcx [mir::abstract_const::Node<'tcx>]> {
+            desc { |tcx| "building an abstract representation for `{}`", tcx.def_path_str(key) }
+        }
+
         query mir_validated(key: LocalDefId) ->
             (
                 Steal<mir::Body<'tcx>>,
@@ -414,6 +414,9 @@ pub enum Vtable<'tcx, N> {
     /// Vtable for a builtin `DeterminantKind` trait implementation.
     VtableDiscriminantKind(VtableDiscriminantKindData),
 
+    /// Vtable for a builtin `Pointee` trait implementation.
+    VtablePointee(VtablePointeeData),
+
     /// Vtable automatically generated for a generator.
     VtableGenerator(VtableGeneratorData<'tcx, N>),
 
@@ -433,6 +436,7 @@ impl<'tcx, N> Vtable<'tcx, N> {
             VtableObject(d) => d.nested,
             VtableFnPointer(d) => d.nested,
             VtableDiscriminantKind(VtableDiscriminantKindData) => Vec::new(),
+            VtablePointee(VtablePointeeData) => Vec::new(),
             VtableTraitAlias(d) => d.nested,
         }
     }
@@ -448,6 +452,7 @@ impl<'tcx, N> Vtable<'tcx, N> {
             VtableObject(d) => &d.nested[..],
             VtableFnPointer(d) => &d.nested[..],
             VtableDiscriminantKind(VtableDiscriminantKindData) => &[],
+            VtablePointee(VtablePointeeData) => &[],
             VtableTraitAlias(d) => &d.nested[..],
         }
     }
@@ -492,6 +497,7 @@ impl<'tcx, N> Vtable<'tcx, N> {
             VtableDiscriminantKind(VtableDiscriminantKindData) => {
                 VtableDiscriminantKind(VtableDiscriminantKindData)
             }
+            VtablePointee(VtablePointeeData) => VtablePointee(VtablePointeeData),
             VtableTraitAlias(d) => VtableTraitAlias(VtableTraitAliasData {
                 alias_def_id: d.alias_def_id,
                 substs: d.substs,
@@ -572,6 +578,9 @@ pub struct VtableFnPointerData<'tcx, N> {
 #[derive(Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable, HashStable, TypeFoldable)]
 pub struct VtableDiscriminantKindData;
 
+#[derive(Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable, HashStable, TypeFoldable)]
+pub struct VtablePointeeData;
+
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, HashStable, TypeFoldable)]
 pub struct VtableTraitAliasData<'tcx, N> {
     pub alias_def_id: DefId,
@@ -21,6 +21,8 @@ impl<'tcx, N: fmt::Debug> fmt::Debug for traits::Vtable<'tcx, N> {
 
             super::VtableDiscriminantKind(ref d) => write!(f, "{:?}", d),
 
+            super::VtablePointee(ref d) => write!(f, "{:?}", d),
+
             super::VtableObject(ref d) => write!(f, "{:?}", d),
 
             super::VtableParam(ref n) => write!(f, "VtableParam({:?})", n),
@@ -279,6 +281,9 @@ impl<'a, 'tcx> Lift<'tcx> for traits::Vtable<'a, ()> {
             traits::VtableDiscriminantKind(traits::VtableDiscriminantKindData) => {
                 Some(traits::VtableDiscriminantKind(traits::VtableDiscriminantKindData))
             }
+            traits::VtablePointee(traits::VtablePointeeData) => {
+                Some(traits::VtablePointee(traits::VtablePointeeData))
+            }
             traits::VtableParam(n) => Some(traits::VtableParam(n)),
             traits::VtableBuiltin(n) => Some(traits::VtableBuiltin(n)),
             traits::VtableObject(traits::VtableObjectData {
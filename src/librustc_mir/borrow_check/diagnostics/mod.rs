@@ -377,16 +377,29 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                     self.describe_field_from_ty(&ty, field, variant_index)
                 }
                 ty::Closure(def_id, _) | ty::Generator(def_id, _, _) => {
-                    // `tcx.upvars_mentioned(def_id)` returns an `Option`, which is `None` in case
-                    // the closure comes from another crate. But in that case we wouldn't
-                    // be borrowck'ing it, so we can just unwrap:
-                    let (&var_id, _) = self
-                        .infcx
-                        .tcx
-                        .upvars_mentioned(def_id)
-                        .unwrap()
-                        .get_index(field.index())
-                        .unwrap();
+                    let tables = self.infcx.tcx.typeck_tables_of(def_id.expect_local());
+                    // With `capture_disjoint_fields`, each field holds a captured
+                    // place, which we describe by the variable it's part of.
+                    let var_id = match tables.closure_min_captures.get(&def_id) {
+                        Some(min_captures) => min_captures
+                            .iter()
+                            .flat_map(|(&var_id, captures)| captures.iter().map(move |_| var_id))
+                            .nth(field.index())
+                            .unwrap(),
+                        // `tcx.upvars_mentioned(def_id)` returns an `Option`, which is `None` in
+                        // case the closure comes from another crate. But in that case we wouldn't
+                        // be borrowck'ing it, so we can just unwrap:
+                        None => {
+                            let (&var_id, _) = self
+                                .infcx
+                                .tcx
+                                .upvars_mentioned(def_id)
+                                .unwrap()
+                                .get_index(field.index())
+                                .unwrap();
+                            var_id
+                        }
+                    };
 
                     self.infcx.tcx.hir().name(var_id).to_string()
                 }
@@ -814,7 +827,23 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
         let expr = &self.infcx.tcx.hir().expect_expr(hir_id).kind;
         debug!("closure_span: hir_id={:?} expr={:?}", hir_id, expr);
         if let hir::ExprKind::Closure(.., body_id, args_span, _) = expr {
-            for (upvar, place) in self.infcx.tcx.upvars_mentioned(def_id)?.values().zip(places) {
+            let tables = self.infcx.tcx.typeck_tables_of(def_id.expect_local());
+            // With `capture_disjoint_fields`, there's an operand for each
+            // captured place rather than for each mentioned variable.
+            let upvar_spans: Vec<Span> = match tables.closure_min_captures.get(&def_id) {
+                Some(min_captures) => min_captures
+                    .values()
+                    .flat_map(|captures| captures.iter().map(|captured| captured.place.span))
+                    .collect(),
+                None => self
+                    .infcx
+                    .tcx
+                    .upvars_mentioned(def_id)?
+                    .values()
+                    .map(|upvar| upvar.span)
+                    .collect(),
+            };
+            for (upvar_span, place) in upvar_spans.into_iter().zip(places) {
                 match place {
                     Operand::Copy(place) | Operand::Move(place)
                         if target_place == place.as_ref() =>
@@ -822,7 +851,7 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                         debug!("closure_span: found captured local {:?}", place);
                         let body = self.infcx.tcx.hir().body(*body_id);
                         let generator_kind = body.generator_kind();
-                        return Some((*args_span, generator_kind, upvar.span));
+                        return Some((*args_span, generator_kind, upvar_span));
                     }
                     _ => {}
                 }
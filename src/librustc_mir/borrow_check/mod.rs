@@ -104,6 +104,25 @@ fn mir_borrowck(tcx: TyCtxt<'_>, def_id: LocalDefId) -> BorrowCheckResult<'_> {
     opt_closure_req
 }
 
+fn make_upvar<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    tables: &ty::TypeckTables<'tcx>,
+    var_hir_id: HirId,
+    capture: ty::UpvarCapture<'tcx>,
+) -> Upvar {
+    let by_ref = match capture {
+        ty::UpvarCapture::ByValue => false,
+        ty::UpvarCapture::ByRef(..) => true,
+    };
+    let mut upvar =
+        Upvar { name: tcx.hir().name(var_hir_id), var_hir_id, by_ref, mutability: Mutability::Not };
+    let bm = *tables.pat_binding_modes().get(var_hir_id).expect("missing binding mode");
+    if bm == ty::BindByValue(hir::Mutability::Mut) {
+        upvar.mutability = Mutability::Mut;
+    }
+    upvar
+}
+
 fn do_mir_borrowck<'a, 'tcx>(
     infcx: &InferCtxt<'a, 'tcx>,
     input_body: &Body<'tcx>,
@@ -139,31 +158,29 @@ fn do_mir_borrowck<'a, 'tcx>(
     if let Some(ErrorReported) = tables.tainted_by_errors {
         infcx.set_tainted_by_errors();
     }
-    let upvars: Vec<_> = tables
-        .closure_captures
-        .get(&def_id.to_def_id())
-        .into_iter()
-        .flat_map(|v| v.values())
-        .map(|upvar_id| {
-            let var_hir_id = upvar_id.var_path.hir_id;
-            let capture = tables.upvar_capture(*upvar_id);
-            let by_ref = match capture {
-                ty::UpvarCapture::ByValue => false,
-                ty::UpvarCapture::ByRef(..) => true,
-            };
-            let mut upvar = Upvar {
-                name: tcx.hir().name(var_hir_id),
-                var_hir_id,
-                by_ref,
-                mutability: Mutability::Not,
-            };
-            let bm = *tables.pat_binding_modes().get(var_hir_id).expect("missing binding mode");
-            if bm == ty::BindByValue(hir::Mutability::Mut) {
-                upvar.mutability = Mutability::Mut;
-            }
-            upvar
-        })
-        .collect();
+    let upvars: Vec<_> = match tables.closure_min_captures.get(&def_id.to_def_id()) {
+        // With `capture_disjoint_fields`, the closure holds a value for each
+        // captured place, named after the variable the place is part of.
+        Some(min_captures) => min_captures
+            .iter()
+            .flat_map(|(&var_hir_id, captures)| {
+                captures.iter().map(move |captured_place| (var_hir_id, captured_place.info))
+            })
+            .map(|(var_hir_id, capture_info)| {
+                make_upvar(tcx, tables, var_hir_id, capture_info.capture_kind)
+            })
+            .collect(),
+        None => tables
+            .closure_captures
+            .get(&def_id.to_def_id())
+            .into_iter()
+            .flat_map(|v| v.values())
+            .map(|upvar_id| {
+                let var_hir_id = upvar_id.var_path.hir_id;
+                make_upvar(tcx, tables, var_hir_id, tables.upvar_capture(*upvar_id))
+            })
+            .collect(),
+    };
 
     // Replace all regions with fresh inference variables. This
     // requires first making our own copy of the MIR. This copy will
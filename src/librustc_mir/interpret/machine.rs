@@ -373,9 +373,13 @@ pub macro compile_time_machine(<$mir: lifetime, $tcx: lifetime>) {
     type PointerTag = ();
     type ExtraFnVal = !;
 
-    type MemoryKind = !;
-    type MemoryMap = rustc_data_structures::fx::FxHashMap<AllocId, (MemoryKind<!>, Allocation)>;
-    const GLOBAL_KIND: Option<!> = None; // no copying of globals from `tcx` to machine memory
+    type MemoryKind = crate::const_eval::MemoryKind;
+    type MemoryMap = rustc_data_structures::fx::FxHashMap<
+        AllocId,
+        (MemoryKind<crate::const_eval::MemoryKind>, Allocation),
+    >;
+    // no copying of globals from `tcx` to machine memory
+    const GLOBAL_KIND: Option<crate::const_eval::MemoryKind> = None;
 
     type AllocExtra = ();
     type FrameExtra = ();
@@ -408,7 +412,7 @@ pub macro compile_time_machine(<$mir: lifetime, $tcx: lifetime>) {
         _memory_extra: &Self::MemoryExtra,
         _id: AllocId,
         alloc: Cow<'b, Allocation>,
-        _kind: Option<MemoryKind<!>>,
+        _kind: Option<MemoryKind<crate::const_eval::MemoryKind>>,
     ) -> (Cow<'b, Allocation<Self::PointerTag>>, Self::PointerTag) {
         // We do not use a tag so we can just cheaply forward the allocation
         (alloc, ())
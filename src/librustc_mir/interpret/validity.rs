@@ -227,15 +227,28 @@ impl<'rt, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> ValidityVisitor<'rt, 'mir, '
                 let mut name = None;
                 if let Some(def_id) = def_id.as_local() {
                     let tables = self.ecx.tcx.typeck_tables_of(def_id);
-                    if let Some(upvars) = tables.closure_captures.get(&def_id.to_def_id()) {
+                    let var_hir_id = match tables.closure_min_captures.get(&def_id.to_def_id()) {
+                        // With `capture_disjoint_fields`, each field holds a
+                        // captured place, which we name after its variable.
+                        Some(min_captures) => min_captures
+                            .iter()
+                            .flat_map(|(&var_hir_id, captures)| {
+                                captures.iter().map(move |_| var_hir_id)
+                            })
+                            .nth(field),
                         // Sometimes the index is beyond the number of upvars (seen
                         // for a generator).
-                        if let Some((&var_hir_id, _)) = upvars.get_index(field) {
-                            let node = self.ecx.tcx.hir().get(var_hir_id);
-                            if let hir::Node::Binding(pat) = node {
-                                if let hir::PatKind::Binding(_, _, ident, _) = pat.kind {
-                                    name = Some(ident.name);
-                                }
+                        None => tables
+                            .closure_captures
+                            .get(&def_id.to_def_id())
+                            .and_then(|upvars| upvars.get_index(field))
+                            .map(|(&var_hir_id, _)| var_hir_id),
+                    };
+                    if let Some(var_hir_id) = var_hir_id {
+                        let node = self.ecx.tcx.hir().get(var_hir_id);
+                        if let hir::Node::Binding(pat) = node {
+                            if let hir::PatKind::Binding(_, _, ident, _) = pat.kind {
+                                name = Some(ident.name);
                             }
                         }
                     }
@@ -7,6 +7,7 @@ use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
 use rustc_middle::mir::*;
 use rustc_middle::ty::cast::CastTy;
+use rustc_middle::ty::subst::SubstsRef;
 use rustc_middle::ty::{self, Instance, InstanceDef, TyCtxt};
 use rustc_span::Span;
 use rustc_trait_selection::traits::error_reporting::InferCtxtExt;
@@ -258,6 +259,23 @@ impl Validator<'mir, 'tcx> {
         self.check_op_spanned(op, span)
     }
 
+    /// Returns `true` if `callee` is a trait method and the current item has a `const` bound
+    /// for the trait reference it is called with, e.g. `T: Default` in a `const fn`.
+    fn has_const_trait_bound(&self, callee: DefId, substs: SubstsRef<'tcx>) -> bool {
+        let trait_id = match self.tcx.trait_of_item(callee) {
+            Some(trait_id) => trait_id,
+            None => return false,
+        };
+        let trait_ref = ty::TraitRef::from_method(self.tcx, trait_id, substs);
+
+        self.param_env.caller_bounds.iter().any(|pred| match pred.kind() {
+            ty::PredicateKind::Trait(bound, hir::Constness::Const) => {
+                bound.skip_binder().trait_ref == trait_ref
+            }
+            _ => false,
+        })
+    }
+
     fn check_static(&mut self, def_id: DefId, span: Span) {
         assert!(
             !self.tcx.is_thread_local_static(def_id),
@@ -551,12 +569,23 @@ impl Visitor<'tcx> for Validator<'mir, 'tcx> {
                 if self.tcx.features().const_trait_impl {
                     let instance = Instance::resolve(self.tcx, self.param_env, def_id, substs);
                     debug!("Resolving ({:?}) -> {:?}", def_id, instance);
-                    if let Ok(Some(func)) = instance {
-                        if let InstanceDef::Item(def_id) = func.def {
-                            if is_const_fn(self.tcx, def_id) {
+                    match instance {
+                        Ok(Some(func)) => {
+                            if let InstanceDef::Item(def_id) = func.def {
+                                if is_const_fn(self.tcx, def_id) {
+                                    return;
+                                }
+                            }
+                        }
+                        // The method is called on a generic type, so we can't know which impl
+                        // gets used. A `const` bound on the caller is enough here, whether the
+                        // impl is actually `const` gets checked once the call is evaluated.
+                        Ok(None) => {
+                            if self.has_const_trait_bound(def_id, substs) {
                                 return;
                             }
                         }
+                        Err(_) => {}
                     }
                 }
 
@@ -266,6 +266,8 @@ fn mir_validated(
     // Ensure that we compute the `mir_const_qualif` for constants at
     // this point, before we steal the mir-const result.
     let _ = tcx.mir_const_qualif(def_id.to_def_id());
+    // Same for the abstract representation of generic constants.
+    let _ = tcx.mir_abstract_const(def_id.to_def_id());
 
     let mut body = tcx.mir_const(def_id.to_def_id()).steal();
 
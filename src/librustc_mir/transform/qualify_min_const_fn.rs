@@ -52,6 +52,13 @@ pub fn is_min_const_fn(tcx: TyCtxt<'tcx>, def_id: DefId, body: &'a Body<'tcx>) -
                                 continue;
                             }
 
+                            // Allow `T: Trait`, which lets us call methods of `impl const Trait`
+                            if constness == hir::Constness::Const
+                                && feature_allowed(tcx, def_id, sym::const_trait_impl)
+                            {
+                                continue;
+                            }
+
                             let generics = tcx.generics_of(current);
                             let def = generics.type_param(p, tcx);
                             let span = tcx.def_span(def.def_id);
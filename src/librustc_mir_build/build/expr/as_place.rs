@@ -4,6 +4,9 @@ use crate::build::expr::category::Category;
 use crate::build::ForGuard::{OutsideGuard, RefWithinGuard};
 use crate::build::{BlockAnd, BlockAndExtension, Builder};
 use crate::hair::*;
+use rustc_hir::def_id::DefId;
+use rustc_hir::HirId;
+use rustc_middle::hir::place::ProjectionKind as HirProjectionKind;
 use rustc_middle::middle::region;
 use rustc_middle::mir::AssertKind::BoundsCheck;
 use rustc_middle::mir::*;
@@ -20,13 +23,134 @@ use rustc_index::vec::Idx;
 /// and `c` can be progressively pushed onto the place builder that is created when converting `a`.
 #[derive(Clone)]
 struct PlaceBuilder<'tcx> {
-    local: Local,
+    base: PlaceBase,
     projection: Vec<PlaceElem<'tcx>>,
 }
 
+/// The "outermost" place that a `PlaceBuilder` starts from.
+#[derive(Copy, Clone, Debug)]
+enum PlaceBase {
+    /// Denotes the start of a `Place`.
+    Local(Local),
+
+    /// A variable mentioned in the body of a closure that captures disjoint
+    /// fields (see `capture_disjoint_fields`).
+    ///
+    /// Which of the closure's captures holds the place being built is only known
+    /// once enough of the projections have been applied, so until then we keep
+    /// track of the root variable, the closure it's captured by, and the kind of
+    /// that closure. The builder is converted to start from a `PlaceBase::Local`
+    /// when it's turned into a `Place`.
+    Upvar { var_hir_id: HirId, closure_def_id: DefId, closure_kind: ty::ClosureKind },
+}
+
+/// Returns the index of the capture of `var_hir_id` by `closure_def_id` that
+/// `projections` start off of, along with the captured place.
+///
+/// The index is that of the capture within the closure, i.e. among the
+/// captures of all the root variables.
+fn find_capture_matching_projections<'a, 'tcx>(
+    tables: &'a ty::TypeckTables<'tcx>,
+    var_hir_id: HirId,
+    closure_def_id: DefId,
+    projections: &[PlaceElem<'tcx>],
+) -> Option<(usize, &'a ty::CapturedPlace<'tcx>)> {
+    let min_captures = tables.closure_min_captures.get(&closure_def_id)?;
+    let (root_index, _, root_variable_min_captures) = min_captures.get_full(&var_hir_id)?;
+
+    // The captures of the root variables before this one come first.
+    let offset: usize = min_captures.values().take(root_index).map(|captures| captures.len()).sum();
+
+    root_variable_min_captures
+        .iter()
+        .enumerate()
+        .find(|(_, capture)| {
+            capture.place.projections.len() <= projections.len()
+                && capture.place.projections.iter().zip(projections).all(|(hir_proj, elem)| {
+                    match (hir_proj.kind, elem) {
+                        (HirProjectionKind::Deref, ProjectionElem::Deref) => true,
+                        (HirProjectionKind::Field(index, _), ProjectionElem::Field(field, _)) => {
+                            field.index() == index as usize
+                        }
+                        _ => false,
+                    }
+                })
+        })
+        .map(|(index, capture)| (offset + index, capture))
+}
+
+/// Converts `from_builder` to start off of the closure environment if it
+/// starts off of an upvar, returning `None` if none of the closure's captures
+/// hold the place.
+fn to_upvars_resolved_place_builder<'a, 'tcx>(
+    from_builder: PlaceBuilder<'tcx>,
+    tcx: TyCtxt<'tcx>,
+    tables: &'a ty::TypeckTables<'tcx>,
+) -> Option<PlaceBuilder<'tcx>> {
+    let (var_hir_id, closure_def_id, closure_kind) = match from_builder.base {
+        PlaceBase::Local(_) => return Some(from_builder),
+        PlaceBase::Upvar { var_hir_id, closure_def_id, closure_kind } => {
+            (var_hir_id, closure_def_id, closure_kind)
+        }
+    };
+
+    let (capture_index, capture) = find_capture_matching_projections(
+        tables,
+        var_hir_id,
+        closure_def_id,
+        &from_builder.projection,
+    )?;
+
+    let closure_ty =
+        tables.node_type(tcx.hir().local_def_id_to_hir_id(closure_def_id.expect_local()));
+    let capture_ty = match closure_ty.kind {
+        ty::Closure(_, substs) => substs.as_closure().upvar_tys().nth(capture_index).unwrap(),
+        _ => bug!("upvar of non-closure type {:?}", closure_ty),
+    };
+
+    // The closure environment is the first argument, which is a reference to
+    // the closure for `Fn` and `FnMut` closures.
+    let mut upvar_resolved_place_builder = PlaceBuilder::from(Local::new(1));
+    if let ty::ClosureKind::Fn | ty::ClosureKind::FnMut = closure_kind {
+        upvar_resolved_place_builder = upvar_resolved_place_builder.deref();
+    }
+
+    upvar_resolved_place_builder =
+        upvar_resolved_place_builder.field(Field::new(capture_index), capture_ty);
+    if let ty::UpvarCapture::ByRef(_) = capture.info.capture_kind {
+        upvar_resolved_place_builder = upvar_resolved_place_builder.deref();
+    }
+
+    // The projections that the captured place is made of are replaced by the
+    // capture itself, the remaining ones still apply.
+    upvar_resolved_place_builder
+        .projection
+        .extend_from_slice(&from_builder.projection[capture.place.projections.len()..]);
+
+    Some(upvar_resolved_place_builder)
+}
+
 impl<'tcx> PlaceBuilder<'tcx> {
-    fn into_place(self, tcx: TyCtxt<'tcx>) -> Place<'tcx> {
-        Place { local: self.local, projection: tcx.intern_place_elems(&self.projection) }
+    fn into_place<'a>(
+        self,
+        tcx: TyCtxt<'tcx>,
+        tables: &'a ty::TypeckTables<'tcx>,
+    ) -> Place<'tcx> {
+        if let PlaceBase::Local(local) = self.base {
+            Place { local, projection: tcx.intern_place_elems(&self.projection) }
+        } else {
+            self.expect_upvars_resolved(tcx, tables).into_place(tcx, tables)
+        }
+    }
+
+    fn expect_upvars_resolved<'a>(
+        self,
+        tcx: TyCtxt<'tcx>,
+        tables: &'a ty::TypeckTables<'tcx>,
+    ) -> PlaceBuilder<'tcx> {
+        let base = self.base;
+        to_upvars_resolved_place_builder(self, tcx, tables)
+            .unwrap_or_else(|| bug!("no capture holds the place starting from {:?}", base))
     }
 
     fn field(self, f: Field, ty: Ty<'tcx>) -> Self {
@@ -49,7 +173,13 @@ impl<'tcx> PlaceBuilder<'tcx> {
 
 impl<'tcx> From<Local> for PlaceBuilder<'tcx> {
     fn from(local: Local) -> Self {
-        Self { local, projection: Vec::new() }
+        Self { base: PlaceBase::Local(local), projection: Vec::new() }
+    }
+}
+
+impl<'tcx> From<PlaceBase> for PlaceBuilder<'tcx> {
+    fn from(base: PlaceBase) -> Self {
+        Self { base, projection: Vec::new() }
     }
 }
 
@@ -71,7 +201,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         M: Mirror<'tcx, Output = Expr<'tcx>>,
     {
         let place_builder = unpack!(block = self.as_place_builder(block, expr));
-        block.and(place_builder.into_place(self.hir.tcx()))
+        block.and(place_builder.into_place(self.hir.tcx(), self.hir.tables()))
     }
 
     /// This is used when constructing a compound `Place`, so that we can avoid creating
@@ -98,7 +228,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         M: Mirror<'tcx, Output = Expr<'tcx>>,
     {
         let place_builder = unpack!(block = self.as_read_only_place_builder(block, expr));
-        block.and(place_builder.into_place(self.hir.tcx()))
+        block.and(place_builder.into_place(self.hir.tcx(), self.hir.tables()))
     }
 
     /// This is used when constructing a compound `Place`, so that we can avoid creating
@@ -171,6 +301,20 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 };
                 block.and(place_builder)
             }
+            ExprKind::UpvarRef { closure_def_id, var_hir_id } => {
+                let closure_hir_id =
+                    this.hir.tcx().hir().local_def_id_to_hir_id(closure_def_id.expect_local());
+                let closure_ty = this.hir.tables().node_type(closure_hir_id);
+                let closure_kind = match closure_ty.kind {
+                    ty::Closure(_, substs) => substs.as_closure().kind(),
+                    _ => bug!("upvar of non-closure type {:?}", closure_ty),
+                };
+                block.and(PlaceBuilder::from(PlaceBase::Upvar {
+                    var_hir_id,
+                    closure_def_id,
+                    closure_kind,
+                }))
+            }
 
             ExprKind::PlaceTypeAscription { source, user_ty } => {
                 let source = this.hir.mirror(source);
@@ -185,7 +329,8 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                             inferred_ty: expr.ty,
                         });
 
-                    let place = place_builder.clone().into_place(this.hir.tcx());
+                    let place =
+                        place_builder.clone().into_place(this.hir.tcx(), this.hir.tables());
                     this.cfg.push(
                         block,
                         Statement {
@@ -246,6 +391,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             | ExprKind::Borrow { .. }
             | ExprKind::AddressOf { .. }
             | ExprKind::Match { .. }
+            | ExprKind::LetElse { .. }
             | ExprKind::Loop { .. }
             | ExprKind::Block { .. }
             | ExprKind::Assign { .. }
@@ -300,6 +446,10 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         let base_place =
             unpack!(block = self.expr_as_place(block, lhs, mutability, Some(fake_borrow_temps),));
 
+        // The index is applied to the place within the closure environment, so
+        // any upvar the base starts off of has to be resolved now.
+        let base_place = base_place.expect_upvars_resolved(self.hir.tcx(), self.hir.tables());
+
         // Making this a *fresh* temporary means we do not have to worry about
         // the index changing later: Nothing will ever change this temporary.
         // The "retagging" transformation (for Stacked Borrows) relies on this.
@@ -307,7 +457,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
 
         block = self.bounds_check(
             block,
-            base_place.clone().into_place(self.hir.tcx()),
+            base_place.clone().into_place(self.hir.tcx(), self.hir.tables()),
             idx,
             expr_span,
             source_info,
@@ -365,8 +515,11 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         source_info: SourceInfo,
     ) {
         let tcx = self.hir.tcx();
-        let place_ty =
-            Place::ty_from(base_place.local, &base_place.projection, &self.local_decls, tcx);
+        let local = match base_place.base {
+            PlaceBase::Local(local) => local,
+            PlaceBase::Upvar { .. } => bug!("expected PlaceBuilder to have been upvar resolved"),
+        };
+        let place_ty = Place::ty_from(local, &base_place.projection, &self.local_decls, tcx);
         if let ty::Slice(_) = place_ty.ty.kind {
             // We need to create fake borrows to ensure that the bounds
             // check that we just did stays valid. Since we can't assign to
@@ -376,7 +529,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 match elem {
                     ProjectionElem::Deref => {
                         let fake_borrow_deref_ty = Place::ty_from(
-                            base_place.local,
+                            local,
                             &base_place.projection[..idx],
                             &self.local_decls,
                             tcx,
@@ -394,14 +547,14 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                             Rvalue::Ref(
                                 tcx.lifetimes.re_erased,
                                 BorrowKind::Shallow,
-                                Place { local: base_place.local, projection },
+                                Place { local, projection },
                             ),
                         );
                         fake_borrow_temps.push(fake_borrow_temp);
                     }
                     ProjectionElem::Index(_) => {
                         let index_ty = Place::ty_from(
-                            base_place.local,
+                            local,
                             &base_place.projection[..idx],
                             &self.local_decls,
                             tcx,
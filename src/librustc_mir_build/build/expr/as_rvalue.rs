@@ -237,6 +237,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             | ExprKind::StaticRef { .. }
             | ExprKind::Block { .. }
             | ExprKind::Match { .. }
+            | ExprKind::LetElse { .. }
             | ExprKind::NeverToAny { .. }
             | ExprKind::Use { .. }
             | ExprKind::Borrow { .. }
@@ -249,6 +250,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             | ExprKind::Deref { .. }
             | ExprKind::Index { .. }
             | ExprKind::VarRef { .. }
+            | ExprKind::UpvarRef { .. }
             | ExprKind::SelfRef
             | ExprKind::Break { .. }
             | ExprKind::Continue { .. }
@@ -386,41 +388,19 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         let arg_place = unpack!(block = this.as_place(block, arg));
 
         let mutability = match arg_place.as_ref() {
-            PlaceRef { local, projection: &[] } => this.local_decls[local].mutability,
-            PlaceRef { local, projection: &[ProjectionElem::Deref] } => {
-                debug_assert!(
-                    this.local_decls[local].is_ref_for_guard(),
-                    "Unexpected capture place",
-                );
-                this.local_decls[local].mutability
-            }
-            PlaceRef {
-                local,
-                projection: &[ref proj_base @ .., ProjectionElem::Field(upvar_index, _)],
-            }
+            // Capturing (a place within) one of the captures of the enclosing
+            // closure: the capture is as mutable as that one.
+            PlaceRef { local, projection: &[ProjectionElem::Field(upvar_index, _), ..] }
             | PlaceRef {
                 local,
-                projection:
-                    &[ref proj_base @ .., ProjectionElem::Field(upvar_index, _), ProjectionElem::Deref],
-            } => {
-                let place = PlaceRef { local, projection: proj_base };
-
-                // Not projected from the implicit `self` in a closure.
-                debug_assert!(
-                    match place.local_or_deref_local() {
-                        Some(local) => local == Local::new(1),
-                        None => false,
-                    },
-                    "Unexpected capture place"
-                );
-                // Not in a closure
-                debug_assert!(
-                    this.upvar_mutbls.len() > upvar_index.index(),
-                    "Unexpected capture place"
-                );
+                projection: &[ProjectionElem::Deref, ProjectionElem::Field(upvar_index, _), ..],
+            } if local == Local::new(1) && !this.upvar_mutbls.is_empty() => {
                 this.upvar_mutbls[upvar_index.index()]
             }
-            _ => bug!("Unexpected capture place"),
+            // Capturing (a place within) a local variable, or a binding within
+            // a match guard (which is a reference to the bound value): the
+            // capture is as mutable as the local.
+            PlaceRef { local, .. } => this.local_decls[local].mutability,
         };
 
         let borrow_kind = match mutability {
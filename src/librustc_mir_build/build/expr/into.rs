@@ -51,6 +51,9 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             ExprKind::Match { scrutinee, arms } => {
                 this.match_expr(destination, expr_span, block, scrutinee, arms)
             }
+            ExprKind::LetElse { scrutinee, then_arm, else_arm } => {
+                this.let_else_expr(destination, expr_span, block, scrutinee, then_arm, else_arm)
+            }
             ExprKind::NeverToAny { source } => {
                 let source = this.hir.mirror(source);
                 let is_call = match source.kind {
@@ -393,6 +396,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
 
             // Avoid creating a temporary
             ExprKind::VarRef { .. }
+            | ExprKind::UpvarRef { .. }
             | ExprKind::SelfRef
             | ExprKind::PlaceTypeAscription { .. }
             | ExprKind::ValueTypeAscription { .. } => {
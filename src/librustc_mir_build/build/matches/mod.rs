@@ -112,6 +112,112 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         )
     }
 
+    /// Lowers the `match` that `let <pat> = <init> else { <els> }; <tail>`
+    /// desugars to.
+    ///
+    /// Unlike in a plain `match`, the temporaries of `<init>` are dropped as
+    /// soon as `<pat>` has been tested, before `<tail>` or `<els>` runs. The
+    /// arm holding `<tail>` is entered before `<init>` is evaluated, so that
+    /// the temporaries borrowed by the bindings can be scheduled there, as
+    /// for the initializer of a `let`.
+    crate fn let_else_expr(
+        &mut self,
+        destination: Place<'tcx>,
+        span: Span,
+        block: BasicBlock,
+        scrutinee: ExprRef<'tcx>,
+        then_arm: Arm<'tcx>,
+        else_arm: Arm<'tcx>,
+    ) -> BlockAnd<()> {
+        let outer_source_info = self.source_info(span);
+        let scrutinee_span = scrutinee.span();
+        let then_source_info = self.source_info(then_arm.span);
+        let then_scope = (then_arm.scope, then_source_info);
+
+        let then_end;
+        let else_block = unpack!(then_end = self.in_scope(then_scope, then_arm.lint_level, |this| {
+            let mut block = block;
+
+            // The scrutinee is a terminating scope. Enter its scopes here
+            // rather than in `as_place`, so that both arms can leave them.
+            let mut scrutinee = this.hir.mirror(scrutinee);
+            let mut scrutinee_scopes = vec![];
+            while let ExprKind::Scope { region_scope, value, .. } = scrutinee.kind {
+                let region_scope = (region_scope, this.source_info(scrutinee.span));
+                this.push_scope(region_scope);
+                scrutinee_scopes.push(region_scope);
+                scrutinee = this.hir.mirror(value);
+            }
+            let scrutinee = ExprRef::Mirror(Box::new(scrutinee));
+            let scrutinee_place =
+                unpack!(block = this.lower_scrutinee(block, scrutinee, scrutinee_span));
+
+            let mut then_candidate = Candidate::new(scrutinee_place, &then_arm.pattern, false);
+            let mut else_candidate = Candidate::new(scrutinee_place, &else_arm.pattern, false);
+            let fake_borrow_temps = this.lower_match_tree(
+                block,
+                scrutinee_span,
+                false,
+                &mut [&mut then_candidate, &mut else_candidate],
+            );
+
+            let mut else_block = this.bind_pattern(
+                outer_source_info,
+                else_candidate,
+                None,
+                &fake_borrow_temps,
+                scrutinee_span,
+                None,
+            );
+            let scope = this.declare_bindings(
+                None,
+                then_arm.span,
+                &then_arm.pattern,
+                ArmHasGuard(false),
+                Some((Some(&scrutinee_place), scrutinee_span)),
+            );
+            let mut then_block = this.bind_pattern(
+                outer_source_info,
+                then_candidate,
+                None,
+                &fake_borrow_temps,
+                scrutinee_span,
+                None,
+            );
+
+            // Drop the temporaries of the scrutinee on both paths.
+            for &region_scope in scrutinee_scopes.iter().rev() {
+                let next = this.cfg.start_new_block();
+                this.exit_top_scope(else_block, next, region_scope.1);
+                else_block = next;
+                unpack!(then_block = this.pop_scope(region_scope, then_block));
+            }
+            // `<els>` runs outside of the arm holding `<tail>`.
+            let else_entry = this.cfg.start_new_block();
+            this.exit_top_scope(else_block, else_entry, then_source_info);
+
+            if let Some(source_scope) = scope {
+                this.source_scope = source_scope;
+            }
+            let body = this.hir.mirror(then_arm.body.clone());
+            unpack!(then_block = this.into(destination, then_block, body));
+            then_block.and(else_entry)
+        }));
+
+        let else_source_info = self.source_info(else_arm.span);
+        let else_scope = (else_arm.scope, else_source_info);
+        let else_end = self.in_scope(else_scope, else_arm.lint_level, |this| {
+            let body = this.hir.mirror(else_arm.body.clone());
+            this.into(destination, else_block, body)
+        });
+
+        let end_block = self.cfg.start_new_block();
+        self.cfg.goto(then_end, outer_source_info, end_block);
+        self.cfg.goto(unpack!(else_end), outer_source_info, end_block);
+
+        end_block.unit()
+    }
+
     /// Evaluate the scrutinee and add the fake read of it.
     fn lower_scrutinee(
         &mut self,
@@ -241,6 +347,14 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                         ArmHasGuard(arm.guard.is_some()),
                         Some((Some(&scrutinee_place), scrutinee_span)),
                     );
+                    // The bindings of an `if let` guard are declared once per arm, even though
+                    // the guard itself is lowered once per candidate of an or-pattern.
+                    let scope = match arm.guard {
+                        Some(Guard::IfLet(ref pat, _)) => {
+                            this.declare_bindings(scope, arm.span, pat, ArmHasGuard(false), None)
+                        }
+                        _ => scope,
+                    };
 
                     let arm_block = this.bind_pattern(
                         outer_source_info,
@@ -1723,13 +1837,63 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
 
             // the block to branch to if the guard fails; if there is no
             // guard, this block is simply unreachable
-            let guard = match guard {
-                Guard::If(e) => self.hir.mirror(e.clone()),
+            let (guard_span, (post_guard_block, otherwise_post_guard_block)) = match guard {
+                Guard::If(e) => {
+                    let e = self.hir.mirror(e.clone());
+                    let source_info = self.source_info(e.span);
+                    (e.span, self.test_bool(block, e, source_info))
+                }
+                Guard::IfLet(pat, scrutinee) => {
+                    let scrutinee_span = scrutinee.span();
+                    // The scrutinee is a terminating scope, but its temporaries
+                    // have to outlive the match on `pat`. So we enter its scopes
+                    // here and leave them on both exits of the guard below.
+                    let mut scrutinee = self.hir.mirror(scrutinee.clone());
+                    let mut guard_scopes = vec![];
+                    while let ExprKind::Scope { region_scope, value, .. } = scrutinee.kind {
+                        let region_scope = (region_scope, self.source_info(scrutinee.span));
+                        self.push_scope(region_scope);
+                        guard_scopes.push(region_scope);
+                        scrutinee = self.hir.mirror(value);
+                    }
+                    let scrutinee_place = unpack!(
+                        block = self.lower_scrutinee(block, scrutinee.to_ref(), scrutinee_span)
+                    );
+                    let mut guard_candidate = Candidate::new(scrutinee_place, pat, false);
+                    let wildcard = Pat::wildcard_from_ty(pat.ty);
+                    let mut otherwise_candidate = Candidate::new(scrutinee_place, &wildcard, false);
+                    let fake_borrow_temps = self.lower_match_tree(
+                        block,
+                        pat.span,
+                        false,
+                        &mut [&mut guard_candidate, &mut otherwise_candidate],
+                    );
+                    // The bindings were already declared by `lower_match_arms`.
+                    let mut post_guard_block = self.bind_pattern(
+                        self.source_info(pat.span),
+                        guard_candidate,
+                        None,
+                        &fake_borrow_temps,
+                        scrutinee_span,
+                        None,
+                    );
+                    let mut otherwise_post_guard_block =
+                        otherwise_candidate.pre_binding_block.unwrap();
+                    // Drop the temporaries of the scrutinee, and whatever was not
+                    // moved into the bindings, before running the arm body or
+                    // moving on to the next arm.
+                    while let Some(region_scope) = guard_scopes.pop() {
+                        let outside_scope = self.cfg.start_new_block();
+                        let source_info = region_scope.1;
+                        self.exit_top_scope(otherwise_post_guard_block, outside_scope, source_info);
+                        otherwise_post_guard_block = outside_scope;
+                        unpack!(post_guard_block = self.pop_scope(region_scope, post_guard_block));
+                    }
+                    (scrutinee_span, (post_guard_block, otherwise_post_guard_block))
+                }
             };
-            let source_info = self.source_info(guard.span);
-            let guard_end = self.source_info(tcx.sess.source_map().end_point(guard.span));
-            let (post_guard_block, otherwise_post_guard_block) =
-                self.test_bool(block, guard, source_info);
+            let source_info = self.source_info(guard_span);
+            let guard_end = self.source_info(tcx.sess.source_map().end_point(guard_span));
             let guard_frame = self.guard_context.pop().unwrap();
             debug!("Exiting guard building context with locals: {:?}", guard_frame);
 
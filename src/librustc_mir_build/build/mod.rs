@@ -808,11 +808,29 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 _ => span_bug!(self.fn_span, "upvars with non-closure env ty {:?}", closure_ty),
             };
             let upvar_tys = upvar_substs.upvar_tys();
-            let upvars_with_tys = upvars.iter().zip(upvar_tys);
-            self.upvar_mutbls = upvars_with_tys
+
+            // With `capture_disjoint_fields`, the closure holds a value for each
+            // captured place, which may only be part of a variable.
+            let captures: Vec<_> = match hir_tables.closure_min_captures.get(&fn_def_id) {
+                Some(min_captures) => min_captures
+                    .iter()
+                    .flat_map(|(&var_id, captures)| {
+                        captures.iter().map(move |captured_place| {
+                            let is_whole_var = captured_place.place.projections.is_empty();
+                            (var_id, captured_place.info.capture_kind, is_whole_var)
+                        })
+                    })
+                    .collect(),
+                None => upvars
+                    .iter()
+                    .map(|(&var_id, &upvar_id)| (var_id, hir_tables.upvar_capture(upvar_id), true))
+                    .collect(),
+            };
+            self.upvar_mutbls = captures
+                .into_iter()
+                .zip(upvar_tys)
                 .enumerate()
-                .map(|(i, ((&var_id, &upvar_id), ty))| {
-                    let capture = hir_tables.upvar_capture(upvar_id);
+                .map(|(i, ((var_id, capture, is_whole_var), ty))| {
 
                     let mut mutability = Mutability::Not;
                     let mut name = kw::Invalid;
@@ -838,14 +856,18 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                         }
                     };
 
-                    self.var_debug_info.push(VarDebugInfo {
-                        name,
-                        source_info: SourceInfo::outermost(tcx_hir.span(var_id)),
-                        place: Place {
-                            local: closure_env_arg,
-                            projection: tcx.intern_place_elems(&projs),
-                        },
-                    });
+                    // A capture of part of a variable doesn't hold the
+                    // variable, so there's nothing to describe it with.
+                    if is_whole_var {
+                        self.var_debug_info.push(VarDebugInfo {
+                            name,
+                            source_info: SourceInfo::outermost(tcx_hir.span(var_id)),
+                            place: Place {
+                                local: closure_env_arg,
+                                projection: tcx.intern_place_elems(&projs),
+                            },
+                        });
+                    }
 
                     mutability
                 })
@@ -6,6 +6,7 @@ use crate::hair::*;
 use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
 use rustc_index::vec::Idx;
+use rustc_middle::hir::place::{PlaceBase as HirPlaceBase, ProjectionKind as HirProjectionKind};
 use rustc_middle::mir::interpret::Scalar;
 use rustc_middle::mir::BorrowKind;
 use rustc_middle::ty::adjustment::{
@@ -384,14 +385,24 @@ fn make_mirror_unadjusted<'a, 'tcx>(
                     span_bug!(expr.span, "closure expr w/o closure type: {:?}", closure_ty);
                 }
             };
-            let upvars = cx
-                .tcx
-                .upvars_mentioned(def_id)
-                .iter()
-                .flat_map(|upvars| upvars.iter())
-                .zip(substs.upvar_tys())
-                .map(|((&var_hir_id, _), ty)| capture_upvar(cx, expr, var_hir_id, ty))
-                .collect();
+            let upvars = match cx.tables().closure_min_captures.get(&def_id) {
+                // With `capture_disjoint_fields`, the closure holds a value for
+                // each captured place rather than for each mentioned variable.
+                Some(min_captures) => min_captures
+                    .values()
+                    .flat_map(|captures| captures.iter())
+                    .zip(substs.upvar_tys())
+                    .map(|(captured_place, ty)| capture_place(cx, expr, captured_place, ty))
+                    .collect(),
+                None => cx
+                    .tcx
+                    .upvars_mentioned(def_id)
+                    .iter()
+                    .flat_map(|upvars| upvars.iter())
+                    .zip(substs.upvar_tys())
+                    .map(|((&var_hir_id, _), ty)| capture_upvar(cx, expr, var_hir_id, ty))
+                    .collect(),
+            };
             ExprKind::Closure { closure_id: def_id, substs, upvars, movability }
         }
 
@@ -522,6 +533,13 @@ fn make_mirror_unadjusted<'a, 'tcx>(
             inputs: asm.inputs_exprs.to_ref(),
         },
 
+        hir::ExprKind::ConstBlock(ref anon_const) => {
+            let anon_const_def_id = cx.tcx.hir().local_def_id(anon_const.hir_id);
+            let literal = ty::Const::from_anon_const(cx.tcx, anon_const_def_id);
+
+            ExprKind::Literal { literal, user_ty: None }
+        }
+
         // Now comes the rote stuff:
         hir::ExprKind::Repeat(ref v, ref count) => {
             let count_def_id = cx.tcx.hir().local_def_id(count.hir_id);
@@ -543,6 +561,15 @@ fn make_mirror_unadjusted<'a, 'tcx>(
             },
             Err(err) => bug!("invalid loop id for continue: {}", err),
         },
+        hir::ExprKind::Match(
+            ref discr,
+            [then_arm, else_arm],
+            hir::MatchSource::LetElseDesugar,
+        ) => ExprKind::LetElse {
+            scrutinee: discr.to_ref(),
+            then_arm: convert_arm(cx, then_arm),
+            else_arm: convert_arm(cx, else_arm),
+        },
         hir::ExprKind::Match(ref discr, ref arms, _) => ExprKind::Match {
             scrutinee: discr.to_ref(),
             arms: arms.iter().map(|a| convert_arm(cx, a)).collect(),
@@ -776,6 +803,9 @@ fn convert_arm<'tcx>(cx: &mut Cx<'_, 'tcx>, arm: &'tcx hir::Arm<'tcx>) -> Arm<'t
         pattern: cx.pattern_from_hir(&arm.pat),
         guard: match arm.guard {
             Some(hir::Guard::If(ref e)) => Some(Guard::If(e.to_ref())),
+            Some(hir::Guard::IfLet(ref pat, ref e)) => {
+                Some(Guard::IfLet(cx.pattern_from_hir(pat), e.to_ref()))
+            }
             _ => None,
         },
         body: arm.body.to_ref(),
@@ -880,6 +910,13 @@ fn convert_var<'tcx>(
     expr: &'tcx hir::Expr<'tcx>,
     var_hir_id: hir::HirId,
 ) -> ExprKind<'tcx> {
+    // With `capture_disjoint_fields` the closure doesn't capture the variable
+    // as a whole, so which of its captures to use can only be decided once the
+    // full place being accessed is known.
+    if cx.tables().closure_min_captures.contains_key(&cx.body_owner) {
+        return ExprKind::UpvarRef { closure_def_id: cx.body_owner, var_hir_id };
+    }
+
     let upvar_index = cx
         .tables()
         .closure_captures
@@ -1086,8 +1123,52 @@ fn capture_upvar<'tcx>(
         span: closure_expr.span,
         kind: convert_var(cx, closure_expr, var_hir_id),
     };
+    capture_expr(closure_expr, captured_var, upvar_capture, upvar_ty)
+}
+
+/// Builds the operand that captures `captured_place` into a closure that
+/// captures disjoint fields.
+fn capture_place<'tcx>(
+    cx: &mut Cx<'_, 'tcx>,
+    closure_expr: &'tcx hir::Expr<'tcx>,
+    captured_place: &ty::CapturedPlace<'tcx>,
+    upvar_ty: Ty<'tcx>,
+) -> ExprRef<'tcx> {
+    let temp_lifetime = cx.region_scope_tree.temporary_scope(closure_expr.hir_id.local_id);
+    let var_hir_id = match captured_place.place.base {
+        HirPlaceBase::Upvar(upvar_id) => upvar_id.var_path.hir_id,
+        base => bug!("expected an upvar, found {:?}", base),
+    };
+    let mut captured = Expr {
+        temp_lifetime,
+        ty: captured_place.place.base_ty,
+        span: closure_expr.span,
+        kind: convert_var(cx, closure_expr, var_hir_id),
+    };
+    for projection in &captured_place.place.projections {
+        let kind = match projection.kind {
+            HirProjectionKind::Deref => ExprKind::Deref { arg: captured.to_ref() },
+            HirProjectionKind::Field(field, _) => {
+                ExprKind::Field { lhs: captured.to_ref(), name: Field::new(field as usize) }
+            }
+            HirProjectionKind::Index | HirProjectionKind::Subslice => {
+                bug!("unexpected projection {:?} in captured place", projection.kind)
+            }
+        };
+        captured = Expr { temp_lifetime, ty: projection.ty, span: closure_expr.span, kind };
+    }
+    capture_expr(closure_expr, captured, captured_place.info.capture_kind, upvar_ty)
+}
+
+/// Moves or borrows `captured` into a closure, depending on `upvar_capture`.
+fn capture_expr<'tcx>(
+    closure_expr: &'tcx hir::Expr<'tcx>,
+    captured: Expr<'tcx>,
+    upvar_capture: ty::UpvarCapture<'tcx>,
+    upvar_ty: Ty<'tcx>,
+) -> ExprRef<'tcx> {
     match upvar_capture {
-        ty::UpvarCapture::ByValue => captured_var.to_ref(),
+        ty::UpvarCapture::ByValue => captured.to_ref(),
         ty::UpvarCapture::ByRef(upvar_borrow) => {
             let borrow_kind = match upvar_borrow.kind {
                 ty::BorrowKind::ImmBorrow => BorrowKind::Shared,
@@ -1095,10 +1176,10 @@ fn capture_upvar<'tcx>(
                 ty::BorrowKind::MutBorrow => BorrowKind::Mut { allow_two_phase_borrow: false },
             };
             Expr {
-                temp_lifetime,
+                temp_lifetime: captured.temp_lifetime,
                 ty: upvar_ty,
                 span: closure_expr.span,
-                kind: ExprKind::Borrow { borrow_kind, arg: captured_var.to_ref() },
+                kind: ExprKind::Borrow { borrow_kind, arg: captured.to_ref() },
             }
             .to_ref()
         }
@@ -185,6 +185,12 @@ crate enum ExprKind<'tcx> {
         scrutinee: ExprRef<'tcx>,
         arms: Vec<Arm<'tcx>>,
     },
+    // The `match` that a `let`-`else` statement desugars to.
+    LetElse {
+        scrutinee: ExprRef<'tcx>,
+        then_arm: Arm<'tcx>,
+        else_arm: Arm<'tcx>,
+    },
     Block {
         body: &'tcx hir::Block<'tcx>,
     },
@@ -208,6 +214,16 @@ crate enum ExprKind<'tcx> {
     VarRef {
         id: hir::HirId,
     },
+    /// Used to represent upvars mentioned in a closure/generator body when the
+    /// closure captures disjoint places (see `capture_disjoint_fields`). The
+    /// place is resolved against the closure's captures while building MIR.
+    UpvarRef {
+        /// DefId of the closure/generator
+        closure_def_id: DefId,
+
+        /// HirId of the root variable
+        var_hir_id: hir::HirId,
+    },
     /// first argument, used for self in a closure
     SelfRef,
     Borrow {
@@ -328,6 +344,7 @@ crate struct Arm<'tcx> {
 #[derive(Clone, Debug)]
 crate enum Guard<'tcx> {
     If(ExprRef<'tcx>),
+    IfLet(Pat<'tcx>, ExprRef<'tcx>),
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -68,6 +68,7 @@ impl<'tcx> Visitor<'tcx> for MatchVisitor<'_, 'tcx> {
             hir::LocalSource::ForLoopDesugar => ("`for` loop binding", None),
             hir::LocalSource::AsyncFn => ("async fn binding", None),
             hir::LocalSource::AwaitDesugar => ("`await` future binding", None),
+            hir::LocalSource::AssignDesugar(_) => ("destructuring assignment binding", None),
         };
         self.check_irrefutable(&loc.pat, msg, sp);
         self.check_patterns(false, &loc.pat);
@@ -165,10 +166,23 @@ impl<'tcx> MatchVisitor<'_, 'tcx> {
         for arm in arms {
             // Check the arm for some things unrelated to exhaustiveness.
             self.check_patterns(arm.guard.is_some(), &arm.pat);
+            if let Some(hir::Guard::IfLet(ref pat, _)) = arm.guard {
+                self.check_patterns(false, pat);
+            }
         }
 
         let mut cx = self.new_cx(scrut.hir_id);
 
+        for arm in arms {
+            if let Some(hir::Guard::IfLet(ref pat, _)) = arm.guard {
+                let mut guard_errors = false;
+                let tpat = self.lower_pattern(&mut cx, pat, &mut guard_errors).0;
+                if !guard_errors {
+                    check_if_let_guard(&mut cx, tpat, pat.hir_id);
+                }
+            }
+        }
+
         let mut have_errors = false;
 
         let inlined_arms: Vec<_> = arms
@@ -239,6 +253,14 @@ impl<'tcx> MatchVisitor<'_, 'tcx> {
                     format!("if {} {{ /* */ }}", &snippet[..snippet.len() - 1]),
                     Applicability::HasPlaceholders,
                 );
+                if self.tcx.features().let_else {
+                    err.span_suggestion(
+                        span,
+                        "you might want to use `let else` to handle the variant that isn't matched",
+                        format!("{} else {{ todo!() }};", &snippet[..snippet.len() - 1]),
+                        Applicability::HasPlaceholders,
+                    );
+                }
             }
             err.note(
                 "for more information, visit \
@@ -350,12 +372,29 @@ fn irrefutable_let_pattern(tcx: TyCtxt<'_>, span: Span, id: HirId, source: hir::
         let msg = match source {
             hir::MatchSource::IfLetDesugar { .. } => "irrefutable if-let pattern",
             hir::MatchSource::WhileLetDesugar => "irrefutable while-let pattern",
+            hir::MatchSource::LetElseDesugar => "irrefutable let-else pattern",
             _ => bug!(),
         };
         lint.build(msg).emit()
     });
 }
 
+/// Check that the pattern of an `if let` guard is refutable.
+fn check_if_let_guard<'p, 'tcx>(
+    cx: &mut MatchCheckCtxt<'p, 'tcx>,
+    pat: &'p super::Pat<'tcx>,
+    pat_id: HirId,
+) {
+    let mut matrix = Matrix::empty();
+    matrix.push(PatStack::from_pattern(pat));
+    if check_not_useful(cx, pat.ty, &matrix, pat_id).is_ok() {
+        // The wildcard pattern isn't reachable, so the guard always matches.
+        cx.tcx.struct_span_lint_hir(IRREFUTABLE_LET_PATTERNS, pat_id, pat.span, |lint| {
+            lint.build("irrefutable if-let guard").emit()
+        });
+    }
+}
+
 /// Check for unreachable patterns.
 fn check_arms<'p, 'tcx>(
     cx: &mut MatchCheckCtxt<'p, 'tcx>,
@@ -371,7 +410,9 @@ fn check_arms<'p, 'tcx>(
                 match source {
                     hir::MatchSource::IfDesugar { .. } | hir::MatchSource::WhileDesugar => bug!(),
 
-                    hir::MatchSource::IfLetDesugar { .. } | hir::MatchSource::WhileLetDesugar => {
+                    hir::MatchSource::IfLetDesugar { .. }
+                    | hir::MatchSource::WhileLetDesugar
+                    | hir::MatchSource::LetElseDesugar => {
                         // Check which arm we're on.
                         match arm_index {
                             // The arm with the user-specified pattern.
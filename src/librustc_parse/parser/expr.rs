@@ -94,26 +94,9 @@ impl<'a> Parser<'a> {
         self.parse_expr().map(|value| AnonConst { id: DUMMY_NODE_ID, value })
     }
 
-    fn parse_expr_catch_underscore(&mut self) -> PResult<'a, P<Expr>> {
-        match self.parse_expr() {
-            Ok(expr) => Ok(expr),
-            Err(mut err) => match self.token.ident() {
-                Some((Ident { name: kw::Underscore, .. }, false))
-                    if self.look_ahead(1, |t| t == &token::Comma) =>
-                {
-                    // Special-case handling of `foo(_, _, _)`
-                    err.emit();
-                    self.bump();
-                    Ok(self.mk_expr(self.prev_token.span, ExprKind::Err, AttrVec::new()))
-                }
-                _ => Err(err),
-            },
-        }
-    }
-
     /// Parses a sequence of expressions delimited by parentheses.
     fn parse_paren_expr_seq(&mut self) -> PResult<'a, Vec<P<Expr>>> {
-        self.parse_paren_comma_seq(|p| p.parse_expr_catch_underscore()).map(|(r, _)| r)
+        self.parse_paren_comma_seq(|p| p.parse_expr()).map(|(r, _)| r)
     }
 
     /// Parses an expression, subject to the given restrictions.
@@ -958,6 +941,8 @@ impl<'a> Parser<'a> {
             })
         } else if self.eat_keyword(kw::Unsafe) {
             self.parse_block_expr(None, lo, BlockCheckMode::Unsafe(ast::UserProvided), attrs)
+        } else if self.check_inline_const(0) {
+            self.parse_const_block(attrs)
         } else if self.is_do_catch_block() {
             self.recover_do_catch(attrs)
         } else if self.is_try_block() {
@@ -971,6 +956,8 @@ impl<'a> Parser<'a> {
             self.parse_yield_expr(attrs)
         } else if self.eat_keyword(kw::Let) {
             self.parse_let_expr(attrs)
+        } else if self.eat_keyword(kw::Underscore) {
+            Ok(self.mk_expr(self.prev_token.span, ExprKind::Underscore, attrs))
         } else if !self.unclosed_delims.is_empty() && self.check(&token::Semi) {
             // Don't complain about bare semicolons after unclosed braces
             // recovery in order to keep the error count down. Fixing the
@@ -1003,6 +990,20 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses an inline const block: `const { ... }`.
+    pub(super) fn parse_const_block(&mut self, attrs: AttrVec) -> PResult<'a, P<Expr>> {
+        let lo = self.token.span;
+        self.expect_keyword(kw::Const)?;
+        let blk = self.parse_block()?;
+        let span = lo.to(blk.span);
+        self.sess.gated_spans.gate(sym::inline_const, span);
+        let anon_const = AnonConst {
+            id: DUMMY_NODE_ID,
+            value: self.mk_expr(blk.span, ExprKind::Block(blk, None), AttrVec::new()),
+        };
+        Ok(self.mk_expr(span, ExprKind::ConstBlock(anon_const), attrs))
+    }
+
     fn maybe_collect_tokens(
         &mut self,
         has_outer_attrs: bool,
@@ -1036,7 +1037,7 @@ impl<'a> Parser<'a> {
         let (es, trailing_comma) = match self.parse_seq_to_end(
             &token::CloseDelim(token::Paren),
             SeqSep::trailing_allowed(token::Comma),
-            |p| p.parse_expr_catch_underscore(),
+            |p| p.parse_expr(),
         ) {
             Ok(x) => x,
             Err(err) => return Ok(self.recover_seq_parse_error(token::Paren, lo, Err(err))),
@@ -1771,11 +1772,26 @@ impl<'a> Parser<'a> {
         Ok(self.mk_expr(lo.to(hi), ExprKind::Match(scrutinee, arms), attrs))
     }
 
+    /// Parses the optional `if COND` or `if let PAT = EXPR` guard of a match arm.
+    fn parse_arm_guard(&mut self) -> PResult<'a, Option<P<Expr>>> {
+        if !self.eat_keyword(kw::If) {
+            return Ok(None);
+        }
+        let if_span = self.prev_token.span;
+        let cond = self.parse_expr()?;
+        if let ExprKind::Let(..) = cond.kind {
+            // A top-level `let` in a guard is gated by `if_let_guard` instead of `let_chains`.
+            self.sess.gated_spans.ungate_last(sym::let_chains, cond.span);
+            self.sess.gated_spans.gate(sym::if_let_guard, if_span.to(cond.span));
+        }
+        Ok(Some(cond))
+    }
+
     pub(super) fn parse_arm(&mut self) -> PResult<'a, Arm> {
         let attrs = self.parse_outer_attributes()?;
         let lo = self.token.span;
         let pat = self.parse_top_pat(GateOr::No)?;
-        let guard = if self.eat_keyword(kw::If) { Some(self.parse_expr()?) } else { None };
+        let guard = self.parse_arm_guard()?;
         let arrow_span = self.token.span;
         self.expect(&token::FatArrow)?;
         let arm_start_span = self.token.span;
@@ -1947,7 +1963,7 @@ impl<'a> Parser<'a> {
     ) -> PResult<'a, P<Expr>> {
         self.bump();
         let mut fields = Vec::new();
-        let mut base = None;
+        let mut base = ast::StructRest::None;
         let mut recover_async = false;
 
         attrs.extend(self.parse_inner_attributes()?);
@@ -1962,8 +1978,14 @@ impl<'a> Parser<'a> {
         while self.token != token::CloseDelim(token::Brace) {
             if self.eat(&token::DotDot) {
                 let exp_span = self.prev_token.span;
+                // A bare `..` is only valid on the left-hand side of a destructuring assignment,
+                // which is checked during lowering.
+                if self.check(&token::CloseDelim(token::Brace)) {
+                    base = ast::StructRest::Rest(self.prev_token.span.shrink_to_hi());
+                    break;
+                }
                 match self.parse_expr() {
-                    Ok(e) => base = Some(e),
+                    Ok(e) => base = ast::StructRest::Base(e),
                     Err(mut e) => {
                         e.emit();
                         self.recover_stmt();
@@ -56,12 +56,25 @@ impl<'a> Parser<'a> {
 
         self.sess.gated_spans.gate(sym::const_generics, lo.to(self.prev_token.span));
 
+        // Parse optional const generics default value, e.g. `const N: usize = 3`.
+        let default = if self.eat(&token::Eq) {
+            let lo = self.token.span;
+            if !self.check_const_arg() {
+                return self.unexpected();
+            }
+            let default = self.parse_const_arg()?;
+            self.sess.gated_spans.gate(sym::const_generics_defaults, lo.to(self.prev_token.span));
+            Some(default)
+        } else {
+            None
+        };
+
         Ok(GenericParam {
             ident,
             id: ast::DUMMY_NODE_ID,
             attrs: preceding_attrs.into(),
             bounds: Vec::new(),
-            kind: GenericParamKind::Const { ty },
+            kind: GenericParamKind::Const { ty, default },
             is_placeholder: false,
         })
     }
@@ -313,6 +313,13 @@ impl<'a> Parser<'a> {
             let pat = self.parse_pat_with_range_pat(false, None)?;
             self.sess.gated_spans.gate(sym::box_patterns, lo.to(self.prev_token.span));
             PatKind::Box(pat)
+        } else if self.check_inline_const(0) {
+            // Parse `const { ... }` as an inline const pattern.
+            let const_expr = self.parse_const_block(AttrVec::new())?;
+            match self.parse_range_end() {
+                Some(form) => self.parse_pat_range_begin_with(const_expr, form)?,
+                None => PatKind::Lit(const_expr),
+            }
         } else if self.can_be_ident_pat() {
             // Parse `ident @ pat`
             // This can give false positives and parse nullary enums,
@@ -714,16 +721,19 @@ impl<'a> Parser<'a> {
 
     /// Is the token `dist` away from the current suitable as the start of a range patterns end?
     fn is_pat_range_end_start(&self, dist: usize) -> bool {
-        self.look_ahead(dist, |t| {
-            t.is_path_start() // e.g. `MY_CONST`;
-                || t.kind == token::Dot // e.g. `.5` for recovery;
-                || t.can_begin_literal_maybe_minus() // e.g. `42`.
-                || t.is_whole_expr()
-        })
+        self.check_inline_const(dist)
+            || self.look_ahead(dist, |t| {
+                t.is_path_start() // e.g. `MY_CONST`;
+                    || t.kind == token::Dot // e.g. `.5` for recovery;
+                    || t.can_begin_literal_maybe_minus() // e.g. `42`.
+                    || t.is_whole_expr()
+            })
     }
 
     fn parse_pat_range_end(&mut self) -> PResult<'a, P<Expr>> {
-        if self.check_path() {
+        if self.check_inline_const(0) {
+            self.parse_const_block(AttrVec::new())
+        } else if self.check_path() {
             let lo = self.token.span;
             let (qself, path) = if self.eat_lt() {
                 // Parse a qualified path
@@ -479,30 +479,7 @@ impl<'a> Parser<'a> {
             GenericArg::Lifetime(self.expect_lifetime())
         } else if self.check_const_arg() {
             // Parse const argument.
-            let expr = if let token::OpenDelim(token::Brace) = self.token.kind {
-                self.parse_block_expr(
-                    None,
-                    self.token.span,
-                    BlockCheckMode::Default,
-                    ast::AttrVec::new(),
-                )?
-            } else if self.token.is_ident() {
-                // FIXME(const_generics): to distinguish between idents for types and consts,
-                // we should introduce a GenericArg::Ident in the AST and distinguish when
-                // lowering to the HIR. For now, idents for const args are not permitted.
-                if self.token.is_bool_lit() {
-                    self.parse_literal_maybe_minus()?
-                } else {
-                    let span = self.token.span;
-                    let msg = "identifiers may currently not be used for const generics";
-                    self.struct_span_err(span, msg).emit();
-                    let block = self.mk_block_err(span);
-                    self.mk_expr(span, ast::ExprKind::Block(block, None), ast::AttrVec::new())
-                }
-            } else {
-                self.parse_literal_maybe_minus()?
-            };
-            GenericArg::Const(AnonConst { id: ast::DUMMY_NODE_ID, value: expr })
+            GenericArg::Const(self.parse_const_arg()?)
         } else if self.check_type() {
             // Parse type argument.
             GenericArg::Type(self.parse_ty()?)
@@ -511,4 +488,33 @@ impl<'a> Parser<'a> {
         };
         Ok(Some(arg))
     }
+
+    /// Parse a const argument, e.g. `<3>`, `<-3>` or `<{ N + 1 }>`. The caller is responsible
+    /// for checking that the current token can begin a const argument.
+    pub(super) fn parse_const_arg(&mut self) -> PResult<'a, AnonConst> {
+        let value = if let token::OpenDelim(token::Brace) = self.token.kind {
+            self.parse_block_expr(
+                None,
+                self.token.span,
+                BlockCheckMode::Default,
+                ast::AttrVec::new(),
+            )?
+        } else if self.token.is_ident() {
+            // FIXME(const_generics): to distinguish between idents for types and consts,
+            // we should introduce a GenericArg::Ident in the AST and distinguish when
+            // lowering to the HIR. For now, idents for const args are not permitted.
+            if self.token.is_bool_lit() {
+                self.parse_literal_maybe_minus()?
+            } else {
+                let span = self.token.span;
+                let msg = "identifiers may currently not be used for const generics";
+                self.struct_span_err(span, msg).emit();
+                let block = self.mk_block_err(span);
+                self.mk_expr(span, ast::ExprKind::Block(block, None), ast::AttrVec::new())
+            }
+        } else {
+            self.parse_literal_maybe_minus()?
+        };
+        Ok(AnonConst { id: ast::DUMMY_NODE_ID, value })
+    }
 }
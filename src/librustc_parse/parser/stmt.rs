@@ -211,8 +211,44 @@ impl<'a> Parser<'a> {
                 return Err(err);
             }
         };
+        let els = match &init {
+            Some(init) if self.eat_keyword(kw::Else) => {
+                self.check_let_else_init(init);
+                let els = self.parse_block()?;
+                self.sess.gated_spans.gate(sym::let_else, lo.to(els.span));
+                Some(els)
+            }
+            _ => None,
+        };
         let hi = if self.token == token::Semi { self.token.span } else { self.prev_token.span };
-        Ok(P(ast::Local { ty, pat, init, id: DUMMY_NODE_ID, span: lo.to(hi), attrs }))
+        Ok(P(ast::Local { ty, pat, init, els, id: DUMMY_NODE_ID, span: lo.to(hi), attrs }))
+    }
+
+    /// Rejects initializers of a `let`-`else` statement that would be ambiguous
+    /// with the `else` keyword, e.g. `let x = if a { b } else { c } else { .. };`
+    /// or `let x = a || b else { .. };`.
+    fn check_let_else_init(&self, init: &Expr) {
+        let msg = match init.kind {
+            ExprKind::Binary(op, ..) if op.node.lazy() => format!(
+                "a `{}` expression cannot be directly assigned in `let...else`",
+                op.node.to_string()
+            ),
+            _ if !classify::expr_requires_semi_to_be_stmt(init) => {
+                "right curly brace `}` before `else` in a `let...else` statement not allowed"
+                    .to_string()
+            }
+            _ => return,
+        };
+        self.struct_span_err(init.span, &msg)
+            .multipart_suggestion(
+                "wrap the expression in parentheses",
+                vec![
+                    (init.span.shrink_to_lo(), "(".to_string()),
+                    (init.span.shrink_to_hi(), ")".to_string()),
+                ],
+                Applicability::MachineApplicable,
+            )
+            .emit();
     }
 
     /// Parses the RHS of a local variable declaration (e.g., '= 14;').
@@ -43,6 +43,7 @@ impl NonConstExpr {
             Self::Match(Normal)
             | Self::Match(IfDesugar { .. })
             | Self::Match(IfLetDesugar { .. })
+            | Self::Match(LetElseDesugar)
             | Self::OrPattern => &[sym::const_if_match],
 
             Self::Loop(Loop) => &[sym::const_loop],
@@ -166,8 +166,11 @@ fn resolve_arm<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, arm: &'tcx hir
 
     visitor.terminating_scopes.insert(arm.body.hir_id.local_id);
 
-    if let Some(hir::Guard::If(ref expr)) = arm.guard {
-        visitor.terminating_scopes.insert(expr.hir_id.local_id);
+    match arm.guard {
+        Some(hir::Guard::If(ref expr)) | Some(hir::Guard::IfLet(_, ref expr)) => {
+            visitor.terminating_scopes.insert(expr.hir_id.local_id);
+        }
+        None => {}
     }
 
     intravisit::walk_arm(visitor, arm);
@@ -251,6 +254,12 @@ fn resolve_expr<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, expr: &'tcx h
                 terminating(expr.hir_id.local_id);
             }
 
+            hir::ExprKind::Match(ref scrutinee, _, hir::MatchSource::LetElseDesugar) => {
+                // Like a `let` initializer, the scrutinee of a `let`-`else` drops
+                // its temporaries before the rest of the block runs.
+                terminating(scrutinee.hir_id.local_id);
+            }
+
             hir::ExprKind::AssignOp(..)
             | hir::ExprKind::Index(..)
             | hir::ExprKind::Unary(..)
@@ -280,6 +289,20 @@ fn resolve_expr<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, expr: &'tcx h
         }
     }
 
+    // The first arm of a `let`-`else` holds the rest of the enclosing block,
+    // so the temporaries that its bindings borrow are extended up to there.
+    if let hir::ExprKind::Match(scrutinee, [then_arm, _], hir::MatchSource::LetElseDesugar) =
+        &expr.kind
+    {
+        let init = match scrutinee.kind {
+            // The type annotation of the `let`, if any.
+            hir::ExprKind::Type(init, _) => init,
+            _ => *scrutinee,
+        };
+        let arm_scope = Scope { id: then_arm.hir_id.local_id, data: ScopeData::Node };
+        record_let_rvalue_scopes(visitor, Some(then_arm.pat), Some(init), Some(arm_scope));
+    }
+
     let prev_pessimistic = visitor.pessimistic_yield;
 
     // Ordinarily, we can rely on the visit order of HIR intravisit
@@ -434,7 +457,25 @@ fn resolve_local<'tcx>(
     debug!("resolve_local(pat={:?}, init={:?})", pat, init);
 
     let blk_scope = visitor.cx.var_parent.map(|(p, _)| p);
+    record_let_rvalue_scopes(visitor, pat, init, blk_scope);
+
+    // Make sure we visit the initializer first, so expr_and_pat_count remains correct
+    if let Some(expr) = init {
+        visitor.visit_expr(expr);
+    }
+    if let Some(pat) = pat {
+        visitor.visit_pat(pat);
+    }
+}
 
+/// Records the extended temporary lifetimes of the initializer of a `let`
+/// (or of the scrutinee of a `let`-`else`) whose bindings live in `blk_scope`.
+fn record_let_rvalue_scopes<'tcx>(
+    visitor: &mut RegionResolutionVisitor<'tcx>,
+    pat: Option<&'tcx hir::Pat<'tcx>>,
+    init: Option<&'tcx hir::Expr<'tcx>>,
+    blk_scope: Option<Scope>,
+) {
     // As an exception to the normal rules governing temporary
     // lifetimes, initializers in a let have a temporary lifetime
     // of the enclosing block. This means that e.g., a program
@@ -501,14 +542,6 @@ fn resolve_local<'tcx>(
         }
     }
 
-    // Make sure we visit the initializer first, so expr_and_pat_count remains correct
-    if let Some(expr) = init {
-        visitor.visit_expr(expr);
-    }
-    if let Some(pat) = pat {
-        visitor.visit_pat(pat);
-    }
-
     /// Returns `true` if `pat` match the `P&` non-terminal.
     ///
     /// ```text
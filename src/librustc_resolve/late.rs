@@ -106,7 +106,10 @@ crate enum RibKind<'a> {
     ItemRibKind(HasGenericParams),
 
     /// We're in a constant item. Can't refer to dynamic stuff.
-    ConstantItemRibKind,
+    ///
+    /// The `bool` is `false` for anonymous constants which are neither `N` nor `{ N }`.
+    /// These may not use generic parameters with `min_const_generics`.
+    ConstantItemRibKind(bool),
 
     /// We passed through a module.
     ModuleRibKind(Module<'a>),
@@ -125,7 +128,7 @@ impl RibKind<'_> {
     // variables.
     crate fn contains_params(&self) -> bool {
         match self {
-            NormalRibKind | FnItemRibKind | ConstantItemRibKind | ModuleRibKind(_)
+            NormalRibKind | FnItemRibKind | ConstantItemRibKind(_) | ModuleRibKind(_)
             | MacroDefinition(_) => false,
             AssocItemRibKind | ItemRibKind(_) | ForwardTyParamBanRibKind => true,
         }
@@ -395,7 +398,8 @@ impl<'a, 'ast> Visitor<'ast> for LateResolutionVisitor<'a, '_, 'ast> {
     }
     fn visit_anon_const(&mut self, constant: &'ast AnonConst) {
         debug!("visit_anon_const {:?}", constant);
-        self.with_constant_rib(|this| {
+        let is_trivial_const_arg = constant.value.is_potential_trivial_const_param();
+        self.with_constant_rib(is_trivial_const_arg, |this| {
             visit::walk_anon_const(this, constant);
         });
     }
@@ -510,6 +514,18 @@ impl<'a, 'ast> Visitor<'ast> for LateResolutionVisitor<'a, '_, 'ast> {
             },
         ));
 
+        // The same applies to const parameter defaults, which may only refer to
+        // the const parameters declared before them.
+        let mut const_default_ban_rib = Rib::new(ForwardTyParamBanRibKind);
+        const_default_ban_rib.bindings.extend(generics.params.iter().filter_map(|param| {
+            match param.kind {
+                GenericParamKind::Const { .. } => {
+                    Some((Ident::with_dummy_span(param.ident.name), Res::Err))
+                }
+                GenericParamKind::Type { .. } | GenericParamKind::Lifetime { .. } => None,
+            }
+        }));
+
         // rust-lang/rust#61631: The type `Self` is essentially
         // another type parameter. For ADTs, we consider it
         // well-defined only after all of the ADT type parameters have
@@ -541,11 +557,24 @@ impl<'a, 'ast> Visitor<'ast> for LateResolutionVisitor<'a, '_, 'ast> {
                     // Allow all following defaults to refer to this type parameter.
                     default_ban_rib.bindings.remove(&Ident::with_dummy_span(param.ident.name));
                 }
-                GenericParamKind::Const { ref ty } => {
+                GenericParamKind::Const { ref ty, ref default } => {
                     for bound in &param.bounds {
                         self.visit_param_bound(bound);
                     }
                     self.visit_ty(ty);
+
+                    if let Some(ref default) = default {
+                        self.ribs[TypeNS].push(default_ban_rib);
+                        self.ribs[ValueNS].push(const_default_ban_rib);
+                        self.visit_anon_const(default);
+                        const_default_ban_rib = self.ribs[ValueNS].pop().unwrap();
+                        default_ban_rib = self.ribs[TypeNS].pop().unwrap();
+                    }
+
+                    // Allow all following defaults to refer to this const parameter.
+                    const_default_ban_rib
+                        .bindings
+                        .remove(&Ident::with_dummy_span(param.ident.name));
                 }
             }
         }
@@ -580,7 +609,7 @@ impl<'a, 'ast> Visitor<'ast> for LateResolutionVisitor<'a, '_, 'ast> {
                         if !check_ns(TypeNS) && check_ns(ValueNS) {
                             // This must be equivalent to `visit_anon_const`, but we cannot call it
                             // directly due to visitor lifetimes so we have to copy-paste some code.
-                            self.with_constant_rib(|this| {
+                            self.with_constant_rib(true, |this| {
                                 this.smart_resolve_path(
                                     ty.id,
                                     qself.as_ref(),
@@ -849,7 +878,9 @@ impl<'a, 'b, 'ast> LateResolutionVisitor<'a, 'b, 'ast> {
                                         // Only impose the restrictions of `ConstRibKind` for an
                                         // actual constant expression in a provided default.
                                         if let Some(expr) = default {
-                                            this.with_constant_rib(|this| this.visit_expr(expr));
+                                            this.with_constant_rib(true, |this| {
+                                                this.visit_expr(expr)
+                                            });
                                         }
                                     }
                                     AssocItemKind::Fn(_, _, generics, _) => {
@@ -890,7 +921,7 @@ impl<'a, 'b, 'ast> LateResolutionVisitor<'a, 'b, 'ast> {
                 self.with_item_rib(HasGenericParams::No, |this| {
                     this.visit_ty(ty);
                     if let Some(expr) = expr {
-                        this.with_constant_rib(|this| this.visit_expr(expr));
+                        this.with_constant_rib(true, |this| this.visit_expr(expr));
                     }
                 });
             }
@@ -987,10 +1018,12 @@ impl<'a, 'b, 'ast> LateResolutionVisitor<'a, 'b, 'ast> {
         self.with_rib(ValueNS, kind, |this| this.with_rib(TypeNS, kind, f))
     }
 
-    fn with_constant_rib(&mut self, f: impl FnOnce(&mut Self)) {
-        debug!("with_constant_rib");
-        self.with_rib(ValueNS, ConstantItemRibKind, |this| {
-            this.with_label_rib(ConstantItemRibKind, f);
+    fn with_constant_rib(&mut self, trivial: bool, f: impl FnOnce(&mut Self)) {
+        debug!("with_constant_rib: trivial={}", trivial);
+        self.with_rib(ValueNS, ConstantItemRibKind(trivial), |this| {
+            this.with_rib(TypeNS, ConstantItemRibKind(trivial), |this| {
+                this.with_label_rib(ConstantItemRibKind(trivial), f);
+            })
         });
     }
 
@@ -1130,7 +1163,7 @@ impl<'a, 'b, 'ast> LateResolutionVisitor<'a, 'b, 'ast> {
                                                 |n, s| ConstNotMemberOfTrait(n, s),
                                             );
 
-                                            this.with_constant_rib(|this| {
+                                            this.with_constant_rib(true, |this| {
                                                 visit::walk_assoc_item(this, item, AssocCtxt::Impl)
                                             });
                                         }
@@ -1234,6 +1267,10 @@ impl<'a, 'b, 'ast> LateResolutionVisitor<'a, 'b, 'ast> {
         // Resolve the initializer.
         walk_list!(self, visit_expr, &local.init);
 
+        // Resolve the `else` block of a `let`-`else` before the pattern,
+        // as its bindings are not in scope there.
+        walk_list!(self, visit_block, &local.els);
+
         // Resolve the pattern.
         self.resolve_pattern_top(&local.pat, PatternSource::Let);
     }
@@ -814,7 +814,7 @@ impl<'l, 'tcx> DumpVisitor<'l, 'tcx> {
         path: &'l ast::Path,
         fields: &'l [ast::Field],
         variant: &'l ty::VariantDef,
-        base: &'l Option<P<ast::Expr>>,
+        rest: &'l ast::StructRest,
     ) {
         if let Some(struct_lit_data) = self.save_ctxt.get_expr_data(ex) {
             self.write_sub_paths_truncated(path);
@@ -832,7 +832,9 @@ impl<'l, 'tcx> DumpVisitor<'l, 'tcx> {
             }
         }
 
-        walk_list!(self, visit_expr, base);
+        if let ast::StructRest::Base(base) = rest {
+            self.visit_expr(base);
+        }
     }
 
     fn process_method_call(
@@ -1373,9 +1375,12 @@ impl<'l, 'tcx> Visitor<'l> for DumpVisitor<'l, 'tcx> {
                         self.visit_ty(&ty);
                     }
                 }
-                ast::GenericParamKind::Const { ref ty } => {
+                ast::GenericParamKind::Const { ref ty, ref default } => {
                     self.process_bounds(&param.bounds);
                     self.visit_ty(&ty);
+                    if let Some(default) = default {
+                        self.visit_anon_const(default);
+                    }
                 }
             }
         }
@@ -1441,7 +1446,7 @@ impl<'l, 'tcx> Visitor<'l> for DumpVisitor<'l, 'tcx> {
         debug!("visit_expr {:?}", ex.kind);
         self.process_macro_use(ex.span);
         match ex.kind {
-            ast::ExprKind::Struct(ref path, ref fields, ref base) => {
+            ast::ExprKind::Struct(ref path, ref fields, ref rest) => {
                 let expr_hir_id = self.save_ctxt.tcx.hir().node_id_to_hir_id(ex.id);
                 let hir_expr = self.save_ctxt.tcx.hir().expect_expr(expr_hir_id);
                 let adt = match self.save_ctxt.tables.expr_ty_opt(&hir_expr) {
@@ -1453,7 +1458,7 @@ impl<'l, 'tcx> Visitor<'l> for DumpVisitor<'l, 'tcx> {
                 };
                 let node_id = self.save_ctxt.tcx.hir().hir_id_to_node_id(hir_expr.hir_id);
                 let res = self.save_ctxt.get_path_res(node_id);
-                self.process_struct_lit(ex, path, fields, adt.variant_of_res(res), base)
+                self.process_struct_lit(ex, path, fields, adt.variant_of_res(res), rest)
             }
             ast::ExprKind::MethodCall(ref seg, ref args) => self.process_method_call(ex, seg, args),
             ast::ExprKind::Field(ref sub_ex, _) => {
@@ -1534,9 +1539,10 @@ impl<'l, 'tcx> Visitor<'l> for DumpVisitor<'l, 'tcx> {
         self.process_macro_use(l.span);
         self.process_var_decl(&l.pat);
 
-        // Just walk the initialiser and type (don't want to walk the pattern again).
+        // Just walk the initialiser, type and `else` block (don't want to walk the pattern again).
         walk_list!(self, visit_ty, &l.ty);
         walk_list!(self, visit_expr, &l.init);
+        walk_list!(self, visit_block, &l.els);
     }
 
     fn visit_foreign_item(&mut self, item: &'l ast::ForeignItem) {
@@ -608,9 +608,13 @@ impl Sig for ast::Generics {
                 start: offset + text.len(),
                 end: offset + text.len() + param_text.as_str().len(),
             });
-            if let ast::GenericParamKind::Const { ref ty } = param.kind {
+            if let ast::GenericParamKind::Const { ref ty, ref default } = param.kind {
                 param_text.push_str(": ");
                 param_text.push_str(&pprust::ty_to_string(&ty));
+                if let Some(default) = default {
+                    param_text.push_str(" = ");
+                    param_text.push_str(&pprust::expr_to_string(&default.value));
+                }
             }
             if !param.bounds.is_empty() {
                 param_text.push_str(": ");
@@ -532,6 +532,13 @@ declare_lint! {
     "unsafe operations in unsafe functions without an explicit unsafe block are deprecated",
 }
 
+declare_lint! {
+    pub DISJOINT_CAPTURE_DROP_REORDER,
+    Allow,
+    "detects closures whose captured values would be dropped in a different order \
+     with `capture_disjoint_fields`",
+}
+
 declare_lint_pass! {
     /// Does nothing as a lint pass, but registers some `Lint`s
     /// that are used by other parts of the compiler.
@@ -604,6 +611,7 @@ declare_lint_pass! {
         INLINE_NO_SANITIZE,
         ASM_SUB_REGISTER,
         UNSAFE_OP_IN_UNSAFE_FN,
+        DISJOINT_CAPTURE_DROP_REORDER,
     ]
 }
 
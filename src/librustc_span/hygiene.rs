@@ -823,6 +823,7 @@ pub enum DesugaringKind {
     Async,
     Await,
     ForLoop,
+    LetElse,
 }
 
 impl DesugaringKind {
@@ -836,6 +837,7 @@ impl DesugaringKind {
             DesugaringKind::TryBlock => "`try` block",
             DesugaringKind::OpaqueTy => "`impl Trait`",
             DesugaringKind::ForLoop => "`for` loop",
+            DesugaringKind::LetElse => "`let...else` statement",
         }
     }
 }
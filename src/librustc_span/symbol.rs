@@ -158,6 +158,7 @@ symbols! {
         assume_init,
         async_await,
         async_closure,
+        async_fn_in_trait,
         attr,
         attributes,
         attr_literals,
@@ -183,6 +184,7 @@ symbols! {
         bitreverse,
         C,
         caller_location,
+        capture_disjoint_fields,
         cdylib,
         cfg,
         cfg_accessible,
@@ -212,13 +214,16 @@ symbols! {
         concat_idents,
         conservative_impl_trait,
         console,
+        const_allocate,
         const_compare_raw_pointers,
         const_constructor,
         const_eval_limit,
+        const_evaluatable_checked,
         const_extern_fn,
         const_fn,
         const_fn_union,
         const_generics,
+        const_generics_defaults,
         const_if_match,
         const_indexing,
         const_in_array_repeat_expressions,
@@ -270,6 +275,7 @@ symbols! {
         deref,
         deref_mut,
         derive,
+        destructuring_assignment,
         diagnostic,
         direct,
         discriminant_value,
@@ -334,6 +340,7 @@ symbols! {
         fn_must_use,
         forbid,
         format_args,
+        format_args_capture,
         format_args_nl,
         from,
         From,
@@ -378,6 +385,7 @@ symbols! {
         i8,
         ident,
         if_let,
+        if_let_guard,
         if_while_or_patterns,
         ignore,
         inlateout,
@@ -396,6 +404,7 @@ symbols! {
         infer_outlives_requirements,
         infer_static_outlives_requirements,
         inline,
+        inline_const,
         intel,
         into_iter,
         IntoIterator,
@@ -419,6 +428,7 @@ symbols! {
         lang_items,
         lateout,
         let_chains,
+        let_else,
         lhs,
         lib,
         lifetime,
@@ -465,6 +475,7 @@ symbols! {
         meta,
         min_align_of,
         min_const_fn,
+        min_const_generics,
         min_const_unsafe_fn,
         min_specialization,
         mips_target_feature,
@@ -685,6 +696,7 @@ symbols! {
         rustc_specialization_trait,
         rustc_stable,
         rustc_std_internal_symbol,
+        rustc_strict_coherence,
         rustc_symbol_name,
         rustc_synthetic,
         rustc_reservation_impl,
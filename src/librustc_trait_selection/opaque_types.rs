@@ -531,7 +531,7 @@ impl<'a, 'tcx> InferCtxtExt<'tcx> for InferCtxt<'a, 'tcx> {
                 .iter()
                 .filter(|param| match param.kind {
                     GenericParamDefKind::Lifetime => true,
-                    GenericParamDefKind::Type { .. } | GenericParamDefKind::Const => false,
+                    GenericParamDefKind::Type { .. } | GenericParamDefKind::Const { .. } => false,
                 })
                 .map(|param| opaque_defn.substs.region_at(param.index as usize))
                 .chain(std::iter::once(self.tcx.lifetimes.re_static))
@@ -713,9 +713,7 @@ where
             ty::Closure(_, ref substs) => {
                 // Skip lifetime parameters of the enclosing item(s)
 
-                for upvar_ty in substs.as_closure().upvar_tys() {
-                    upvar_ty.visit_with(self);
-                }
+                substs.as_closure().tupled_upvars_ty().visit_with(self);
 
                 substs.as_closure().sig_as_fn_ptr_ty().visit_with(self);
             }
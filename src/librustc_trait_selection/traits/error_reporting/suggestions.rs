@@ -19,7 +19,7 @@ use rustc_middle::ty::{
     TyCtxt, TypeFoldable, WithConstness,
 };
 use rustc_span::symbol::{kw, sym, Ident, Symbol};
-use rustc_span::{MultiSpan, Span, DUMMY_SP};
+use rustc_span::{DesugaringKind, MultiSpan, Span, DUMMY_SP};
 use std::fmt;
 
 use super::InferCtxtPrivExt;
@@ -166,6 +166,12 @@ pub trait InferCtxtExt<'tcx> {
         trait_ref: &ty::Binder<ty::TraitRef<'tcx>>,
         span: Span,
     );
+
+    fn note_async_fn_in_trait_future(
+        &self,
+        err: &mut DiagnosticBuilder<'_>,
+        trait_ref: &ty::PolyTraitRef<'tcx>,
+    );
 }
 
 fn predicate_constraint(generics: &hir::Generics<'_>, pred: String) -> (Span, String) {
@@ -1977,6 +1983,118 @@ impl<'a, 'tcx> InferCtxtExt<'tcx> for InferCtxt<'a, 'tcx> {
             }
         }
     }
+
+    /// Adds a note to the diagnostic when the future returned by an `async fn` in a trait does
+    /// not implement an auto trait. That future is an associated type of the trait, and the
+    /// trait does not promise anything about it beyond `Future`.
+    ///
+    /// ```text
+    /// error[E0277]: `<S as Service>::{async fn call}<'_>` cannot be sent between threads safely
+    ///   --> $DIR/send-bound.rs:13:5
+    ///    |
+    /// LL | fn is_send<T: Send>(_: T) {}
+    ///    |               ---- required by this bound in `is_send`
+    /// ...
+    /// LL |     is_send(service.call());
+    ///    |     ^^^^^^^ `<S as Service>::{async fn call}<'_>` cannot be sent between threads safely
+    ///    |
+    ///    = help: the trait `std::marker::Send` is not implemented for
+    ///            `<S as Service>::{async fn call}<'_>`
+    /// note: the future returned by `Service::call` is not known to implement `std::marker::Send`
+    ///   --> $DIR/send-bound.rs:7:5
+    ///    |
+    /// LL |     async fn call(&self) -> u32;
+    ///    |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///    = note: each implementation of `Service` defines its own future for `call`, so
+    ///            whether it implements `std::marker::Send` is only known for a concrete
+    ///            implementation
+    ///    = help: to require every implementation to return a future that implements
+    ///            `std::marker::Send`, declare `call` as a `fn` returning
+    ///            `Pin<Box<dyn Future<Output = u32> + Send + '_>>` instead
+    /// ```
+    fn note_async_fn_in_trait_future(
+        &self,
+        err: &mut DiagnosticBuilder<'_>,
+        trait_ref: &ty::PolyTraitRef<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        if !tcx.trait_is_auto(trait_ref.def_id()) {
+            return;
+        }
+        let item_def_id = match trait_ref.skip_binder().self_ty().kind {
+            ty::Projection(data) => data.item_def_id,
+            _ => return,
+        };
+        if !tcx.def_span(item_def_id).is_desugaring(DesugaringKind::Async) {
+            return;
+        }
+
+        // The associated type is named `{async fn name}` after the function it is
+        // desugared from, see `LoweringContext::async_fn_assoc_ty`.
+        let item = tcx.associated_item(item_def_id);
+        let trait_def_id = item.container.id();
+        let fn_item = tcx.associated_items(trait_def_id).in_definition_order().find(|fn_item| {
+            fn_item.kind == ty::AssocKind::Fn
+                && item.ident.name.as_str() == format!("{{async fn {}}}", fn_item.ident)
+        });
+        let fn_item = match fn_item {
+            Some(fn_item) => fn_item,
+            None => return,
+        };
+        let fn_def_id = fn_item.def_id;
+        let auto_trait = tcx.def_path_str(trait_ref.def_id());
+        err.span_note(
+            tcx.def_span(fn_def_id),
+            &format!(
+                "the future returned by `{}` is not known to implement `{}`",
+                tcx.def_path_str(fn_def_id),
+                auto_trait,
+            ),
+        );
+        err.note(&format!(
+            "each implementation of `{}` defines its own future for `{}`, so whether it \
+             implements `{}` is only known for a concrete implementation",
+            tcx.def_path_str(trait_def_id),
+            fn_item.ident,
+            auto_trait,
+        ));
+
+        // The future cannot be given a bound, as its associated type has no name that can be
+        // written. The method can instead be declared to return a boxed future with the bound.
+        let output = match tcx.hir().get_if_local(item_def_id) {
+            Some(Node::TraitItem(hir::TraitItem {
+                kind: hir::TraitItemKind::Type([hir::GenericBound::Trait(future, _)], _),
+                ..
+            })) => future.trait_ref.path.segments.last().and_then(|segment| {
+                match segment.args?.bindings {
+                    [hir::TypeBinding { kind: hir::TypeBindingKind::Equality { ty }, .. }] => {
+                        Some(ty)
+                    }
+                    _ => None,
+                }
+            }),
+            _ => None,
+        };
+        let output = output.and_then(|ty| match ty.kind {
+            // The `()` of an `async fn` without a return type has no snippet.
+            hir::TyKind::Tup([]) => Some("()".to_string()),
+            _ => tcx.sess.source_map().span_to_snippet(ty.span).ok(),
+        });
+        if let Some(output) = output {
+            let has_lifetimes = !tcx.generics_of(item_def_id).params.is_empty();
+            let lifetime = if has_lifetimes { " + '_" } else { "" };
+            err.help(&format!(
+                "to require every implementation to return a future that implements `{}`, \
+                 declare `{}` as a `fn` returning `Pin<Box<dyn Future<Output = {}> + {}{}>>` \
+                 instead",
+                auto_trait,
+                fn_item.ident,
+                output,
+                tcx.item_name(trait_ref.def_id()),
+                lifetime,
+            ));
+        }
+    }
 }
 
 /// Collect all the returned expressions within the input expression.
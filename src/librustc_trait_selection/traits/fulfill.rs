@@ -9,6 +9,7 @@ use rustc_middle::ty::error::ExpectedFound;
 use rustc_middle::ty::{self, Const, ToPolyTraitRef, Ty, TypeFoldable};
 use std::marker::PhantomData;
 
+use super::const_evaluatable;
 use super::project;
 use super::select::SelectionContext;
 use super::wf;
@@ -506,14 +507,14 @@ impl<'a, 'b, 'tcx> ObligationProcessor for FulfillProcessor<'a, 'b, 'tcx> {
             }
 
             &ty::PredicateKind::ConstEvaluatable(def_id, substs) => {
-                match self.selcx.infcx().const_eval_resolve(
-                    obligation.param_env,
+                match const_evaluatable::is_const_evaluatable(
+                    self.selcx.infcx(),
                     def_id,
                     substs,
-                    None,
-                    Some(obligation.cause.span),
+                    obligation.param_env,
+                    obligation.cause.span,
                 ) {
-                    Ok(_) => ProcessResult::Changed(vec![]),
+                    Ok(()) => ProcessResult::Changed(vec![]),
                     Err(err) => ProcessResult::Error(CodeSelectionError(ConstEvalFailure(err))),
                 }
             }
@@ -521,6 +522,10 @@ impl<'a, 'b, 'tcx> ObligationProcessor for FulfillProcessor<'a, 'b, 'tcx> {
             ty::PredicateKind::ConstEquate(c1, c2) => {
                 debug!("equating consts: c1={:?} c2={:?}", c1, c2);
 
+                if const_evaluatable::try_unify_unevaluated(self.selcx.tcx(), c1, c2) {
+                    return ProcessResult::Changed(vec![]);
+                }
+
                 let stalled_on = &mut pending_obligation.stalled_on;
 
                 let mut evaluate = |c: &'tcx Const<'tcx>| {
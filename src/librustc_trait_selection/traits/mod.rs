@@ -7,6 +7,7 @@ pub mod auto_trait;
 mod chalk_fulfill;
 pub mod codegen;
 mod coherence;
+pub mod const_evaluatable;
 mod engine;
 pub mod error_reporting;
 mod fulfill;
@@ -494,7 +495,7 @@ fn vtable_methods<'tcx>(
             let substs = trait_ref.map_bound(|trait_ref| {
                 InternalSubsts::for_item(tcx, def_id, |param, _| match param.kind {
                     GenericParamDefKind::Lifetime => tcx.lifetimes.re_erased.into(),
-                    GenericParamDefKind::Type { .. } | GenericParamDefKind::Const => {
+                    GenericParamDefKind::Type { .. } | GenericParamDefKind::Const { .. } => {
                         trait_ref.substs[param.index as usize]
                     }
                 })
@@ -560,6 +561,7 @@ pub fn provide(providers: &mut ty::query::Providers<'_>) {
         vtable_methods,
         substitute_normalize_and_test_predicates,
         type_implements_trait,
+        mir_abstract_const: const_evaluatable::mir_abstract_const,
         ..*providers
     };
 }
@@ -29,11 +29,11 @@ use crate::traits::{Obligation, ObligationCause};
 use crate::traits::{SelectionError, Unimplemented};
 use crate::traits::{
     VtableAutoImpl, VtableBuiltin, VtableClosure, VtableDiscriminantKind, VtableFnPointer,
-    VtableGenerator, VtableImpl, VtableObject, VtableParam, VtableTraitAlias,
+    VtableGenerator, VtableImpl, VtableObject, VtableParam, VtablePointee, VtableTraitAlias,
 };
 use crate::traits::{
     VtableAutoImplData, VtableBuiltinData, VtableClosureData, VtableDiscriminantKindData,
-    VtableFnPointerData, VtableGeneratorData, VtableImplData, VtableObjectData,
+    VtableFnPointerData, VtableGeneratorData, VtableImplData, VtableObjectData, VtablePointeeData,
     VtableTraitAliasData,
 };
 
@@ -93,6 +93,8 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
 
             DiscriminantKindCandidate => Ok(VtableDiscriminantKind(VtableDiscriminantKindData)),
 
+            PointeeCandidate => Ok(VtablePointee(VtablePointeeData)),
+
             TraitAliasCandidate(alias_def_id) => {
                 let data = self.confirm_trait_alias_candidate(obligation, alias_def_id);
                 Ok(VtableTraitAlias(data))
@@ -6,6 +6,7 @@ use self::EvaluationResult::*;
 use self::SelectionCandidate::*;
 
 use super::coherence::{self, Conflict};
+use super::const_evaluatable;
 use super::project;
 use super::project::normalize_with_depth_to;
 use super::util;
@@ -484,14 +485,14 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             }
 
             &ty::PredicateKind::ConstEvaluatable(def_id, substs) => {
-                match self.tcx().const_eval_resolve(
-                    obligation.param_env,
+                match const_evaluatable::is_const_evaluatable(
+                    self.infcx,
                     def_id,
                     substs,
-                    None,
-                    None,
+                    obligation.param_env,
+                    obligation.cause.span(self.tcx()),
                 ) {
-                    Ok(_) => Ok(EvaluatedToOk),
+                    Ok(()) => Ok(EvaluatedToOk),
                     Err(ErrorHandled::TooGeneric) => Ok(EvaluatedToAmbig),
                     Err(_) => Ok(EvaluatedToErr),
                 }
@@ -500,6 +501,10 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             ty::PredicateKind::ConstEquate(c1, c2) => {
                 debug!("evaluate_predicate_recursively: equating consts c1={:?} c2={:?}", c1, c2);
 
+                if const_evaluatable::try_unify_unevaluated(self.tcx(), c1, c2) {
+                    return Ok(EvaluatedToOk);
+                }
+
                 let evaluate = |c: &'tcx ty::Const<'tcx>| {
                     if let ty::ConstKind::Unevaluated(def_id, substs, promoted) = c.val {
                         self.infcx
@@ -973,6 +978,12 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
     ) -> SelectionResult<'tcx, SelectionCandidate<'tcx>> {
         if let Some(conflict) = self.is_knowable(stack) {
             debug!("coherence stage: not knowable");
+            if let Conflict::Upstream = conflict {
+                if self.upstream_negative_impl_applies(&stack.obligation) {
+                    debug!("coherence stage: ruled out by an upstream negative impl");
+                    return Err(Unimplemented);
+                }
+            }
             if self.intercrate_ambiguity_causes.is_some() {
                 debug!("evaluate_stack: intercrate_ambiguity_causes is some");
                 // Heuristics: show the diagnostics when there are no candidates in crate.
@@ -1129,6 +1140,48 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
         coherence::trait_ref_is_knowable(self.tcx(), trait_ref)
     }
 
+    /// Returns `true` if a negative impl in the crate that defines the trait
+    /// rules out `obligation`. The upstream crate could otherwise add an impl
+    /// for it later, but with such an impl it has promised not to. Only traits
+    /// marked `#[rustc_strict_coherence]` make that promise, other negative
+    /// impls may still be removed.
+    fn upstream_negative_impl_applies(&mut self, obligation: &TraitObligation<'tcx>) -> bool {
+        let trait_def_id = obligation.predicate.def_id();
+        if !self.tcx().has_attr(trait_def_id, sym::rustc_strict_coherence) {
+            return false;
+        }
+
+        let predicate = self.infcx().resolve_vars_if_possible(&obligation.predicate);
+
+        // Matching the impl would constrain the inference variables, while it
+        // says nothing about the other types they may stand for.
+        if predicate.has_infer_types_or_consts() {
+            return false;
+        }
+
+        let obligation = obligation.with(predicate);
+        let mut applies = false;
+        self.tcx().for_each_relevant_impl(
+            trait_def_id,
+            predicate.skip_binder().trait_ref.self_ty(),
+            |impl_def_id| {
+                if applies
+                    || impl_def_id.krate != trait_def_id.krate
+                    || self.tcx().impl_polarity(impl_def_id) != ty::ImplPolarity::Negative
+                    || !self.tcx().predicates_of(impl_def_id).predicates.is_empty()
+                {
+                    return;
+                }
+                self.infcx.probe(|snapshot| {
+                    if let Ok(substs) = self.match_impl(impl_def_id, &obligation, snapshot) {
+                        applies = substs.obligations.is_empty();
+                    }
+                });
+            },
+        );
+        applies
+    }
+
     /// Returns `true` if the global caches can be used.
     /// Do note that if the type itself is not in the
     /// global tcx, the local caches will be used.
@@ -1388,14 +1441,16 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
         let is_global =
             |cand: &ty::PolyTraitRef<'_>| cand.is_global() && !cand.has_late_bound_regions();
 
-        // (*) Prefer `BuiltinCandidate { has_nested: false }` and `DiscriminantKindCandidate`
-        // to anything else.
+        // (*) Prefer `BuiltinCandidate { has_nested: false }`, `DiscriminantKindCandidate`
+        // and `PointeeCandidate` to anything else.
         //
         // This is a fix for #53123 and prevents winnowing from accidentally extending the
         // lifetime of a variable.
         match other.candidate {
             // (*)
-            BuiltinCandidate { has_nested: false } | DiscriminantKindCandidate => true,
+            BuiltinCandidate { has_nested: false }
+            | DiscriminantKindCandidate
+            | PointeeCandidate => true,
             ParamCandidate(ref cand) => match victim.candidate {
                 AutoImplCandidate(..) => {
                     bug!(
@@ -1404,7 +1459,9 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                     );
                 }
                 // (*)
-                BuiltinCandidate { has_nested: false } | DiscriminantKindCandidate => false,
+                BuiltinCandidate { has_nested: false }
+                | DiscriminantKindCandidate
+                | PointeeCandidate => false,
                 ImplCandidate(..)
                 | ClosureCandidate
                 | GeneratorCandidate
@@ -1433,7 +1490,9 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                     );
                 }
                 // (*)
-                BuiltinCandidate { has_nested: false } | DiscriminantKindCandidate => false,
+                BuiltinCandidate { has_nested: false }
+                | DiscriminantKindCandidate
+                | PointeeCandidate => false,
                 ImplCandidate(..)
                 | ClosureCandidate
                 | GeneratorCandidate
@@ -1639,7 +1698,13 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
 
             ty::Closure(_, substs) => {
                 // (*) binder moved here
-                Where(ty::Binder::bind(substs.as_closure().upvar_tys().collect()))
+                let ty = self.infcx.shallow_resolve(substs.as_closure().tupled_upvars_ty());
+                if let ty::Infer(ty::TyVar(_)) = ty.kind {
+                    // The captures of this closure have not been inferred yet.
+                    Ambiguous
+                } else {
+                    Where(ty::Binder::bind(substs.as_closure().upvar_tys().collect()))
+                }
             }
 
             ty::Adt(..) | ty::Projection(..) | ty::Param(..) | ty::Opaque(..) => {
@@ -1708,7 +1773,10 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                 tys.iter().map(|k| k.expect_ty()).collect()
             }
 
-            ty::Closure(_, ref substs) => substs.as_closure().upvar_tys().collect(),
+            ty::Closure(_, ref substs) => {
+                let ty = self.infcx.shallow_resolve(substs.as_closure().tupled_upvars_ty());
+                vec![ty]
+            }
 
             ty::Generator(_, ref substs, _) => {
                 let witness = substs.as_generator().witness();
@@ -239,7 +239,8 @@ fn resolve_associated_item<'tcx>(
         traits::VtableAutoImpl(..)
         | traits::VtableParam(..)
         | traits::VtableTraitAlias(..)
-        | traits::VtableDiscriminantKind(..) => None,
+        | traits::VtableDiscriminantKind(..)
+        | traits::VtablePointee(..) => None,
     })
 }
 
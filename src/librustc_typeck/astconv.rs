@@ -335,8 +335,8 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
                 GenericParamDefKind::Type { has_default, .. } => {
                     defaults.types += has_default as usize
                 }
-                GenericParamDefKind::Const => {
-                    // FIXME(const_generics:defaults)
+                GenericParamDefKind::Const { has_default } => {
+                    defaults.consts += has_default as usize
                 }
             };
         }
@@ -436,11 +436,10 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
             )
             .and(arg_count_correct);
         }
-        // FIXME(const_generics:defaults)
-        if !infer_args || arg_counts.consts > param_counts.consts {
+        if !infer_args || arg_counts.consts > param_counts.consts - defaults.consts {
             arg_count_correct = check_kind_count(
                 "const",
-                param_counts.consts,
+                param_counts.consts - defaults.consts,
                 param_counts.consts,
                 arg_counts.consts,
                 arg_counts.lifetimes + arg_counts.types,
@@ -617,7 +616,7 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
                         match (arg, &param.kind, arg_count.explicit_late_bound) {
                             (GenericArg::Lifetime(_), GenericParamDefKind::Lifetime, _)
                             | (GenericArg::Type(_), GenericParamDefKind::Type { .. }, _)
-                            | (GenericArg::Const(_), GenericParamDefKind::Const, _) => {
+                            | (GenericArg::Const(_), GenericParamDefKind::Const { .. }, _) => {
                                 substs.push(provided_kind(param, arg));
                                 args.next();
                                 params.next();
@@ -824,7 +823,7 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
                         self.ast_ty_to_ty(&ty).into()
                     }
                 }
-                (GenericParamDefKind::Const, GenericArg::Const(ct)) => {
+                (GenericParamDefKind::Const { .. }, GenericArg::Const(ct)) => {
                     let ct_def_id = tcx.hir().local_def_id(ct.value.hir_id);
                     ty::Const::from_anon_const(tcx, ct_def_id).into()
                 }
@@ -868,10 +867,14 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
                             tcx.types.err.into()
                         }
                     }
-                    GenericParamDefKind::Const => {
+                    GenericParamDefKind::Const { has_default } => {
                         let ty = tcx.at(span).type_of(param.def_id);
-                        // FIXME(const_generics:defaults)
-                        if infer_args {
+                        if !infer_args && has_default {
+                            // No const parameter provided, but a default exists.
+                            tcx.const_param_default(param.def_id)
+                                .subst_spanned(tcx, substs.unwrap(), Some(span))
+                                .into()
+                        } else if infer_args {
                             // No const parameters were provided, we can infer all.
                             self.ct_infer(ty, Some(param), span).into()
                         } else {
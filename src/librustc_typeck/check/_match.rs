@@ -1,9 +1,10 @@
 use crate::check::coercion::CoerceMany;
 use crate::check::{Diverges, Expectation, FnCtxt, Needs};
+use rustc_errors::struct_span_err;
 use rustc_hir as hir;
 use rustc_hir::ExprKind;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
-use rustc_middle::ty::Ty;
+use rustc_middle::ty::{Ty, TypeFoldable};
 use rustc_span::Span;
 use rustc_trait_selection::traits::ObligationCauseCode;
 use rustc_trait_selection::traits::{IfExpressionCause, MatchExpressionArmCause, ObligationCause};
@@ -39,7 +40,19 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
             // FIXME(60707): Consider removing hack with principled solution.
             self.check_expr_has_type_or_error(scrut, self.tcx.types.bool, |_| {})
         } else {
-            self.demand_scrutinee_type(arms, scrut)
+            let contains_ref_bindings = arms
+                .iter()
+                .filter_map(|a| a.pat.contains_explicit_ref_binding())
+                .max_by_key(|m| match *m {
+                    hir::Mutability::Mut => 1,
+                    hir::Mutability::Not => 0,
+                });
+            match (match_src, &scrut.kind) {
+                (LetElseDesugar, &ExprKind::Type(init, ty)) => {
+                    self.check_let_else_scrutinee(scrut, init, ty, contains_ref_bindings)
+                }
+                _ => self.demand_scrutinee_type(scrut, contains_ref_bindings, arms.is_empty()),
+            }
         };
 
         // If there are no arms, that is a diverging match; a special case.
@@ -94,7 +107,15 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                 self.diverges.set(Diverges::Maybe);
                 match g {
                     hir::Guard::If(e) => {
-                        self.check_expr_has_type_or_error(e, tcx.types.bool, |_| {})
+                        self.check_expr_has_type_or_error(e, tcx.types.bool, |_| {});
+                    }
+                    hir::Guard::IfLet(pat, e) => {
+                        let scrut_ty = self.demand_scrutinee_type(
+                            e,
+                            pat.contains_explicit_ref_binding(),
+                            false,
+                        );
+                        self.check_pat_top(&pat, scrut_ty, Some(e.span), true);
                     }
                 };
             }
@@ -106,6 +127,8 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                 && self.if_fallback_coercion(expr.span, &arms[0].body, &mut coercion)
             {
                 tcx.types.err
+            } else if match_src == LetElseDesugar && i != 0 {
+                self.check_let_else_block(&arm.body)
             } else {
                 // Only call this if this is not an `if` expr with an expected type and no `else`
                 // clause to avoid duplicated type errors. (#60254)
@@ -187,6 +210,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         let msg = match source {
             IfDesugar { .. } | IfLetDesugar { .. } => "block in `if` expression",
             WhileDesugar { .. } | WhileLetDesugar { .. } => "block in `while` expression",
+            LetElseDesugar => "block in `let...else` statement",
             _ => "arm",
         };
         for arm in arms {
@@ -194,6 +218,48 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
     }
 
+    /// Checks the scrutinee `<init>: <ty>` of a desugared `let`-`else` with a
+    /// type annotation. As in a `let`, `<ty>` is a coercion site unless the
+    /// pattern has `ref` bindings (see `check_decl_initializer`).
+    fn check_let_else_scrutinee(
+        &self,
+        scrut: &'tcx hir::Expr<'tcx>,
+        init: &'tcx hir::Expr<'tcx>,
+        ty: &'tcx hir::Ty<'tcx>,
+        contains_ref_bindings: Option<hir::Mutability>,
+    ) -> Ty<'tcx> {
+        let ty = self.to_ty_saving_user_provided_ty(ty);
+        if let Some(m) = contains_ref_bindings {
+            let init_ty = self.check_expr_with_needs(init, Needs::maybe_mut_place(m));
+            self.demand_eqtype(init.span, ty, init_ty);
+        } else {
+            self.check_expr_coercable_to_type(init, ty);
+        }
+        self.write_ty(scrut.hir_id, ty);
+        ty
+    }
+
+    /// Checks that the `else` block of a desugared `let`-`else` diverges, as the
+    /// bindings of its pattern would otherwise be uninitialized.
+    fn check_let_else_block(&self, els: &'tcx hir::Expr<'tcx>) -> Ty<'tcx> {
+        let ty = self.check_expr(els);
+        if !self.diverges.get().is_always() && !ty.references_error() {
+            let ty = self.resolve_vars_if_possible(&ty);
+            struct_span_err!(
+                self.tcx.sess,
+                els.span,
+                E0308,
+                "`else` clause of `let...else` does not diverge"
+            )
+            .span_label(els.span, format!("expected `!`, found `{}`", ty))
+            .help("try adding a diverging expression, such as `return` or `panic!(..)`")
+            .help("...or use `match` instead of `let...else`")
+            .emit();
+            return self.tcx.types.err;
+        }
+        ty
+    }
+
     /// Handle the fallback arm of a desugared if(-let) like a missing else.
     ///
     /// Returns `true` if there was an error forcing the coercion to the `()` type.
@@ -371,8 +437,9 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
 
     fn demand_scrutinee_type(
         &self,
-        arms: &'tcx [hir::Arm<'tcx>],
         scrut: &'tcx hir::Expr<'tcx>,
+        contains_ref_bindings: Option<hir::Mutability>,
+        no_arms: bool,
     ) -> Ty<'tcx> {
         // Not entirely obvious: if matches may create ref bindings, we want to
         // use the *precise* type of the scrutinee, *not* some supertype, as
@@ -426,17 +493,9 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         // (once introduced) is populated by the time we get here.
         //
         // See #44848.
-        let contains_ref_bindings = arms
-            .iter()
-            .filter_map(|a| a.pat.contains_explicit_ref_binding())
-            .max_by_key(|m| match *m {
-                hir::Mutability::Mut => 1,
-                hir::Mutability::Not => 0,
-            });
-
         if let Some(m) = contains_ref_bindings {
             self.check_expr_with_needs(scrut, Needs::maybe_mut_place(m))
-        } else if arms.is_empty() {
+        } else if no_arms {
             self.check_expr(scrut)
         } else {
             // ...but otherwise we want to use any supertype of the
@@ -86,10 +86,17 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         // as they immediately unified with the actual type below, including
         // the `InferCtxt::closure_sig` and `ClosureSubsts::sig_ty` methods.
         let tupled_upvars_idx = base_substs.len() + if generator_types.is_some() { 4 } else { 2 };
+        // With `capture_disjoint_fields`, the number of captures of a closure is
+        // only known once `upvar.rs` has run, so the whole tuple of upvar types
+        // is left as a single type variable until then.
+        let capture_disjoint_fields =
+            generator_types.is_none() && self.tcx.features().capture_disjoint_fields;
         let substs =
             base_substs.extend_to(self.tcx, expr_def_id.to_def_id(), |param, _| match param.kind {
                 GenericParamDefKind::Lifetime => span_bug!(expr.span, "closure has lifetime param"),
-                GenericParamDefKind::Type { .. } => if param.index as usize == tupled_upvars_idx {
+                GenericParamDefKind::Type { .. } => if param.index as usize == tupled_upvars_idx
+                    && !capture_disjoint_fields
+                {
                     self.tcx.mk_tup(self.tcx.upvars_mentioned(expr_def_id).iter().flat_map(
                         |upvars| {
                             upvars.iter().map(|(&var_hir_id, _)| {
@@ -115,7 +122,9 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                     })
                 }
                 .into(),
-                GenericParamDefKind::Const => span_bug!(expr.span, "closure has const param"),
+                GenericParamDefKind::Const { .. } => {
+                    span_bug!(expr.span, "closure has const param")
+                }
             });
         if let Some(GeneratorTypes { resume_ty, yield_ty, interior, movability }) = generator_types
         {
@@ -612,10 +612,34 @@ impl<'a, 'tcx> ProbeContext<'a, 'tcx> {
 
                     self.assemble_inherent_candidates_from_object(generalized_self_ty);
                     self.assemble_inherent_impl_candidates_for_type(p.def_id());
+
+                    if Some(p.def_id()) == lang_items.error_trait() {
+                        // Only the impl for the same auto traits as `self_ty`
+                        // can match, the others are filtered out later on.
+                        self.assemble_inherent_impl_for_primitive(lang_items.error_alloc_impl());
+                        self.assemble_inherent_impl_for_primitive(
+                            lang_items.error_send_alloc_impl(),
+                        );
+                        self.assemble_inherent_impl_for_primitive(
+                            lang_items.error_send_sync_alloc_impl(),
+                        );
+                        self.assemble_inherent_impl_for_primitive(lang_items.error_std_impl());
+                        self.assemble_inherent_impl_for_primitive(
+                            lang_items.error_send_std_impl(),
+                        );
+                        self.assemble_inherent_impl_for_primitive(
+                            lang_items.error_send_sync_std_impl(),
+                        );
+                    }
                 }
             }
             ty::Adt(def, _) => {
                 self.assemble_inherent_impl_candidates_for_type(def.did);
+
+                if Some(def.did) == lang_items.c_str_type() {
+                    let lang_def_id = lang_items.c_str_alloc_impl();
+                    self.assemble_inherent_impl_for_primitive(lang_def_id);
+                }
             }
             ty::Foreign(did) => {
                 self.assemble_inherent_impl_candidates_for_type(did);
@@ -1617,7 +1641,7 @@ impl<'a, 'tcx> ProbeContext<'a, 'tcx> {
                             // In general, during probe we erase regions.
                             self.tcx.lifetimes.re_erased.into()
                         }
-                        GenericParamDefKind::Type { .. } | GenericParamDefKind::Const => {
+                        GenericParamDefKind::Type { .. } | GenericParamDefKind::Const { .. } => {
                             self.var_for_def(self.span, param)
                         }
                     }
@@ -826,6 +826,24 @@ fn primary_body_of(
     }
 }
 
+/// Inline `const` blocks are type-checked together with the body they appear
+/// in, so that their type can be inferred from how they are used. If `def_id`
+/// is such a block, returns the owner of that body.
+fn inline_const_parent(tcx: TyCtxt<'_>, def_id: DefId) -> Option<DefId> {
+    let hir_id = tcx.hir().local_def_id_to_hir_id(def_id.as_local()?);
+    let parent_id = tcx.hir().get_parent_node(hir_id);
+    match tcx.hir().get(parent_id) {
+        Node::Expr(&hir::Expr { kind: ExprKind::ConstBlock(ref anon_const), .. })
+            if anon_const.hir_id == hir_id => {}
+        _ => return None,
+    }
+    let (owner_id, _) = tcx
+        .hir()
+        .parent_iter(parent_id)
+        .find(|&(id, _)| tcx.hir().maybe_body_owned_by(id).is_some())?;
+    Some(tcx.hir().local_def_id(owner_id).to_def_id())
+}
+
 fn has_typeck_tables(tcx: TyCtxt<'_>, def_id: DefId) -> bool {
     // Closures' tables come from their outermost function,
     // as they are part of the same "inference environment".
@@ -833,6 +851,9 @@ fn has_typeck_tables(tcx: TyCtxt<'_>, def_id: DefId) -> bool {
     if outer_def_id != def_id {
         return tcx.has_typeck_tables(outer_def_id);
     }
+    if let Some(outer_def_id) = inline_const_parent(tcx, def_id) {
+        return tcx.has_typeck_tables(outer_def_id);
+    }
 
     if let Some(def_id) = def_id.as_local() {
         let id = tcx.hir().local_def_id_to_hir_id(def_id);
@@ -987,6 +1008,9 @@ fn typeck_tables_of_with_fallback<'tcx>(
     if outer_def_id != def_id {
         return tcx.typeck_tables_of(outer_def_id);
     }
+    if let Some(outer_def_id) = inline_const_parent(tcx, def_id.to_def_id()) {
+        return tcx.typeck_tables_of(outer_def_id.expect_local());
+    }
 
     let id = tcx.hir().as_local_hir_id(def_id);
     let span = tcx.hir().span(id);
@@ -5582,7 +5606,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                     (GenericParamDefKind::Type { .. }, GenericArg::Type(ty)) => {
                         self.to_ty(ty).into()
                     }
-                    (GenericParamDefKind::Const, GenericArg::Const(ct)) => {
+                    (GenericParamDefKind::Const { .. }, GenericArg::Const(ct)) => {
                         self.to_const(&ct.value).into()
                     }
                     _ => unreachable!(),
@@ -5612,10 +5636,15 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                                 self.var_for_def(span, param)
                             }
                         }
-                        GenericParamDefKind::Const => {
-                            // FIXME(const_generics:defaults)
-                            // No const parameters were provided, we have to infer them.
-                            self.var_for_def(span, param)
+                        GenericParamDefKind::Const { has_default } => {
+                            if !infer_args && has_default {
+                                tcx.const_param_default(param.def_id)
+                                    .subst_spanned(tcx, substs.unwrap(), Some(span))
+                                    .into()
+                            } else {
+                                // No const parameters were provided, we have to infer them.
+                                self.var_for_def(span, param)
+                            }
                         }
                     }
                 },
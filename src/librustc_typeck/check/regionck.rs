@@ -380,6 +380,9 @@ impl<'a, 'tcx> Visitor<'tcx> for RegionCtxt<'a, 'tcx> {
     fn visit_arm(&mut self, arm: &'tcx hir::Arm<'tcx>) {
         // see above
         self.constrain_bindings_in_pat(&arm.pat);
+        if let Some(hir::Guard::IfLet(ref pat, _)) = arm.guard {
+            self.constrain_bindings_in_pat(pat);
+        }
         intravisit::walk_arm(self, arm);
     }
 
@@ -485,7 +488,7 @@ impl<'a, 'tcx> RegionCtxt<'a, 'tcx> {
     ) {
         if let mc::PlaceBase::Rvalue = place.base {
             if place.projections.is_empty() {
-                let typ = self.resolve_type(place.ty);
+                let typ = self.resolve_type(place.ty());
                 let body_id = self.body_id;
                 let _ = dropck::check_drop_obligations(self, typ, span, body_id);
             }
@@ -552,6 +555,11 @@ impl<'a, 'tcx> RegionCtxt<'a, 'tcx> {
         debug!("discr_cmt={:?}", discr_cmt);
         for arm in arms {
             self.link_pattern(discr_cmt.clone(), &arm.pat);
+            if let Some(hir::Guard::IfLet(ref pat, ref e)) = arm.guard {
+                if let Ok(guard_cmt) = self.with_mc(|mc| mc.cat_expr(e)) {
+                    self.link_pattern(guard_cmt, pat);
+                }
+            }
         }
     }
 
@@ -637,8 +645,8 @@ impl<'a, 'tcx> RegionCtxt<'a, 'tcx> {
         borrow_kind: ty::BorrowKind,
         borrow_place: &mc::Place<'tcx>,
     ) {
-        let origin = infer::DataBorrowed(borrow_place.ty, span);
-        self.type_must_outlive(origin, borrow_place.ty, borrow_region);
+        let origin = infer::DataBorrowed(borrow_place.ty(), span);
+        self.type_must_outlive(origin, borrow_place.ty(), borrow_region);
 
         for pointer_ty in borrow_place.deref_tys() {
             debug!(
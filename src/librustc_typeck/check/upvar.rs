@@ -29,19 +29,32 @@
 //! `ty::InferBorrow(upvar_id)` or something like that, but this would
 //! then mean that all later passes would have to check for these figments
 //! and report an error, and it just seems like more mess in the end.)
+//!
+//! ### Capturing disjoint fields
+//!
+//! With `#![feature(capture_disjoint_fields)]`, a closure does not capture
+//! whole variables but the precise places it uses, e.g. `x.a` and `*x.b`
+//! rather than `x`. Every use of a place rooted at an upvar is recorded
+//! along with the kind of capture it requires. Once the body has been
+//! walked, the recorded places are truncated where precise capture is not
+//! possible (see `restrict_capture_precision`) and merged, so that no
+//! captured place is an ancestor of another one. The result is stored in
+//! `closure_min_captures` and determines the fields of the closure.
 
 use super::FnCtxt;
 
 use crate::expr_use_visitor as euv;
 use crate::mem_categorization as mc;
-use crate::mem_categorization::PlaceBase;
+use crate::mem_categorization::{PlaceBase, ProjectionKind};
 use rustc_data_structures::fx::FxIndexMap;
+use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc_infer::infer::UpvarRegion;
 use rustc_middle::ty::{self, Ty, TyCtxt, UpvarSubsts};
+use rustc_session::lint::{self, Level};
 use rustc_span::{Span, Symbol};
 
 impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
@@ -70,6 +83,12 @@ impl<'a, 'tcx> Visitor<'tcx> for InferBorrowKindVisitor<'a, 'tcx> {
             self.visit_body(body);
             self.fcx.analyze_closure(expr.hir_id, expr.span, body, cc);
         }
+        // Closures in inline `const` blocks are type-checked along with the
+        // enclosing body, so they are analyzed with it as well.
+        if let hir::ExprKind::ConstBlock(ref anon_const) = expr.kind {
+            let body = self.fcx.tcx.hir().body(anon_const.body);
+            self.visit_body(body);
+        }
 
         intravisit::walk_expr(self, expr);
     }
@@ -150,9 +169,11 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         let mut delegate = InferBorrowKind {
             fcx: self,
             closure_def_id,
+            capture_clause,
             current_closure_kind: ty::ClosureKind::LATTICE_BOTTOM,
             current_origin: None,
             adjust_upvar_captures: ty::UpvarCaptureMap::default(),
+            capture_information: Vec::new(),
         };
         euv::ExprUseVisitor::new(
             &mut delegate,
@@ -177,6 +198,11 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
 
         self.tables.borrow_mut().upvar_capture_map.extend(delegate.adjust_upvar_captures);
+        let capture_information = delegate.capture_information;
+
+        // Generators always capture whole variables.
+        let is_closure = matches!(substs, UpvarSubsts::Closure(_));
+        let capture_disjoint_fields = is_closure && self.tcx.features().capture_disjoint_fields;
 
         // Now that we've analyzed the closure, we know how each
         // variable is borrowed, and we know what traits the closure
@@ -190,14 +216,43 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         // C, then the type would have infinite size (and the
         // inference algorithm will reject it).
 
-        // Equate the type variables for the upvars with the actual types.
-        let final_upvar_tys = self.final_upvar_tys(closure_hir_id);
-        debug!(
-            "analyze_closure: id={:?} substs={:?} final_upvar_tys={:?}",
-            closure_hir_id, substs, final_upvar_tys
-        );
-        for (upvar_ty, final_upvar_ty) in substs.upvar_tys().zip(final_upvar_tys) {
-            self.demand_suptype(span, upvar_ty, final_upvar_ty);
+        if capture_disjoint_fields {
+            let min_captures = self.compute_min_captures(closure_hir_id, span, capture_information);
+
+            // Equate the type variable for the tuple of upvars with the types
+            // of the places actually captured.
+            let final_tupled_upvars_ty = self.tcx.mk_tup(
+                min_captures
+                    .values()
+                    .flat_map(|captures| captures.iter())
+                    .map(|captured| self.captured_place_ty(captured)),
+            );
+            debug!(
+                "analyze_closure: id={:?} substs={:?} final_tupled_upvars_ty={:?}",
+                closure_hir_id, substs, final_tupled_upvars_ty
+            );
+            self.demand_suptype(span, substs.tupled_upvars_ty(), final_tupled_upvars_ty);
+
+            self.tables.borrow_mut().closure_min_captures.insert(closure_def_id, min_captures);
+        } else {
+            if is_closure {
+                self.check_disjoint_capture_drop_reorder(
+                    closure_hir_id,
+                    span,
+                    body,
+                    capture_information,
+                );
+            }
+
+            // Equate the type variables for the upvars with the actual types.
+            let final_upvar_tys = self.final_upvar_tys(closure_hir_id);
+            debug!(
+                "analyze_closure: id={:?} substs={:?} final_upvar_tys={:?}",
+                closure_hir_id, substs, final_upvar_tys
+            );
+            for (upvar_ty, final_upvar_ty) in substs.upvar_tys().zip(final_upvar_tys) {
+                self.demand_suptype(span, upvar_ty, final_upvar_ty);
+            }
         }
 
         // If we are also inferred the closure kind here,
@@ -208,6 +263,166 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
     }
 
+    /// Computes the minimal set of places that the closure `closure_hir_id`
+    /// needs to capture to support all of the uses in `capture_information`,
+    /// grouped by the variable each place is rooted at.
+    ///
+    /// Variables are listed in the order they are mentioned in the closure;
+    /// a variable that is mentioned but never used as a place is captured
+    /// as a whole.
+    fn compute_min_captures(
+        &self,
+        closure_hir_id: hir::HirId,
+        closure_span: Span,
+        capture_information: Vec<(mc::Place<'tcx>, ty::CaptureInfo<'tcx>)>,
+    ) -> ty::RootVariableMinCaptureList<'tcx> {
+        let closure_def_id = self.tcx.hir().local_def_id(closure_hir_id);
+        let mut root_var_min_capture_list = ty::RootVariableMinCaptureList::default();
+
+        if let Some(upvars) = self.tcx.upvars_mentioned(closure_def_id) {
+            for &var_hir_id in upvars.keys() {
+                root_var_min_capture_list.insert(var_hir_id, Vec::new());
+            }
+        }
+
+        for (place, capture_info) in capture_information {
+            let var_hir_id = match place.base {
+                PlaceBase::Upvar(upvar_id) => upvar_id.var_path.hir_id,
+                base => bug!("expected upvar, found={:?}", base),
+            };
+            let (place, capture_kind) =
+                restrict_capture_precision(place, capture_info.capture_kind);
+            let capture_info = ty::CaptureInfo { capture_kind, ..capture_info };
+            debug!("compute_min_captures: place={:?} capture_info={:?}", place, capture_info);
+
+            match root_var_min_capture_list.get_mut(&var_hir_id) {
+                Some(min_cap_list) => insert_min_capture(min_cap_list, place, capture_info),
+                None => bug!("capture of {:?} which is not an upvar", var_hir_id),
+            }
+        }
+
+        for (&var_hir_id, min_cap_list) in root_var_min_capture_list.iter_mut() {
+            if !min_cap_list.is_empty() {
+                continue;
+            }
+            let upvar_id = ty::UpvarId {
+                var_path: ty::UpvarPath { hir_id: var_hir_id },
+                closure_expr_id: closure_def_id,
+            };
+            let place = mc::Place {
+                hir_id: closure_hir_id,
+                span: closure_span,
+                base_ty: self.node_ty(var_hir_id),
+                base: PlaceBase::Upvar(upvar_id),
+                projections: Vec::new(),
+            };
+            let capture_kind = self.tables.borrow().upvar_capture(upvar_id);
+            min_cap_list.push(ty::CapturedPlace {
+                place,
+                info: ty::CaptureInfo { expr_id: None, capture_kind },
+            });
+        }
+
+        debug!(
+            "compute_min_captures: closure={:?} min_captures={:?}",
+            closure_def_id, root_var_min_capture_list
+        );
+        root_var_min_capture_list
+    }
+
+    /// Returns the type of the closure field holding `captured_place`.
+    fn captured_place_ty(&self, captured_place: &ty::CapturedPlace<'tcx>) -> Ty<'tcx> {
+        let place_ty = captured_place.place.ty();
+        match captured_place.info.capture_kind {
+            ty::UpvarCapture::ByValue => place_ty,
+            ty::UpvarCapture::ByRef(borrow) => self.tcx.mk_ref(
+                borrow.region,
+                ty::TypeAndMut { ty: place_ty, mutbl: borrow.kind.to_mutbl_lossy() },
+            ),
+        }
+    }
+
+    /// Lints closures that capture a variable by value today, but would only
+    /// capture parts of it with `capture_disjoint_fields`. The rest of such a
+    /// variable would then be dropped at the end of its scope rather than
+    /// along with the closure.
+    fn check_disjoint_capture_drop_reorder(
+        &self,
+        closure_hir_id: hir::HirId,
+        closure_span: Span,
+        body: &hir::Body<'_>,
+        capture_information: Vec<(mc::Place<'tcx>, ty::CaptureInfo<'tcx>)>,
+    ) {
+        let lint = lint::builtin::DISJOINT_CAPTURE_DROP_REORDER;
+        if self.tcx.lint_level_at_node(lint, closure_hir_id).0 == Level::Allow {
+            return;
+        }
+
+        let closure_def_id = self.tcx.hir().local_def_id(closure_hir_id);
+        let min_captures =
+            self.compute_min_captures(closure_hir_id, closure_span, capture_information);
+
+        let mut need_migrations = Vec::new();
+        for (&var_hir_id, min_cap_list) in min_captures.iter() {
+            let upvar_id = ty::UpvarId {
+                var_path: ty::UpvarPath { hir_id: var_hir_id },
+                closure_expr_id: closure_def_id,
+            };
+            if let ty::UpvarCapture::ByRef(_) = self.tables.borrow().upvar_capture(upvar_id) {
+                continue;
+            }
+            if let [captured] = &min_cap_list[..] {
+                if captured.place.projections.is_empty() {
+                    continue;
+                }
+            }
+            let var_ty = self.resolve_vars_if_possible(&self.node_ty(var_hir_id));
+            if var_ty.needs_infer() || !var_ty.needs_drop(self.tcx, self.param_env) {
+                continue;
+            }
+            need_migrations.push(var_hir_id);
+        }
+
+        if need_migrations.is_empty() {
+            return;
+        }
+
+        self.tcx.struct_span_lint_hir(lint, closure_hir_id, closure_span, |lint| {
+            let var_names: Vec<_> = need_migrations
+                .iter()
+                .map(|&var_hir_id| var_name(self.tcx, var_hir_id).to_string())
+                .collect();
+            let migrations_text = if let [var_name] = &var_names[..] {
+                format!("let _ = {};", var_name)
+            } else {
+                format!("let _ = ({});", var_names.join(", "))
+            };
+            let variables = var_names
+                .iter()
+                .map(|var_name| format!("`{}`", var_name))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let body_span = body.value.span;
+            let source_map = self.tcx.sess.source_map();
+            let (sugg, applicability) = match source_map.span_to_snippet(body_span) {
+                Ok(snippet) => (
+                    format!("{{ {} {} }}", migrations_text, snippet),
+                    Applicability::MachineApplicable,
+                ),
+                Err(_) => (migrations_text.clone(), Applicability::HasPlaceholders),
+            };
+            lint.build("drop order affected for closure because of `capture_disjoint_fields`")
+                .span_suggestion(
+                    body_span,
+                    &format!("add a dummy let to cause {} to be fully captured", variables),
+                    sugg,
+                    applicability,
+                )
+                .emit();
+        });
+    }
+
     // Returns a list of `Ty`s for each upvar.
     fn final_upvar_tys(&self, closure_id: hir::HirId) -> Vec<Ty<'tcx>> {
         // Presently an unboxed closure type cannot "escape" out of a
@@ -250,6 +465,9 @@ struct InferBorrowKind<'a, 'tcx> {
     // The def-id of the closure whose kind and upvar accesses are being inferred.
     closure_def_id: DefId,
 
+    // Whether the closure is a `move` closure.
+    capture_clause: hir::CaptureBy,
+
     // The kind that we have inferred that the current closure
     // requires. Note that we *always* infer a minimal kind, even if
     // we don't always *use* that in the final result (i.e., sometimes
@@ -265,9 +483,47 @@ struct InferBorrowKind<'a, 'tcx> {
     // For each upvar that we access, we track the minimal kind of
     // access we need (ref, ref mut, move, etc).
     adjust_upvar_captures: ty::UpvarCaptureMap<'tcx>,
+
+    // Every place rooted at an upvar of the current closure that is used in
+    // its body, along with the kind of capture that use requires. This is
+    // what `capture_disjoint_fields` computes the captured places from.
+    capture_information: Vec<(mc::Place<'tcx>, ty::CaptureInfo<'tcx>)>,
 }
 
 impl<'a, 'tcx> InferBorrowKind<'a, 'tcx> {
+    /// Records a use of `place` if it is rooted at an upvar of the current
+    /// closure. `borrow_kind` is the kind of borrow the use requires, or
+    /// `None` if it moves out of `place`.
+    fn record_capture(&mut self, place: &mc::Place<'tcx>, borrow_kind: Option<ty::BorrowKind>) {
+        let upvar_id = match place.base {
+            PlaceBase::Upvar(upvar_id)
+                if upvar_id.closure_expr_id.to_def_id() == self.closure_def_id =>
+            {
+                upvar_id
+            }
+            _ => return,
+        };
+
+        let capture_kind = match (self.capture_clause, borrow_kind) {
+            (hir::CaptureBy::Value, _) | (hir::CaptureBy::Ref, None) => ty::UpvarCapture::ByValue,
+            (hir::CaptureBy::Ref, Some(kind)) => {
+                // All the places captured from a variable share the region
+                // the variable was seeded with in `analyze_closure`.
+                match self.fcx.tables.borrow().upvar_capture(upvar_id) {
+                    ty::UpvarCapture::ByRef(upvar_borrow) => {
+                        ty::UpvarCapture::ByRef(ty::UpvarBorrow { kind, ..upvar_borrow })
+                    }
+                    ty::UpvarCapture::ByValue => {
+                        bug!("upvar {:?} of a non-move closure seeded by value", upvar_id)
+                    }
+                }
+            }
+        };
+
+        let capture_info = ty::CaptureInfo { expr_id: Some(place.hir_id), capture_kind };
+        self.capture_information.push((place.clone(), capture_info));
+    }
+
     fn adjust_upvar_borrow_kind_for_consume(
         &mut self,
         place: &mc::Place<'tcx>,
@@ -456,10 +712,18 @@ impl<'a, 'tcx> euv::Delegate<'tcx> for InferBorrowKind<'a, 'tcx> {
     fn consume(&mut self, place: &mc::Place<'tcx>, mode: euv::ConsumeMode) {
         debug!("consume(place={:?},mode={:?})", place, mode);
         self.adjust_upvar_borrow_kind_for_consume(place, mode);
+
+        // Copying a value out of a place only needs a shared borrow of it.
+        let borrow_kind = match mode {
+            euv::Copy => Some(ty::ImmBorrow),
+            euv::Move => None,
+        };
+        self.record_capture(place, borrow_kind);
     }
 
     fn borrow(&mut self, place: &mc::Place<'tcx>, bk: ty::BorrowKind) {
         debug!("borrow(place={:?}, bk={:?})", place, bk);
+        self.record_capture(place, Some(bk));
 
         match bk {
             ty::ImmBorrow => {}
@@ -476,6 +740,143 @@ impl<'a, 'tcx> euv::Delegate<'tcx> for InferBorrowKind<'a, 'tcx> {
         debug!("mutate(assignee_place={:?})", assignee_place);
 
         self.adjust_upvar_borrow_kind_for_mut(assignee_place);
+        self.record_capture(assignee_place, Some(ty::MutBorrow));
+    }
+
+    fn fake_read(&mut self, place: &mc::Place<'tcx>) {
+        debug!("fake_read(place={:?})", place);
+
+        // MIR building needs the whole place, so it has to be captured even
+        // if only parts of it are used.
+        self.record_capture(place, Some(ty::ImmBorrow));
+    }
+}
+
+/// Truncates `place` to the longest prefix that a closure can capture
+/// precisely, adjusting `capture_kind` for the projections that were removed.
+///
+/// Captures stop:
+/// - at the dereference of a raw pointer, since that requires `unsafe`;
+/// - at indexing and subslicing, since the index is not part of the place;
+/// - at fields of enums, unions and packed structs, which cannot be
+///   borrowed or moved separately;
+/// - at any dereference when capturing by value, since nothing can be moved
+///   out of a reference.
+fn restrict_capture_precision<'tcx>(
+    place: mc::Place<'tcx>,
+    capture_kind: ty::UpvarCapture<'tcx>,
+) -> (mc::Place<'tcx>, ty::UpvarCapture<'tcx>) {
+    let by_value = matches!(capture_kind, ty::UpvarCapture::ByValue);
+    let truncate_at = place.projections.iter().enumerate().position(|(i, proj)| {
+        let ty_before = place.ty_before_projection(i);
+        match proj.kind {
+            ProjectionKind::Deref => by_value || ty_before.is_unsafe_ptr(),
+            ProjectionKind::Field(..) => match ty_before.kind {
+                ty::Adt(def, _) => def.is_enum() || def.is_union() || def.repr.packed(),
+                _ => false,
+            },
+            ProjectionKind::Index | ProjectionKind::Subslice => true,
+        }
+    });
+
+    match truncate_at {
+        Some(len) => truncate_place(place, capture_kind, len),
+        None => (place, capture_kind),
+    }
+}
+
+/// Truncates `place` to its first `len` projections, weakening a by-ref
+/// `capture_kind` where the removed projections went through a pointer:
+/// - mutating through a raw pointer only needs a shared borrow of it;
+/// - mutating through a `&mut` needs a unique, but not a mutable, borrow
+///   of the reference.
+fn truncate_place<'tcx>(
+    mut place: mc::Place<'tcx>,
+    mut capture_kind: ty::UpvarCapture<'tcx>,
+    len: usize,
+) -> (mc::Place<'tcx>, ty::UpvarCapture<'tcx>) {
+    if let ty::UpvarCapture::ByRef(ref mut upvar_borrow) = capture_kind {
+        for (i, proj) in place.projections.iter().enumerate().skip(len) {
+            if proj.kind != ProjectionKind::Deref {
+                continue;
+            }
+            match place.ty_before_projection(i).kind {
+                ty::RawPtr(_) => {
+                    upvar_borrow.kind = ty::ImmBorrow;
+                    break;
+                }
+                ty::Ref(.., hir::Mutability::Mut) if upvar_borrow.kind == ty::MutBorrow => {
+                    upvar_borrow.kind = ty::UniqueImmBorrow;
+                }
+                _ => {}
+            }
+        }
+    }
+    place.projections.truncate(len);
+    (place, capture_kind)
+}
+
+/// Returns `true` if `ancestor` is `descendant` itself or one of the places
+/// `descendant` is projected from.
+fn is_ancestor_or_same_capture(ancestor: &mc::Place<'_>, descendant: &mc::Place<'_>) -> bool {
+    ancestor.base == descendant.base
+        && ancestor.projections.len() <= descendant.projections.len()
+        && ancestor.projections.iter().zip(&descendant.projections).all(|(a, d)| a.kind == d.kind)
+}
+
+/// Adds `place` to the captures of one root variable, keeping the invariant
+/// that no captured place is an ancestor of another one. If `place` is
+/// already covered by a captured ancestor, that capture is strengthened as
+/// needed; otherwise `place` replaces all of its captured descendants.
+fn insert_min_capture<'tcx>(
+    min_cap_list: &mut ty::MinCaptureList<'tcx>,
+    place: mc::Place<'tcx>,
+    capture_info: ty::CaptureInfo<'tcx>,
+) {
+    for captured in min_cap_list.iter_mut() {
+        if is_ancestor_or_same_capture(&captured.place, &place) {
+            let len = captured.place.projections.len();
+            let (_, capture_kind) = truncate_place(place, capture_info.capture_kind, len);
+            let capture_info = ty::CaptureInfo { capture_kind, ..capture_info };
+            captured.info = determine_capture_info(captured.info, capture_info);
+            return;
+        }
+    }
+
+    let mut capture_info = capture_info;
+    min_cap_list.retain(|captured| {
+        if !is_ancestor_or_same_capture(&place, &captured.place) {
+            return true;
+        }
+        let len = place.projections.len();
+        let (_, capture_kind) =
+            truncate_place(captured.place.clone(), captured.info.capture_kind, len);
+        let descendant_info = ty::CaptureInfo { capture_kind, ..captured.info };
+        capture_info = determine_capture_info(capture_info, descendant_info);
+        false
+    });
+    min_cap_list.push(ty::CapturedPlace { place, info: capture_info });
+}
+
+/// Returns whichever of the two captures is stronger, preferring
+/// `capture_info_a` if they are equally strong:
+/// `ByValue > MutBorrow > UniqueImmBorrow > ImmBorrow`.
+fn determine_capture_info<'tcx>(
+    capture_info_a: ty::CaptureInfo<'tcx>,
+    capture_info_b: ty::CaptureInfo<'tcx>,
+) -> ty::CaptureInfo<'tcx> {
+    match (capture_info_a.capture_kind, capture_info_b.capture_kind) {
+        (ty::UpvarCapture::ByValue, _) => capture_info_a,
+        (_, ty::UpvarCapture::ByValue) => capture_info_b,
+        (ty::UpvarCapture::ByRef(ref_a), ty::UpvarCapture::ByRef(ref_b)) => {
+            match (ref_a.kind, ref_b.kind) {
+                (ty::ImmBorrow, ty::UniqueImmBorrow | ty::MutBorrow)
+                | (ty::UniqueImmBorrow, ty::MutBorrow) => capture_info_b,
+                (ty::ImmBorrow, ty::ImmBorrow)
+                | (ty::UniqueImmBorrow, ty::ImmBorrow | ty::UniqueImmBorrow)
+                | (ty::MutBorrow, _) => capture_info_a,
+            }
+        }
     }
 }
 
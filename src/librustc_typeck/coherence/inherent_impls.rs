@@ -14,6 +14,7 @@ use rustc_hir::itemlikevisit::ItemLikeVisitor;
 use rustc_middle::ty::{self, CrateInherentImpls, TyCtxt};
 
 use rustc_ast::ast;
+use rustc_span::symbol::sym;
 use rustc_span::Span;
 
 /// On-demand query: yields a map containing all types mapped to their inherent impls.
@@ -53,12 +54,58 @@ impl ItemLikeVisitor<'v> for InherentCollect<'tcx> {
         let self_ty = self.tcx.type_of(def_id);
         let lang_items = self.tcx.lang_items();
         match self_ty.kind {
+            ty::Adt(def, _) if !def.did.is_local() && Some(def.did) == lang_items.c_str_type() => {
+                // `CStr` lives in libcore, but its allocating methods are
+                // defined in liballoc, in the same way as for `str`.
+                self.check_primitive_impl(
+                    def_id,
+                    None,
+                    lang_items.c_str_alloc_impl(),
+                    "c_str_alloc",
+                    "CStr",
+                    item.span,
+                );
+            }
             ty::Adt(def, _) => {
                 self.check_def_id(item, def.did);
             }
             ty::Foreign(did) => {
                 self.check_def_id(item, did);
             }
+            ty::Dynamic(ref data, ..)
+                if data.principal_def_id().map_or(false, |did| !did.is_local())
+                    && data.principal_def_id() == lang_items.error_trait() =>
+            {
+                // `Error` lives in libcore, but the methods of `dyn Error` that
+                // need a `Box` are defined in liballoc, and the ones that need a
+                // `Backtrace` in libstd, in the same way as for `CStr` and `f32`.
+                // There is one impl for each set of auto traits.
+                let send = self.tcx.get_diagnostic_item(sym::send_trait);
+                let sync = lang_items.sync_trait();
+                let auto_traits: Vec<_> = data.auto_traits().map(Some).collect();
+                let (alloc_def_id, std_def_id, lang, ty) = match auto_traits[..] {
+                    [] => (
+                        lang_items.error_alloc_impl(),
+                        lang_items.error_std_impl(),
+                        "error_alloc",
+                        "dyn Error",
+                    ),
+                    [a] if a == send => (
+                        lang_items.error_send_alloc_impl(),
+                        lang_items.error_send_std_impl(),
+                        "error_send_alloc",
+                        "dyn Error + Send",
+                    ),
+                    [a, b] if (a, b) == (send, sync) || (a, b) == (sync, send) => (
+                        lang_items.error_send_sync_alloc_impl(),
+                        lang_items.error_send_sync_std_impl(),
+                        "error_send_sync_alloc",
+                        "dyn Error + Send + Sync",
+                    ),
+                    _ => (None, None, "error_alloc", "dyn Error"),
+                };
+                self.check_primitive_impl(def_id, alloc_def_id, std_def_id, lang, ty, item.span);
+            }
             ty::Dynamic(ref data, ..) if data.principal_def_id().is_some() => {
                 self.check_def_id(item, data.principal_def_id().unwrap());
             }
@@ -34,6 +34,7 @@ use rustc_middle::hir::map::blocks::FnLikeNode;
 use rustc_middle::hir::map::Map;
 use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
 use rustc_middle::mir::mono::Linkage;
+use rustc_middle::ty::fold::{TypeFoldable, TypeVisitor};
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::subst::{InternalSubsts, Subst};
 use rustc_middle::ty::util::Discr;
@@ -65,6 +66,7 @@ pub fn provide(providers: &mut Providers<'_>) {
     *providers = Providers {
         type_of: type_of::type_of,
         generics_of,
+        const_param_default,
         predicates_of,
         predicates_defined_on,
         explicit_predicates_of,
@@ -1184,7 +1186,10 @@ fn generics_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::Generics {
                     // expressions' count (i.e. `N` in `[x; N]`), and explicit
                     // `enum` discriminants (i.e. `D` in `enum Foo { Bar = D }`),
                     // as they shouldn't be able to cause query cycle errors.
+                    // Inline `const` blocks can use the generics of the
+                    // enclosing item as well.
                     Node::Expr(&Expr { kind: ExprKind::Repeat(_, ref constant), .. })
+                    | Node::Expr(&Expr { kind: ExprKind::ConstBlock(ref constant), .. })
                     | Node::Variant(Variant { disr_expr: Some(ref constant), .. })
                         if constant.hir_id == hir_id =>
                     {
@@ -1359,13 +1364,21 @@ fn generics_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::Generics {
     }));
 
     params.extend(ast_generics.params.iter().filter_map(|param| {
-        if let GenericParamKind::Const { .. } = param.kind {
+        if let GenericParamKind::Const { ref default, .. } = param.kind {
+            if !allow_defaults && default.is_some() {
+                tcx.sess.span_err(
+                    param.span,
+                    "defaults for const parameters are only allowed in \
+                    `struct`, `enum`, `type`, or `trait` definitions",
+                );
+            }
+
             let param_def = ty::GenericParamDef {
                 index: type_start + i as u32,
                 name: param.name.ident().name,
                 def_id: tcx.hir().local_def_id(param.hir_id).to_def_id(),
                 pure_wrt_drop: param.pure_wrt_drop,
-                kind: ty::GenericParamDefKind::Const,
+                kind: ty::GenericParamDefKind::Const { has_default: default.is_some() },
             };
             i += 1;
             Some(param_def)
@@ -1409,6 +1422,21 @@ fn generics_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::Generics {
     }
 }
 
+fn const_param_default(tcx: TyCtxt<'_>, def_id: DefId) -> &ty::Const<'_> {
+    let hir_id = tcx.hir().as_local_hir_id(def_id.expect_local());
+    let default_def_id = match tcx.hir().get(hir_id) {
+        Node::GenericParam(hir::GenericParam {
+            kind: hir::GenericParamKind::Const { default: Some(ct), .. },
+            ..
+        }) => tcx.hir().local_def_id(ct.hir_id),
+        _ => span_bug!(
+            tcx.def_span(def_id),
+            "`const_param_default` expected a const generic parameter with a default"
+        ),
+    };
+    ty::Const::from_anon_const(tcx, default_def_id)
+}
+
 fn are_suggestable_generic_args(generic_args: &[hir::GenericArg<'_>]) -> bool {
     generic_args
         .iter()
@@ -1885,6 +1913,15 @@ fn explicit_predicates_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::GenericPredicat
             &hir::WherePredicate::BoundPredicate(ref bound_pred) => {
                 let ty = icx.to_ty(&bound_pred.bounded_ty);
 
+                // Generic constants mentioned in the bounded type are assumed to
+                // be evaluatable inside of this item.
+                if tcx.features().const_evaluatable_checked {
+                    let span = bound_pred.bounded_ty.span;
+                    predicates.extend(
+                        const_evaluatable_predicates_of(tcx, ty).into_iter().map(|p| (p, span)),
+                    );
+                }
+
                 // Keep the type around in a dummy predicate, in case of no bounds.
                 // That way, `where Ty:` is not a complete noop (see #53696) and `Ty`
                 // is still checked for WF.
@@ -2041,7 +2078,7 @@ fn associated_item_predicates(
                 unimplemented_error("type");
                 tcx.types.err.into()
             }
-            ty::GenericParamDefKind::Const => {
+            ty::GenericParamDefKind::Const { .. } => {
                 unimplemented_error("const");
                 tcx.mk_const(ty::Const { val: ty::ConstKind::Error, ty: tcx.type_of(param.def_id) })
                     .into()
@@ -2096,6 +2133,31 @@ fn associated_item_predicates(
     }
 }
 
+/// Returns a `ConstEvaluatable` predicate for each generic constant used in `ty`.
+fn const_evaluatable_predicates_of<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+) -> Vec<ty::Predicate<'tcx>> {
+    struct ConstCollector<'tcx> {
+        tcx: TyCtxt<'tcx>,
+        preds: Vec<ty::Predicate<'tcx>>,
+    }
+
+    impl<'tcx> TypeVisitor<'tcx> for ConstCollector<'tcx> {
+        fn visit_const(&mut self, ct: &'tcx ty::Const<'tcx>) -> bool {
+            if let ty::ConstKind::Unevaluated(def_id, substs, None) = ct.val {
+                let pred = ty::PredicateKind::ConstEvaluatable(def_id, substs);
+                self.preds.push(pred.to_predicate(self.tcx));
+            }
+            ct.super_visit_with(self)
+        }
+    }
+
+    let mut collector = ConstCollector { tcx, preds: Vec::new() };
+    ty.visit_with(&mut collector);
+    collector.preds
+}
+
 /// Converts a specific `GenericBound` from the AST into a set of
 /// predicates that apply to the self type. A vector is returned
 /// because this can be anywhere from zero predicates (`T: ?Sized` adds no
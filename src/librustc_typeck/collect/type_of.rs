@@ -221,6 +221,22 @@ pub(super) fn type_of(tcx: TyCtxt<'_>, def_id: DefId) -> Ty<'_> {
                     tcx.types.usize
                 }
 
+                Node::Expr(&Expr { kind: ExprKind::ConstBlock(ref anon_const), .. })
+                    if anon_const.hir_id == hir_id =>
+                {
+                    // The type of an inline `const` block is inferred along with
+                    // the body it appears in.
+                    tcx.typeck_tables_of(def_id.expect_local()).node_type(anon_const.hir_id)
+                }
+
+                Node::GenericParam(&GenericParam {
+                    hir_id: param_hir_id,
+                    kind: GenericParamKind::Const { default: Some(ct), .. },
+                    ..
+                }) if ct.hir_id == hir_id => {
+                    tcx.type_of(tcx.hir().local_def_id(param_hir_id).to_def_id())
+                }
+
                 Node::Variant(Variant { disr_expr: Some(ref e), .. }) if e.hir_id == hir_id => tcx
                     .adt_def(tcx.hir().get_parent_did(hir_id).to_def_id())
                     .repr
@@ -292,7 +308,7 @@ pub(super) fn type_of(tcx: TyCtxt<'_>, def_id: DefId) -> Ty<'_> {
                         .params
                         .iter()
                         .filter(|param| {
-                            if let ty::GenericParamDefKind::Const = param.kind {
+                            if let ty::GenericParamDefKind::Const { .. } = param.kind {
                                 true
                             } else {
                                 false
@@ -331,6 +347,31 @@ pub(super) fn type_of(tcx: TyCtxt<'_>, def_id: DefId) -> Ty<'_> {
             GenericParamKind::Type { default: Some(ref ty), .. } => icx.to_ty(ty),
             GenericParamKind::Const { ty: ref hir_ty, .. } => {
                 let ty = icx.to_ty(hir_ty);
+                if tcx.features().min_const_generics && !tcx.features().const_generics {
+                    // `min_const_generics` only supports a small set of types which are all
+                    // trivially structural-match, so the checks below are not needed.
+                    let is_supported = match ty.kind {
+                        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Error => true,
+                        _ => false,
+                    };
+                    if !is_supported {
+                        tcx.sess
+                            .struct_span_err(
+                                hir_ty.span,
+                                &format!(
+                                    "`{}` is forbidden as the type of a const generic parameter",
+                                    ty
+                                ),
+                            )
+                            .note("the only supported types are integers, `bool` and `char`")
+                            .help(
+                                "more complex types are supported with \
+                                 `#![feature(const_generics)]`",
+                            )
+                            .emit();
+                    }
+                    return ty;
+                }
                 if !tcx.features().const_compare_raw_pointers {
                     let err = match ty.peel_refs().kind {
                         ty::FnPtr(_) => Some("function pointers"),
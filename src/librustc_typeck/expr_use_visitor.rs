@@ -5,14 +5,16 @@
 pub use self::ConsumeMode::*;
 
 // Export these here so that Clippy can use them.
-pub use mc::{Place, PlaceBase, Projection};
+pub use mc::{Place, PlaceBase, Projection, ProjectionKind};
 
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::PatKind;
+use rustc_index::vec::Idx;
 use rustc_infer::infer::InferCtxt;
 use rustc_middle::ty::{self, adjustment, TyCtxt};
+use rustc_target::abi::VariantIdx;
 
 use crate::mem_categorization as mc;
 use rustc_span::Span;
@@ -32,6 +34,11 @@ pub trait Delegate<'tcx> {
 
     // The path at `place` is being assigned to.
     fn mutate(&mut self, assignee_place: &mc::Place<'tcx>);
+
+    // The place at `place` is read as a whole, even though only parts of it
+    // may be used, e.g. the initializer of `let _ = x;` or the base of a
+    // functional record update.
+    fn fake_read(&mut self, _place: &mc::Place<'tcx>) {}
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -255,7 +262,10 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
                 self.consume_exprs(&ia.inputs_exprs);
             }
 
-            hir::ExprKind::Continue(..) | hir::ExprKind::Lit(..) | hir::ExprKind::Err => {}
+            hir::ExprKind::Continue(..)
+            | hir::ExprKind::Lit(..)
+            | hir::ExprKind::ConstBlock(..)
+            | hir::ExprKind::Err => {}
 
             hir::ExprKind::Loop(ref blk, _, _) => {
                 self.walk_block(blk);
@@ -345,6 +355,7 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
             // `walk_pat`:
             self.walk_expr(&expr);
             let init_place = return_if_err!(self.mc.cat_expr(&expr));
+            self.delegate.fake_read(&init_place);
             self.walk_irrefutable_pat(&init_place, &local.pat);
         }
     }
@@ -381,10 +392,11 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
         };
 
         let with_place = return_if_err!(self.mc.cat_expr(&with_expr));
+        self.delegate.fake_read(&with_place);
 
         // Select just those fields of the `with`
         // expression that will actually be used
-        match with_place.ty.kind {
+        match with_place.ty().kind {
             ty::Adt(adt, substs) if adt.is_struct() => {
                 // Consume those fields of the with expression that are needed.
                 for (f_index, with_field) in adt.non_enum_variant().fields.iter().enumerate() {
@@ -396,6 +408,7 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
                             &*with_expr,
                             with_place.clone(),
                             with_field.ty(self.tcx(), substs),
+                            ProjectionKind::Field(f_index as u32, VariantIdx::new(0)),
                         );
                         self.delegate_consume(&field_place);
                     }
@@ -482,8 +495,14 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
     fn walk_arm(&mut self, discr_place: &Place<'tcx>, arm: &hir::Arm<'_>) {
         self.walk_pat(discr_place, &arm.pat);
 
-        if let Some(hir::Guard::If(ref e)) = arm.guard {
-            self.consume_expr(e)
+        match arm.guard {
+            Some(hir::Guard::If(ref e)) => self.consume_expr(e),
+            Some(hir::Guard::IfLet(ref pat, ref e)) => {
+                let e_place = return_if_err!(self.mc.cat_expr(e));
+                self.borrow_expr(e, ty::ImmBorrow);
+                self.walk_pat(&e_place, pat);
+            }
+            None => {}
         }
 
         self.consume_expr(&arm.body);
@@ -539,6 +558,35 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
         debug!("walk_captures({:?})", closure_expr);
 
         let closure_def_id = self.tcx().hir().local_def_id(closure_expr.hir_id);
+
+        // With `capture_disjoint_fields`, the closure may only capture some
+        // paths rooted at each variable; report exactly those paths.
+        if let Some(min_captures) =
+            self.mc.tables.closure_min_captures.get(&closure_def_id.to_def_id())
+        {
+            for (&var_id, captures) in min_captures {
+                let var_place = return_if_err!(self.cat_captured_var(
+                    closure_expr.hir_id,
+                    fn_decl_span,
+                    var_id,
+                ));
+                for captured in captures {
+                    let mut captured_place = var_place.clone();
+                    captured_place.projections.extend(captured.place.projections.iter().cloned());
+                    match captured.info.capture_kind {
+                        ty::UpvarCapture::ByValue => {
+                            let mode = copy_or_move(&self.mc, &captured_place);
+                            self.delegate.consume(&captured_place, mode);
+                        }
+                        ty::UpvarCapture::ByRef(upvar_borrow) => {
+                            self.delegate.borrow(&captured_place, upvar_borrow.kind);
+                        }
+                    }
+                }
+            }
+            return;
+        }
+
         if let Some(upvars) = self.tcx().upvars_mentioned(closure_def_id) {
             for &var_id in upvars.keys() {
                 let upvar_id = ty::UpvarId {
@@ -581,5 +629,5 @@ fn copy_or_move<'a, 'tcx>(
     mc: &mc::MemCategorizationContext<'a, 'tcx>,
     place: &Place<'tcx>,
 ) -> ConsumeMode {
-    if !mc.type_is_copy_modulo_regions(place.ty, place.span) { Move } else { Copy }
+    if !mc.type_is_copy_modulo_regions(place.ty(), place.span) { Move } else { Copy }
 }
@@ -588,11 +588,16 @@ impl Clean<GenericParamDef> for ty::GenericParamDef {
                     },
                 )
             }
-            ty::GenericParamDefKind::Const { .. } => (
+            ty::GenericParamDefKind::Const { has_default } => (
                 self.name.clean(cx),
                 GenericParamDefKind::Const {
                     did: self.def_id,
                     ty: cx.tcx.type_of(self.def_id).clean(cx),
+                    default: if has_default {
+                        Some(print_const(cx, cx.tcx.const_param_default(self.def_id)))
+                    } else {
+                        None
+                    },
                 },
             ),
         };
@@ -630,11 +635,12 @@ impl Clean<GenericParamDef> for hir::GenericParam<'_> {
                     synthetic,
                 },
             ),
-            hir::GenericParamKind::Const { ref ty } => (
+            hir::GenericParamKind::Const { ref ty, default } => (
                 self.name.ident().name.clean(cx),
                 GenericParamDefKind::Const {
                     did: cx.tcx.hir().local_def_id(self.hir_id).to_def_id(),
                     ty: ty.clean(cx),
+                    default: default.map(|ct| print_const_expr(cx, ct.body)),
                 },
             ),
         };
@@ -178,15 +178,25 @@ impl clean::GenericParamDef {
 
                 Ok(())
             }
-            clean::GenericParamDefKind::Const { ref ty, .. } => {
+            clean::GenericParamDefKind::Const { ref ty, ref default, .. } => {
                 f.write_str("const ")?;
                 f.write_str(&self.name)?;
 
                 if f.alternate() {
-                    write!(f, ": {:#}", ty.print())
+                    write!(f, ": {:#}", ty.print())?;
                 } else {
-                    write!(f, ":&nbsp;{}", ty.print())
+                    write!(f, ":&nbsp;{}", ty.print())?;
                 }
+
+                if let Some(ref default) = default {
+                    if f.alternate() {
+                        write!(f, " = {}", default)?;
+                    } else {
+                        write!(f, "&nbsp;=&nbsp;{}", Escape(default))?;
+                    }
+                }
+
+                Ok(())
             }
         })
     }
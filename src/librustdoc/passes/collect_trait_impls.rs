@@ -60,6 +60,13 @@ pub fn collect_trait_impls(krate: Crate, cx: &DocContext<'_>) -> Crate {
         lang_items.str_alloc_impl(),
         lang_items.slice_alloc_impl(),
         lang_items.slice_u8_alloc_impl(),
+        lang_items.c_str_alloc_impl(),
+        lang_items.error_alloc_impl(),
+        lang_items.error_send_alloc_impl(),
+        lang_items.error_send_sync_alloc_impl(),
+        lang_items.error_std_impl(),
+        lang_items.error_send_std_impl(),
+        lang_items.error_send_sync_std_impl(),
         lang_items.const_ptr_impl(),
         lang_items.mut_ptr_impl(),
         lang_items.const_slice_ptr_impl(),
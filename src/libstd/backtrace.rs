@@ -7,7 +7,8 @@
 //!
 //! A backtrace is typically quite handy to attach to errors (e.g. types
 //! implementing `std::error::Error`) to get a causal chain of where an error
-//! was generated.
+//! was generated. Errors hand out their backtrace through `Error::provide`,
+//! and callers retrieve it with `<dyn Error>::backtrace`.
 //!
 //! > **Note**: this module is unstable and is designed in [RFC 2504], and you
 //! > can learn more about its status in the [tracking issue].
@@ -91,11 +92,13 @@
 // `Backtrace`, but that's a relatively small price to pay relative to capturing
 // a backtrace or actually symbolizing it.
 
+use crate::cell::UnsafeCell;
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
+use crate::path::PathBuf;
 use crate::sync::atomic::{AtomicUsize, Ordering::SeqCst};
-use crate::sync::Mutex;
+use crate::sync::Once;
 use crate::sys_common::backtrace::{lock, output_filename};
 use crate::vec::Vec;
 use backtrace::BytesOrWideString;
@@ -130,7 +133,7 @@ pub enum BacktraceStatus {
 enum Inner {
     Unsupported,
     Disabled,
-    Captured(Mutex<Capture>),
+    Captured(LazilyResolvedCapture),
 }
 
 struct Capture {
@@ -144,7 +147,15 @@ fn _assert_send_sync() {
     _assert::<Backtrace>();
 }
 
-struct BacktraceFrame {
+/// A single frame of a captured backtrace.
+///
+/// Frames are returned by [`Backtrace::frames`]. A frame corresponds to one
+/// instruction pointer on the stack, but may resolve to several symbols if
+/// functions were inlined into it.
+///
+/// [`Backtrace::frames`]: struct.Backtrace.html#method.frames
+#[unstable(feature = "backtrace_frames", issue = "none")]
+pub struct BacktraceFrame {
     frame: RawFrame,
     symbols: Vec<BacktraceSymbol>,
 }
@@ -155,10 +166,20 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol that a [`BacktraceFrame`] resolved to.
+///
+/// Only the line of a symbol's location is available, not its column. The
+/// `backtrace` crate reports columns starting with version 0.3.54, but that
+/// version can no longer be built as a dependency of the standard library,
+/// which stays on 0.3.46 until it can.
+///
+/// [`BacktraceFrame`]: struct.BacktraceFrame.html
+#[unstable(feature = "backtrace_frames", issue = "none")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
+    inlined: bool,
 }
 
 enum BytesOrWide {
@@ -168,12 +189,11 @@ enum BytesOrWide {
 
 impl fmt::Debug for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut capture = match &self.inner {
+        let capture = match &self.inner {
             Inner::Unsupported => return fmt.write_str("<unsupported>"),
             Inner::Disabled => return fmt.write_str("<disabled>"),
-            Inner::Captured(c) => c.lock().unwrap(),
+            Inner::Captured(c) => c.force(),
         };
-        capture.resolve();
 
         let frames = &capture.frames[capture.actual_start..];
 
@@ -215,6 +235,15 @@ impl fmt::Debug for BacktraceSymbol {
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "none")]
+impl fmt::Debug for BacktraceFrame {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut dbg = fmt.debug_list();
+        dbg.entries(&self.symbols);
+        dbg.finish()
+    }
+}
+
 impl fmt::Debug for BytesOrWide {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         output_filename(
@@ -317,7 +346,7 @@ impl Backtrace {
         let inner = if frames.is_empty() {
             Inner::Unsupported
         } else {
-            Inner::Captured(Mutex::new(Capture {
+            Inner::Captured(LazilyResolvedCapture::new(Capture {
                 actual_start: actual_start.unwrap_or(0),
                 frames,
                 resolved: false,
@@ -337,16 +366,140 @@ impl Backtrace {
             Inner::Captured(_) => BacktraceStatus::Captured,
         }
     }
+
+    /// Returns the frames of this backtrace, starting with the frame which
+    /// called `capture` or `force_capture`.
+    ///
+    /// Capturing a backtrace only records instruction pointers. The first
+    /// call to this function (or the first time the backtrace is formatted)
+    /// resolves them to symbols, which can be expensive, so a backtrace can
+    /// be captured eagerly and only resolved if it is actually inspected.
+    ///
+    /// An empty slice is returned if no backtrace was captured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace, backtrace_frames)]
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// for frame in backtrace.frames() {
+    ///     for symbol in frame.symbols() {
+    ///         println!("{:?} at {:?}:{:?}", symbol.name(), symbol.filename(), symbol.lineno());
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn frames(&self) -> &[BacktraceFrame] {
+        match &self.inner {
+            Inner::Unsupported | Inner::Disabled => &[],
+            Inner::Captured(c) => {
+                let capture = c.force();
+                &capture.frames[capture.actual_start..]
+            }
+        }
+    }
+}
+
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is normally the address of the next instruction to execute in
+    /// the frame, not the one currently executing.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the starting address of the function this frame is in.
+    ///
+    /// This may be the same as `ip` on platforms where the symbol address
+    /// cannot be determined.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.frame.symbol_address()
+    }
+
+    /// Returns the symbols this frame resolved to.
+    ///
+    /// When functions were inlined into this frame there is one symbol per
+    /// inlined function, innermost first, followed by the function the code
+    /// was inlined into. All symbols but the last return `true` from
+    /// [`BacktraceSymbol::is_inlined`]. The slice is empty if the frame could
+    /// not be resolved.
+    ///
+    /// [`BacktraceSymbol::is_inlined`]: struct.BacktraceSymbol.html#method.is_inlined
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of this symbol, without the trailing hash
+    /// of Rust symbol names.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace::SymbolName::new(b)))
+    }
+
+    /// Returns the raw, possibly mangled, name of this symbol.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn raw_name(&self) -> Option<&[u8]> {
+        self.name.as_deref()
+    }
+
+    /// Returns the file this symbol was defined in, if debug information is
+    /// available.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().map(|b| b.to_path_buf())
+    }
+
+    /// Returns the line number for this symbol, if debug information is
+    /// available.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns whether this symbol is a function that was inlined into the
+    /// frame it belongs to.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn is_inlined(&self) -> bool {
+        self.inlined
+    }
+}
+
+impl BytesOrWide {
+    fn to_path_buf(&self) -> PathBuf {
+        match self {
+            #[cfg(unix)]
+            BytesOrWide::Bytes(bytes) => {
+                use crate::os::unix::prelude::*;
+                crate::ffi::OsStr::from_bytes(bytes).into()
+            }
+            #[cfg(not(unix))]
+            BytesOrWide::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned().into(),
+            #[cfg(windows)]
+            BytesOrWide::Wide(wide) => {
+                use crate::os::windows::prelude::*;
+                crate::ffi::OsString::from_wide(wide).into()
+            }
+            #[cfg(not(windows))]
+            BytesOrWide::Wide(wide) => String::from_utf16_lossy(wide).into(),
+        }
+    }
 }
 
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut capture = match &self.inner {
+        let capture = match &self.inner {
             Inner::Unsupported => return fmt.write_str("unsupported backtrace"),
             Inner::Disabled => return fmt.write_str("disabled backtrace"),
-            Inner::Captured(c) => c.lock().unwrap(),
+            Inner::Captured(c) => c.force(),
         };
-        capture.resolve();
 
         let full = fmt.alternate();
         let (frames, style) = if full {
@@ -389,6 +542,37 @@ impl fmt::Display for Backtrace {
     }
 }
 
+// A capture which is resolved the first time it is looked at. Once resolved
+// the frames are never mutated again, which is what allows handing out shared
+// references to them from `Backtrace::frames`.
+struct LazilyResolvedCapture {
+    sync: Once,
+    capture: UnsafeCell<Capture>,
+}
+
+impl LazilyResolvedCapture {
+    fn new(capture: Capture) -> Self {
+        LazilyResolvedCapture { sync: Once::new(), capture: UnsafeCell::new(capture) }
+    }
+
+    fn force(&self) -> &Capture {
+        self.sync.call_once(|| {
+            // SAFETY: This is the only place the capture is mutated, and
+            // `call_once` guarantees it runs at most once, before any shared
+            // reference below is created.
+            unsafe { (*self.capture.get()).resolve() };
+        });
+
+        // SAFETY: Resolution has finished and the capture is immutable from
+        // now on.
+        unsafe { &*self.capture.get() }
+    }
+}
+
+// SAFETY: The capture is only mutated inside `Once::call_once`, which
+// synchronizes with every other access.
+unsafe impl Sync for LazilyResolvedCapture where Capture: Sync {}
+
 impl Capture {
     fn resolve(&mut self) {
         // If we're already resolved, nothing to do!
@@ -410,6 +594,11 @@ impl Capture {
             };
             unsafe {
                 backtrace::resolve_frame_unsynchronized(frame, |symbol| {
+                    // Symbols are reported innermost first, so every symbol
+                    // but the last one of a frame has been inlined.
+                    if let Some(prev) = symbols.last_mut() {
+                        prev.inlined = true;
+                    }
                     symbols.push(BacktraceSymbol {
                         name: symbol.name().map(|m| m.as_bytes().to_vec()),
                         filename: symbol.filename_raw().map(|b| match b {
@@ -417,6 +606,7 @@ impl Capture {
                             BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
                         }),
                         lineno: symbol.lineno(),
+                        inlined: false,
                     });
                 });
             }
@@ -432,12 +622,20 @@ impl RawFrame {
             RawFrame::Fake => 1 as *mut c_void,
         }
     }
+
+    fn symbol_address(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            #[cfg(test)]
+            RawFrame::Fake => 1 as *mut c_void,
+        }
+    }
 }
 
-#[test]
-fn test_debug() {
-    let backtrace = Backtrace {
-        inner: Inner::Captured(Mutex::new(Capture {
+#[cfg(test)]
+fn generate_fake_backtrace() -> Backtrace {
+    Backtrace {
+        inner: Inner::Captured(LazilyResolvedCapture::new(Capture {
             actual_start: 1,
             resolved: true,
             frames: vec![
@@ -447,6 +645,7 @@ fn test_debug() {
                         name: Some(b"std::backtrace::Backtrace::create".to_vec()),
                         filename: Some(BytesOrWide::Bytes(b"rust/backtrace.rs".to_vec())),
                         lineno: Some(100),
+                        inlined: false,
                     }],
                 },
                 BacktraceFrame {
@@ -455,6 +654,7 @@ fn test_debug() {
                         name: Some(b"__rust_maybe_catch_panic".to_vec()),
                         filename: None,
                         lineno: None,
+                        inlined: false,
                     }],
                 },
                 BacktraceFrame {
@@ -464,17 +664,24 @@ fn test_debug() {
                             name: Some(b"std::rt::lang_start_internal".to_vec()),
                             filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
                             lineno: Some(300),
+                            inlined: true,
                         },
                         BacktraceSymbol {
                             name: Some(b"std::rt::lang_start".to_vec()),
                             filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
                             lineno: Some(400),
+                            inlined: false,
                         },
                     ],
                 },
             ],
         })),
-    };
+    }
+}
+
+#[test]
+fn test_debug() {
+    let backtrace = generate_fake_backtrace();
 
     #[rustfmt::skip]
     let expected = "Backtrace [\
@@ -485,3 +692,22 @@ fn test_debug() {
 
     assert_eq!(format!("{:#?}", backtrace), expected);
 }
+
+#[test]
+fn test_frames() {
+    let backtrace = generate_fake_backtrace();
+    let frames = backtrace.frames();
+    assert_eq!(frames.len(), 2);
+
+    let symbols = frames[1].symbols();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0].name().as_deref(), Some("std::rt::lang_start_internal"));
+    assert_eq!(symbols[0].raw_name(), Some(&b"std::rt::lang_start_internal"[..]));
+    assert_eq!(symbols[0].filename(), Some(PathBuf::from("rust/rt.rs")));
+    assert_eq!(symbols[0].lineno(), Some(300));
+    assert!(symbols[0].is_inlined());
+    assert!(!symbols[1].is_inlined());
+
+    let disabled = Backtrace { inner: Inner::Disabled };
+    assert!(disabled.frames().is_empty());
+}
@@ -155,10 +155,12 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use alloc_crate::ffi::{CString, IntoStringError, NulError};
 #[stable(feature = "cstr_from_bytes", since = "1.10.0")]
-pub use self::c_str::FromBytesWithNulError;
+pub use core::ffi::FromBytesWithNulError;
 #[stable(feature = "rust1", since = "1.0.0")]
-pub use self::c_str::{CStr, CString, IntoStringError, NulError};
+pub use core::ffi::CStr;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::os_str::{OsStr, OsString};
@@ -174,5 +176,8 @@ pub use core::ffi::c_void;
 )]
 pub use core::ffi::{VaList, VaListImpl};
 
+#[cfg(any(unix, target_os = "cloudabi"))]
+pub(crate) use self::c_str::cstr_from_ptr;
+
 mod c_str;
 mod os_str;
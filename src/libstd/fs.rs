@@ -9,7 +9,8 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-use crate::ffi::OsString;
+use crate::cmp::Ordering;
+use crate::ffi::{OsStr, OsString};
 use crate::fmt;
 use crate::io::{self, Initializer, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
 use crate::path::{Path, PathBuf};
@@ -526,6 +527,185 @@ impl File {
         self.inner.truncate(size)
     }
 
+    /// Acquires an exclusive advisory lock on the file, blocking until it
+    /// can be acquired.
+    ///
+    /// At most one handle may hold an exclusive lock on a file at a time,
+    /// and no handle may hold a shared lock while it does. The lock is
+    /// released by [`unlock`] or when the file is closed.
+    ///
+    /// The lock is only advisory: it does not stop other processes from
+    /// reading or writing the file unless they also use locking.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_EX` flag, and to the `LockFileEx` function on Windows
+    /// with the `LOCKFILE_EXCLUSIVE_LOCK` flag. On Solaris and illumos, which
+    /// lack `flock`, a whole-file `fcntl` lock is used, which is held by the
+    /// process rather than by this handle. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// Whether a handle that already holds a lock can upgrade or downgrade
+    /// it by calling another locking method is unspecified.
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`unlock`]: #method.unlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     f.lock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared advisory lock on the file, blocking until it can be
+    /// acquired.
+    ///
+    /// Any number of handles may hold a shared lock on a file at the same
+    /// time, as long as no handle holds an exclusive lock. The lock is
+    /// released by [`unlock`] or when the file is closed.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_SH` flag, and to the `LockFileEx` function on Windows.
+    /// See [`lock`] for the other caveats that apply. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`lock`]: #method.lock
+    /// [`unlock`]: #method.unlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     f.lock_shared()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Tries to acquire an exclusive advisory lock on the file without
+    /// blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired and `Ok(false)` if another
+    /// handle holds a conflicting lock. See [`lock`] for details about the
+    /// lock itself.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_EX | LOCK_NB` flags, and to the `LockFileEx` function on
+    /// Windows with the `LOCKFILE_EXCLUSIVE_LOCK` and
+    /// `LOCKFILE_FAIL_IMMEDIATELY` flags. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`lock`]: #method.lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     if !f.try_lock()? {
+    ///         println!("foo.txt is in use by another process");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock()
+    }
+
+    /// Tries to acquire a shared advisory lock on the file without blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired and `Ok(false)` if another
+    /// handle holds an exclusive lock. See [`lock_shared`] for details about
+    /// the lock itself.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_SH | LOCK_NB` flags, and to the `LockFileEx` function on
+    /// Windows with the `LOCKFILE_FAIL_IMMEDIATELY` flag. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`lock_shared`]: #method.lock_shared
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     if f.try_lock_shared()? {
+    ///         // read the file while no one is writing to it
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases a lock acquired through this handle.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_UN` flag, and to the `UnlockFile` function on Windows.
+    /// Windows returns an error if the file is not locked, while Unix does
+    /// not. Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     f.lock()?;
+    ///     // update the file
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
+
     /// Queries metadata about the underlying file.
     ///
     /// # Examples
@@ -740,6 +920,13 @@ impl Seek for &File {
     }
 }
 
+#[unstable(feature = "is_terminal", issue = "none")]
+impl io::IsTerminal for File {
+    fn is_terminal(&self) -> bool {
+        self.inner.is_terminal()
+    }
+}
+
 impl OpenOptions {
     /// Creates a blank new set of options ready for configuration.
     ///
@@ -2114,6 +2301,568 @@ pub fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
     fs_imp::readdir(path.as_ref()).map(ReadDir)
 }
 
+/// Returns a builder for an iterator over all the entries of a directory
+/// tree, recursively.
+///
+/// The iterator yields the entry for `path` itself first, at depth 0, and
+/// then every entry below it. Each directory is yielded before its contents.
+/// The builder can limit the depths that are visited, follow symbolic links
+/// and sort the entries of each directory, and [`WalkDirIter::skip_current_dir`]
+/// can be used to prune whole subtrees while iterating.
+///
+/// Directories are opened relative to their already opened parent where the
+/// platform allows it, so that renaming or replacing one of their ancestors
+/// while the walk is in progress can't redirect it to another part of the
+/// filesystem. Symbolic links other than `path` itself are only followed if
+/// [`follow_links`] is enabled, in which case loops are detected and reported
+/// as errors instead of being walked forever.
+///
+/// [`WalkDirIter::skip_current_dir`]: struct.WalkDirIter.html#method.skip_current_dir
+/// [`follow_links`]: struct.WalkDir.html#method.follow_links
+///
+/// # Platform-specific behavior
+///
+/// On Unix, subdirectories are opened with `openat` and `fdopendir`. Other
+/// platforms open them by path. Note that, this
+/// [may change in the future][changes].
+///
+/// [changes]: ../io/index.html#platform-specific-behavior
+///
+/// # Errors
+///
+/// The iterator yields an error, and carries on with the rest of the walk,
+/// if a directory can't be read, for example because the process lacks the
+/// permission to read it, or if it is part of a symbolic link loop.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     for entry in fs::walk_dir("src").max_depth(2).sort_by_file_name() {
+///         let entry = entry?;
+///         println!("{}{}", "  ".repeat(entry.depth()), entry.file_name().to_string_lossy());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> WalkDir {
+    WalkDir {
+        root: path.as_ref().to_path_buf(),
+        min_depth: 0,
+        max_depth: usize::MAX,
+        follow_links: false,
+        sorter: None,
+    }
+}
+
+/// A builder to configure a recursive walk over a directory tree.
+///
+/// This struct is created by the [`walk_dir`] function, and turned into an
+/// iterator with [`IntoIterator::into_iter`] or by using it in a `for` loop.
+///
+/// [`walk_dir`]: fn.walk_dir.html
+/// [`IntoIterator::into_iter`]: ../iter/trait.IntoIterator.html#tymethod.into_iter
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDir {
+    root: PathBuf,
+    min_depth: usize,
+    max_depth: usize,
+    follow_links: bool,
+    sorter: Option<Box<dyn FnMut(&WalkDirEntry, &WalkDirEntry) -> Ordering + Send + Sync>>,
+}
+
+impl WalkDir {
+    /// Only yields entries at least `depth` levels below the root.
+    ///
+    /// The root itself is at depth 0 and its direct children at depth 1.
+    /// Directories above the minimum depth are still walked.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn min_depth(mut self, depth: usize) -> WalkDir {
+        self.min_depth = depth;
+        self
+    }
+
+    /// Doesn't descend more than `depth` levels below the root.
+    ///
+    /// With a maximum depth of 0 only the root itself is yielded.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn max_depth(mut self, depth: usize) -> WalkDir {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Follows symbolic links to directories and walks their contents.
+    ///
+    /// When enabled, entries for symbolic links report the file type of their
+    /// target, and a link pointing to one of its own ancestors yields an error
+    /// instead of being walked. Disabled by default.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn follow_links(mut self, follow: bool) -> WalkDir {
+        self.follow_links = follow;
+        self
+    }
+
+    /// Yields the entries of each directory in the order given by `compare`.
+    ///
+    /// This reads a whole directory before yielding any of its entries.
+    /// Errors reading a directory are yielded before its entries.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn sort_by<F>(mut self, compare: F) -> WalkDir
+    where
+        F: FnMut(&WalkDirEntry, &WalkDirEntry) -> Ordering + Send + Sync + 'static,
+    {
+        self.sorter = Some(Box::new(compare));
+        self
+    }
+
+    /// Yields the entries of each directory sorted by file name.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn sort_by_file_name(self) -> WalkDir {
+        self.sort_by(|a, b| a.file_name().cmp(b.file_name()))
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl fmt::Debug for WalkDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalkDir")
+            .field("root", &self.root)
+            .field("min_depth", &self.min_depth)
+            .field("max_depth", &self.max_depth)
+            .field("follow_links", &self.follow_links)
+            .field("sorted", &self.sorter.is_some())
+            .finish()
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl IntoIterator for WalkDir {
+    type Item = io::Result<WalkDirEntry>;
+    type IntoIter = WalkDirIter;
+
+    fn into_iter(self) -> WalkDirIter {
+        WalkDirIter {
+            start: Some(self.root),
+            min_depth: self.min_depth,
+            max_depth: self.max_depth,
+            follow_links: self.follow_links,
+            sorter: self.sorter,
+            stack: Vec::new(),
+            ids: Vec::new(),
+            deferred: None,
+            last_unopened_dir: false,
+        }
+    }
+}
+
+/// An iterator over the entries of a directory tree.
+///
+/// This struct is created by calling `into_iter` on a [`WalkDir`].
+///
+/// [`WalkDir`]: struct.WalkDir.html
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDirIter {
+    // The root, until it has been yielded.
+    start: Option<PathBuf>,
+    min_depth: usize,
+    max_depth: usize,
+    follow_links: bool,
+    sorter: Option<Box<dyn FnMut(&WalkDirEntry, &WalkDirEntry) -> Ordering + Send + Sync>>,
+    // The directories being walked, from the root down to the current one,
+    // along with their identities for detecting loops.
+    stack: Vec<DirList>,
+    ids: Vec<fs_imp::DirId>,
+    // An error opening the directory that was yielded last, to be yielded
+    // next unless that directory is skipped.
+    deferred: Option<io::Error>,
+    // Whether the entry that was yielded last is a directory the walk did not
+    // descend into, because of `max_depth` or an error opening it.
+    last_unopened_dir: bool,
+}
+
+enum DirList {
+    Open(fs_imp::ReadDir),
+    Sorted(crate::vec::IntoIter<io::Result<WalkDirEntry>>),
+}
+
+impl DirList {
+    fn next(&mut self, depth: usize, follow_links: bool) -> Option<io::Result<WalkDirEntry>> {
+        match *self {
+            DirList::Open(ref mut dir) => dir
+                .next()
+                .map(|entry| entry.and_then(|e| WalkDirEntry::from_entry(e, depth, follow_links))),
+            DirList::Sorted(ref mut entries) => entries.next(),
+        }
+    }
+}
+
+impl WalkDirIter {
+    /// Skips the rest of the directory that was yielded last.
+    ///
+    /// If the last entry yielded was a directory, none of its contents are
+    /// yielded. Otherwise the remaining entries of the directory containing
+    /// it are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut walk = fs::walk_dir(".").into_iter();
+    ///     while let Some(entry) = walk.next() {
+    ///         let entry = entry?;
+    ///         if entry.file_name() == "target" {
+    ///             walk.skip_current_dir();
+    ///             continue;
+    ///         }
+    ///         println!("{}", entry.path().display());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn skip_current_dir(&mut self) {
+        // The last directory isn't on the stack, so there is nothing to pop,
+        // but an error about opening it doesn't matter anymore.
+        if self.last_unopened_dir {
+            self.deferred = None;
+            return;
+        }
+        if self.stack.pop().is_some() {
+            self.ids.pop();
+        }
+    }
+
+    // Opens `entry` and makes it the current directory if the walk should
+    // descend into it. Returns whether it did.
+    fn push(&mut self, entry: &WalkDirEntry) -> io::Result<bool> {
+        if !entry.file_type.is_dir() || entry.depth >= self.max_depth {
+            return Ok(false);
+        }
+        let (dir, id) = match entry.inner {
+            Some(ref inner) => inner.open_dir(entry.follow_link)?,
+            None => fs_imp::open_dir(&entry.path)?,
+        };
+        if self.ids.contains(&id) {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("file system loop found: {} points to an ancestor", entry.path.display()),
+            ));
+        }
+
+        let depth = entry.depth + 1;
+        let list = match self.sorter {
+            None => DirList::Open(dir),
+            Some(ref mut compare) => {
+                let follow_links = self.follow_links;
+                let mut entries: Vec<_> = dir
+                    .map(|entry| {
+                        entry.and_then(|e| WalkDirEntry::from_entry(e, depth, follow_links))
+                    })
+                    .collect();
+                entries.sort_by(|a, b| match (a, b) {
+                    (Ok(a), Ok(b)) => compare(a, b),
+                    (Err(_), Err(_)) => Ordering::Equal,
+                    (Ok(_), Err(_)) => Ordering::Greater,
+                    (Err(_), Ok(_)) => Ordering::Less,
+                });
+                DirList::Sorted(entries.into_iter())
+            }
+        };
+        self.stack.push(list);
+        self.ids.push(id);
+        Ok(true)
+    }
+
+    // Descends into `entry` if needed and decides whether it is yielded.
+    fn visit(&mut self, entry: WalkDirEntry) -> Option<io::Result<WalkDirEntry>> {
+        let pushed = self.push(&entry).unwrap_or_else(|e| {
+            self.deferred = Some(e);
+            false
+        });
+        self.last_unopened_dir = entry.file_type.is_dir() && !pushed;
+        if entry.depth >= self.min_depth { Some(Ok(entry)) } else { self.deferred.take().map(Err) }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl Iterator for WalkDirIter {
+    type Item = io::Result<WalkDirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<WalkDirEntry>> {
+        if let Some(err) = self.deferred.take() {
+            return Some(Err(err));
+        }
+        if let Some(root) = self.start.take() {
+            match WalkDirEntry::from_root(root) {
+                Ok(entry) => {
+                    if let Some(ret) = self.visit(entry) {
+                        return Some(ret);
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        loop {
+            let depth = self.stack.len();
+            let next = self.stack.last_mut()?.next(depth, self.follow_links);
+            match next {
+                Some(Ok(entry)) => {
+                    if let Some(ret) = self.visit(entry) {
+                        return Some(ret);
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.stack.pop();
+                    self.ids.pop();
+                }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl fmt::Debug for WalkDirIter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalkDirIter").field("depth", &self.stack.len()).finish()
+    }
+}
+
+/// An entry yielded by a [`WalkDirIter`].
+///
+/// [`WalkDirIter`]: struct.WalkDirIter.html
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDirEntry {
+    path: PathBuf,
+    depth: usize,
+    file_type: FileType,
+    follow_link: bool,
+    // The entry as read from its parent directory; `None` for the root.
+    inner: Option<fs_imp::DirEntry>,
+}
+
+impl WalkDirEntry {
+    fn from_root(path: PathBuf) -> io::Result<WalkDirEntry> {
+        // The root is always followed if it is a symbolic link.
+        let mut file_type = fs_imp::lstat(&path)?.file_type();
+        let follow_link = file_type.is_symlink();
+        if follow_link {
+            file_type = fs_imp::stat(&path)?.file_type();
+        }
+        Ok(WalkDirEntry {
+            path,
+            depth: 0,
+            file_type: FileType(file_type),
+            follow_link,
+            inner: None,
+        })
+    }
+
+    fn from_entry(
+        entry: fs_imp::DirEntry,
+        depth: usize,
+        follow_links: bool,
+    ) -> io::Result<WalkDirEntry> {
+        let path = entry.path();
+        let mut file_type = entry.file_type()?;
+        let follow_link = follow_links && file_type.is_symlink();
+        if follow_link {
+            file_type = fs_imp::stat(&path)?.file_type();
+        }
+        Ok(WalkDirEntry {
+            path,
+            depth,
+            file_type: FileType(file_type),
+            follow_link,
+            inner: Some(entry),
+        })
+    }
+
+    /// Returns the full path to this entry: the root of the walk joined with
+    /// the names of all the directories leading to this entry.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Consumes the entry and returns its full path.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn into_path(self) -> PathBuf {
+        self.path
+    }
+
+    /// Returns the bare file name of this entry, or the whole path for a
+    /// root without a file name such as `.` or `/`.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_name(&self) -> &OsStr {
+        self.path.file_name().unwrap_or_else(|| self.path.as_os_str())
+    }
+
+    /// Returns how many levels below the root this entry is. The root itself
+    /// is at depth 0.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the file type of this entry.
+    ///
+    /// For a symbolic link that was followed this is the type of its target.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_type(&self) -> FileType {
+        self.file_type
+    }
+
+    /// Returns `true` if this entry is a symbolic link, whether or not it was
+    /// followed.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path_is_symlink(&self) -> bool {
+        self.follow_link || self.file_type.is_symlink()
+    }
+
+    /// Queries the metadata of this entry.
+    ///
+    /// For a symbolic link that was followed this is the metadata of its
+    /// target, otherwise symbolic links aren't traversed.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn metadata(&self) -> io::Result<Metadata> {
+        if self.follow_link {
+            return metadata(&self.path);
+        }
+        match self.inner {
+            Some(ref entry) => entry.metadata().map(Metadata),
+            None => symlink_metadata(&self.path),
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl fmt::Debug for WalkDirEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WalkDirEntry").field(&self.path).finish()
+    }
+}
+
+/// A handle to an open directory, relative to which other files and
+/// directories can be opened, created and removed.
+///
+/// Unlike operations on full paths, operations through a `Dir` keep working
+/// on the same directory even if it, or any of its ancestors, is renamed or
+/// replaced after it was opened. This makes it possible to work safely inside
+/// directories that other, less trusted, processes can modify.
+///
+/// Paths passed to the `_at` methods are resolved relative to the directory.
+/// They may consist of several components, and an absolute path ignores the
+/// directory altogether.
+///
+/// # Platform-specific behavior
+///
+/// This type currently corresponds to an `O_DIRECTORY` file descriptor and
+/// the `openat`, `mkdirat` and `unlinkat` functions on Unix. On WASI it
+/// wraps a directory file descriptor as well. On Windows the `Dir` only
+/// remembers the path it was opened with, and the `_at` methods join their
+/// argument onto that path, so they don't provide the guarantees above. Note
+/// that, this [may change in the future][changes].
+///
+/// [changes]: ../io/index.html#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(dir_handle)]
+/// use std::fs::{Dir, OpenOptions};
+/// use std::io::Write;
+///
+/// fn main() -> std::io::Result<()> {
+///     let dir = Dir::open("/var/spool/app")?;
+///     dir.create_dir_at("incoming")?;
+///     let mut options = OpenOptions::new();
+///     options.write(true).create(true);
+///     let mut file = dir.open_file_at("incoming/job", &options)?;
+///     file.write_all(b"hello")?;
+///     dir.remove_at("incoming/job")?;
+///     dir.remove_at("incoming")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "dir_handle", issue = "none")]
+pub struct Dir(fs_imp::DirHandle);
+
+impl Dir {
+    /// Opens the directory at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` does not exist or is
+    /// not a directory, or if the process lacks the permission to read it.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Dir> {
+        fs_imp::DirHandle::open(path.as_ref()).map(Dir)
+    }
+
+    /// Opens the file at `path` relative to this directory, with the options
+    /// specified by `options`.
+    ///
+    /// This is the equivalent of [`OpenOptions::open`] for paths relative to
+    /// the directory.
+    ///
+    /// [`OpenOptions::open`]: struct.OpenOptions.html#method.open
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open_file_at<P: AsRef<Path>>(&self, path: P, options: &OpenOptions) -> io::Result<File> {
+        self.0.open_file_at(path.as_ref(), &options.0).map(|inner| File { inner })
+    }
+
+    /// Creates a new, empty directory at `path` relative to this directory.
+    ///
+    /// This is the equivalent of [`create_dir`] for paths relative to the
+    /// directory.
+    ///
+    /// [`create_dir`]: fn.create_dir.html
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn create_dir_at<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.0.create_dir_at(path.as_ref())
+    }
+
+    /// Removes the file, symbolic link or empty directory at `path` relative
+    /// to this directory.
+    ///
+    /// Symbolic links are removed themselves, not followed.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn remove_at<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.0.remove_at(path.as_ref())
+    }
+
+    /// Returns an iterator over the entries of this directory.
+    ///
+    /// The paths of the entries are built from the path this directory was
+    /// opened with, which may no longer lead to them if the directory was
+    /// moved in the meantime.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        self.0.read_dir().map(ReadDir)
+    }
+}
+
+#[unstable(feature = "dir_handle", issue = "none")]
+impl fmt::Debug for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl AsInner<fs_imp::DirHandle> for Dir {
+    fn as_inner(&self) -> &fs_imp::DirHandle {
+        &self.0
+    }
+}
+
 /// Changes the permissions found on a file or a directory.
 ///
 /// # Platform-specific behavior
@@ -2836,6 +3585,48 @@ mod tests {
         assert!(canary.exists());
     }
 
+    #[test]
+    fn dir_handle_relative_ops() {
+        let tmpdir = tmpdir();
+        let dir = check!(fs::Dir::open(tmpdir.path()));
+        check!(dir.create_dir_at("sub"));
+        assert!(tmpdir.join("sub").is_dir());
+
+        let mut opts = OpenOptions::new();
+        opts.write(true).create_new(true);
+        check!(check!(dir.open_file_at("sub/file", &opts)).write(b"foo"));
+        assert_eq!(check!(fs::read(tmpdir.join("sub/file"))), b"foo");
+        assert!(dir.open_file_at("sub/file", &opts).is_err());
+
+        let names: Vec<_> = check!(dir.read_dir()).map(|e| check!(e).path()).collect();
+        assert_eq!(names, [tmpdir.join("sub")]);
+
+        assert!(dir.remove_at("sub").is_err());
+        check!(dir.remove_at("sub/file"));
+        check!(dir.remove_at("sub"));
+        assert!(!tmpdir.join("sub").exists());
+        assert_eq!(dir.remove_at("sub").unwrap_err().kind(), ErrorKind::NotFound);
+
+        assert!(fs::Dir::open(tmpdir.join("missing")).is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn dir_handle_follows_renames() {
+        let tmpdir = tmpdir();
+        let old = tmpdir.join("old");
+        let new = tmpdir.join("new");
+        check!(fs::create_dir(&old));
+        let dir = check!(fs::Dir::open(&old));
+        check!(fs::rename(&old, &new));
+
+        check!(dir.create_dir_at("sub"));
+        assert!(new.join("sub").is_dir());
+        check!(symlink_dir(&new, &new.join("link")));
+        check!(dir.remove_at("link"));
+        assert!(new.join("sub").is_dir());
+    }
+
     #[test]
     // only Windows makes a distinction between file and directory symlinks.
     #[cfg(windows)]
@@ -3214,6 +4005,44 @@ mod tests {
         assert_eq!(v, b"fo\0\0\0\0wut".to_vec());
     }
 
+    // `fcntl` locks are held per process, so two handles in the same process
+    // never conflict on these platforms.
+    #[test]
+    #[cfg(not(any(target_os = "solaris", target_os = "illumos")))]
+    fn file_lock_conflicts() {
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("lock.txt");
+
+        let f1 = check!(File::create(&path));
+        let f2 = check!(File::open(&path));
+
+        check!(f1.lock());
+        assert!(!check!(f2.try_lock()));
+        assert!(!check!(f2.try_lock_shared()));
+        check!(f1.unlock());
+
+        check!(f1.lock_shared());
+        assert!(check!(f2.try_lock_shared()));
+        assert!(!check!(OpenOptions::new().write(true).open(&path)).try_lock().unwrap());
+        check!(f2.unlock());
+        check!(f1.unlock());
+
+        assert!(check!(f2.try_lock()));
+        drop(f2);
+        // Closing the file releases its lock.
+        assert!(check!(f1.try_lock()));
+        check!(f1.unlock());
+    }
+
+    #[test]
+    fn regular_file_is_not_terminal() {
+        use crate::io::IsTerminal;
+
+        let tmpdir = tmpdir();
+        let f = check!(File::create(&tmpdir.join("tty.txt")));
+        assert!(!f.is_terminal());
+    }
+
     #[test]
     fn open_flavors() {
         use crate::fs::OpenOptions as OO;
@@ -3526,6 +4355,111 @@ mod tests {
         assert_eq!(res.err().unwrap().kind(), ErrorKind::NotFound);
     }
 
+    fn walk_dir_names(walk: fs::WalkDir, root: &Path) -> Vec<(usize, String)> {
+        walk.into_iter()
+            .map(|entry| {
+                let entry = check!(entry);
+                let rel = entry.path().strip_prefix(root).unwrap();
+                let rel = rel.components().map(|c| c.as_os_str().to_str().unwrap());
+                (entry.depth(), rel.collect::<Vec<_>>().join("/"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn walk_dir_sorted() {
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("root");
+        check!(fs::create_dir_all(root.join("b/d")));
+        check!(fs::create_dir_all(root.join("a")));
+        check!(File::create(root.join("c")));
+        check!(File::create(root.join("a/x")));
+        check!(File::create(root.join("b/d/y")));
+
+        let all = walk_dir_names(fs::walk_dir(&root).sort_by_file_name(), &root);
+        let expected =
+            [(0, ""), (1, "a"), (2, "a/x"), (1, "b"), (2, "b/d"), (3, "b/d/y"), (1, "c")];
+        let expected: Vec<_> = expected.iter().map(|&(d, p)| (d, p.to_string())).collect();
+        assert_eq!(all, expected);
+
+        let middle = walk_dir_names(
+            fs::walk_dir(&root).min_depth(1).max_depth(2).sort_by_file_name(),
+            &root,
+        );
+        let expected = [(1, "a"), (2, "a/x"), (1, "b"), (2, "b/d"), (1, "c")];
+        let expected: Vec<_> = expected.iter().map(|&(d, p)| (d, p.to_string())).collect();
+        assert_eq!(middle, expected);
+
+        let entry = check!(fs::walk_dir(root.join("c")).into_iter().next().unwrap());
+        assert_eq!(entry.file_name(), "c");
+        assert!(entry.file_type().is_file());
+        assert!(check!(entry.metadata()).is_file());
+    }
+
+    #[test]
+    fn walk_dir_skip_current_dir() {
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("root");
+        check!(fs::create_dir_all(root.join("skipped/inner")));
+        check!(File::create(root.join("skipped/file")));
+        check!(File::create(root.join("kept")));
+
+        let mut walk = fs::walk_dir(&root).sort_by_file_name().into_iter();
+        let mut seen = Vec::new();
+        while let Some(entry) = walk.next() {
+            let entry = check!(entry);
+            if entry.file_name() == "skipped" {
+                walk.skip_current_dir();
+            }
+            seen.push(entry.file_name().to_str().unwrap().to_string());
+        }
+        assert_eq!(seen, ["root", "kept", "skipped"]);
+    }
+
+    #[test]
+    fn walk_dir_skip_current_dir_max_depth() {
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("root");
+        check!(fs::create_dir_all(root.join("a")));
+        check!(File::create(root.join("a/x")));
+        check!(File::create(root.join("b")));
+
+        // `a` is not descended into, so skipping it must not skip `b`.
+        let mut walk = fs::walk_dir(&root).max_depth(1).sort_by_file_name().into_iter();
+        let mut seen = Vec::new();
+        while let Some(entry) = walk.next() {
+            let entry = check!(entry);
+            if entry.file_name() == "a" {
+                walk.skip_current_dir();
+            }
+            seen.push(entry.file_name().to_str().unwrap().to_string());
+        }
+        assert_eq!(seen, ["root", "a", "b"]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn walk_dir_symlink_loop() {
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("root");
+        check!(fs::create_dir_all(root.join("sub")));
+        check!(symlink_dir(&root, root.join("sub/link")));
+
+        let entries: Vec<_> = fs::walk_dir(&root).into_iter().collect();
+        assert_eq!(entries.len(), 3);
+        assert!(entries.iter().all(|e| e.is_ok()));
+        let link = entries.iter().map(|e| e.as_ref().unwrap()).find(|e| e.file_name() == "link");
+        let link = link.unwrap();
+        assert!(link.path_is_symlink() && link.file_type().is_symlink());
+
+        let entries: Vec<_> = fs::walk_dir(&root).follow_links(true).into_iter().collect();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries.iter().filter(|e| e.is_err()).count(), 1);
+        let link = entries.iter().filter_map(|e| e.as_ref().ok()).find(|e| e.file_name() == "link");
+        let link = link.unwrap();
+        assert!(link.path_is_symlink() && link.file_type().is_dir());
+    }
+
     #[test]
     fn create_dir_all_with_junctions() {
         let tmpdir = tmpdir();
@@ -795,6 +795,60 @@ impl fmt::Debug for StderrLock<'_> {
     }
 }
 
+/// Trait to determine if a descriptor/handle refers to a terminal/tty.
+///
+/// This is typically used by command line programs to decide whether to
+/// emit colors or other escape sequences, or whether to prompt for input.
+///
+/// # Platform-specific behavior
+///
+/// On Unix this uses `isatty`, and on Windows it checks whether the handle
+/// is attached to a console with `GetConsoleMode`, so terminal emulators
+/// which talk to the program over a pipe (such as mintty) are not detected.
+/// On WASI a character device that can be neither seeked nor told is
+/// considered a terminal. On all other platforms this always returns
+/// `false`. Note that, this [may change in the future][changes].
+///
+/// [changes]: ../io/index.html#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(is_terminal)]
+/// use std::io::{self, IsTerminal, Write};
+///
+/// fn main() -> io::Result<()> {
+///     let stdout = io::stdout();
+///     if stdout.is_terminal() {
+///         writeln!(stdout.lock(), "\x1b[1mhello\x1b[0m")?;
+///     } else {
+///         writeln!(stdout.lock(), "hello")?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "is_terminal", issue = "none")]
+pub trait IsTerminal {
+    /// Returns `true` if the descriptor/handle refers to a terminal/tty.
+    #[unstable(feature = "is_terminal", issue = "none")]
+    fn is_terminal(&self) -> bool;
+}
+
+macro_rules! impl_is_terminal {
+    ($raw:ident => $($t:ty),*) => {$(
+        #[unstable(feature = "is_terminal", issue = "none")]
+        impl IsTerminal for $t {
+            fn is_terminal(&self) -> bool {
+                stdio::$raw::new().map_or(false, |raw| raw.is_terminal())
+            }
+        }
+    )*}
+}
+
+impl_is_terminal!(Stdin => Stdin, StdinLock<'_>);
+impl_is_terminal!(Stdout => Stdout, StdoutLock<'_>);
+impl_is_terminal!(Stderr => Stderr, StderrLock<'_>);
+
 /// Resets the thread-local stderr handle to the specified writer
 ///
 /// This will replace the current thread's stderr handle, returning the old
@@ -230,6 +230,7 @@
 // compiler details that will never be stable
 // NB: the following list is sorted to minimize merge conflicts.
 #![feature(alloc_error_handler)]
+#![feature(alloc_c_string)]
 #![feature(alloc_layout_extra)]
 #![feature(allocator_api)]
 #![feature(allocator_internals)]
@@ -239,6 +240,7 @@
 #![feature(array_error_internals)]
 #![feature(asm)]
 #![feature(associated_type_bounds)]
+#![feature(async_stream)]
 #![feature(atomic_mut_ptr)]
 #![feature(box_syntax)]
 #![feature(c_variadic)]
@@ -250,6 +252,8 @@
 #![feature(char_internals)]
 #![feature(clamp)]
 #![feature(concat_idents)]
+#![feature(c_str_in_core)]
+#![feature(const_cstr_methods)]
 #![feature(const_cstr_unchecked)]
 #![feature(const_raw_ptr_deref)]
 #![feature(container_error_extra)]
@@ -262,6 +266,9 @@
 #![feature(doc_masked)]
 #![feature(dropck_eyepatch)]
 #![feature(duration_constants)]
+#![feature(error_generic_member_access)]
+#![feature(error_in_core)]
+#![feature(error_iter)]
 #![feature(exact_size_is_empty)]
 #![feature(exhaustive_patterns)]
 #![feature(extend_one)]
@@ -278,6 +285,7 @@
 #![feature(int_error_matching)]
 #![feature(into_future)]
 #![feature(integer_atomics)]
+#![feature(ip_in_core)]
 #![feature(lang_items)]
 #![feature(libc)]
 #![feature(link_args)]
@@ -312,6 +320,7 @@
 #![feature(str_internals)]
 #![feature(test)]
 #![feature(thread_local)]
+#![feature(thread_local_const_init)]
 #![feature(toowned_clone_into)]
 #![feature(total_cmp)]
 #![feature(trace_macros)]
@@ -437,6 +446,8 @@ pub use core::ptr;
 pub use core::raw;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::result;
+#[unstable(feature = "async_stream", issue = "none")]
+pub use core::stream;
 #[stable(feature = "i128", since = "1.26.0")]
 pub use core::u128;
 #[stable(feature = "rust1", since = "1.0.0")]
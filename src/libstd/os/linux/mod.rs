@@ -4,3 +4,5 @@
 
 pub mod fs;
 pub mod raw;
+#[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+pub mod thread;
@@ -0,0 +1,314 @@
+//! Linux-specific extensions to primitives in the `std::thread` module.
+//!
+//! These control which CPUs a thread may run on, how the kernel schedules it
+//! and the name the kernel reports for it (for example in `/proc` or `top`).
+//! They are not available with uClibc, which lacks the affinity functions.
+
+#![unstable(feature = "linux_thread_ext", issue = "none")]
+
+use crate::ffi::CString;
+use crate::fmt;
+use crate::io;
+use crate::iter::FromIterator;
+use crate::sys::thread as imp;
+use crate::sys_common::AsInner;
+use crate::thread::{Builder, JoinHandle};
+
+/// A set of CPUs, used to restrict the CPUs a thread is allowed to run on.
+///
+/// CPUs are identified by their index, as used by the kernel (for example
+/// in `/proc/cpuinfo`). Indices must be below [`CpuSet::CAPACITY`].
+///
+/// [`CpuSet::CAPACITY`]: #associatedconstant.CAPACITY
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_thread_ext)]
+/// use std::os::linux::thread::{self, CpuSet};
+///
+/// let cpus: CpuSet = [0, 1].iter().copied().collect();
+/// thread::set_affinity(&cpus).unwrap();
+/// assert_eq!(thread::affinity().unwrap(), cpus);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct CpuSet {
+    inner: imp::linux::CpuSet,
+}
+
+impl CpuSet {
+    /// The number of CPUs a `CpuSet` can hold.
+    pub const CAPACITY: usize = imp::linux::CpuSet::CAPACITY;
+
+    /// Creates an empty set.
+    pub fn new() -> CpuSet {
+        CpuSet { inner: imp::linux::CpuSet::new() }
+    }
+
+    /// Adds the CPU with index `cpu` to the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cpu` is not below [`CpuSet::CAPACITY`].
+    ///
+    /// [`CpuSet::CAPACITY`]: #associatedconstant.CAPACITY
+    pub fn set(&mut self, cpu: usize) {
+        self.inner.set(cpu)
+    }
+
+    /// Removes the CPU with index `cpu` from the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cpu` is not below [`CpuSet::CAPACITY`].
+    ///
+    /// [`CpuSet::CAPACITY`]: #associatedconstant.CAPACITY
+    pub fn clear(&mut self, cpu: usize) {
+        self.inner.clear(cpu)
+    }
+
+    /// Returns `true` if the CPU with index `cpu` is in the set.
+    pub fn contains(&self, cpu: usize) -> bool {
+        self.inner.contains(cpu)
+    }
+
+    /// Returns the number of CPUs in the set.
+    pub fn count(&self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl Default for CpuSet {
+    fn default() -> CpuSet {
+        CpuSet::new()
+    }
+}
+
+impl fmt::Debug for CpuSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl FromIterator<usize> for CpuSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> CpuSet {
+        let mut cpus = CpuSet::new();
+        for cpu in iter {
+            cpus.set(cpu);
+        }
+        cpus
+    }
+}
+
+/// A kernel scheduling policy, see `sched(7)`.
+///
+/// The real-time policies [`Fifo`] and [`RoundRobin`] take a static priority
+/// between 1 and 99 and usually need the `CAP_SYS_NICE` capability. The
+/// other policies require a priority of 0.
+///
+/// [`Fifo`]: #variant.Fifo
+/// [`RoundRobin`]: #variant.RoundRobin
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchedPolicy {
+    /// The default time-sharing policy (`SCHED_OTHER`).
+    Other,
+    /// For CPU-bound, non-interactive work (`SCHED_BATCH`).
+    Batch,
+    /// For work that should only run when the system is otherwise idle
+    /// (`SCHED_IDLE`).
+    Idle,
+    /// First-in, first-out real-time scheduling (`SCHED_FIFO`).
+    Fifo,
+    /// Round-robin real-time scheduling (`SCHED_RR`).
+    RoundRobin,
+}
+
+impl SchedPolicy {
+    fn as_raw(self) -> libc::c_int {
+        match self {
+            SchedPolicy::Other => libc::SCHED_OTHER,
+            SchedPolicy::Batch => libc::SCHED_BATCH,
+            SchedPolicy::Idle => libc::SCHED_IDLE,
+            SchedPolicy::Fifo => libc::SCHED_FIFO,
+            SchedPolicy::RoundRobin => libc::SCHED_RR,
+        }
+    }
+}
+
+fn thread_name(name: &str) -> io::Result<CString> {
+    // The kernel limits thread names to 16 bytes, including the nul byte.
+    if name.len() > 15 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "thread name may not be longer than 15 bytes",
+        ));
+    }
+    CString::new(name).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "thread name may not contain interior null bytes",
+        )
+    })
+}
+
+/// Restricts the current thread to the CPUs in `cpus`.
+///
+/// This corresponds to the `pthread_setaffinity_np` function.
+pub fn set_affinity(cpus: &CpuSet) -> io::Result<()> {
+    imp::linux::set_affinity(unsafe { libc::pthread_self() }, &cpus.inner)
+}
+
+/// Returns the set of CPUs the current thread may run on.
+///
+/// This corresponds to the `pthread_getaffinity_np` function.
+pub fn affinity() -> io::Result<CpuSet> {
+    imp::linux::affinity(unsafe { libc::pthread_self() }).map(|inner| CpuSet { inner })
+}
+
+/// Sets the scheduling policy and static priority of the current thread.
+///
+/// This corresponds to the `pthread_setschedparam` function.
+pub fn set_sched_policy(policy: SchedPolicy, priority: i32) -> io::Result<()> {
+    imp::linux::set_sched_policy(unsafe { libc::pthread_self() }, policy.as_raw(), priority)
+}
+
+/// Sets the nice value of the current thread.
+///
+/// On Linux the nice value applies to individual threads rather than to the
+/// whole process. Lowering it usually needs the `CAP_SYS_NICE` capability.
+pub fn set_nice(nice: i32) -> io::Result<()> {
+    imp::linux::set_nice(nice)
+}
+
+/// Sets the name the kernel reports for the current thread.
+///
+/// This does not change the name returned by [`Thread::name`]. Names are
+/// limited to 15 bytes.
+///
+/// [`Thread::name`]: ../../../../std/thread/struct.Thread.html#method.name
+pub fn set_name(name: &str) -> io::Result<()> {
+    imp::linux::set_name(unsafe { libc::pthread_self() }, &thread_name(name)?)
+}
+
+/// Linux-specific extensions to [`thread::Builder`].
+///
+/// [`thread::Builder`]: ../../../../std/thread/struct.Builder.html
+pub trait BuilderExt {
+    /// Restricts the thread-to-be to the CPUs in `cpus`.
+    ///
+    /// The affinity is applied before the thread starts running, so it
+    /// never runs on any other CPU. Spawning fails if it cannot be applied.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_thread_ext)]
+    /// use std::os::linux::thread::{BuilderExt, CpuSet};
+    /// use std::thread;
+    ///
+    /// let cpus: CpuSet = [2].iter().copied().collect();
+    /// let handle = thread::Builder::new().affinity(cpus).spawn(|| {
+    ///     // runs on CPU 2 only
+    /// }).unwrap();
+    /// handle.join().unwrap();
+    /// ```
+    fn affinity(self, cpus: CpuSet) -> Builder;
+}
+
+impl BuilderExt for Builder {
+    fn affinity(self, cpus: CpuSet) -> Builder {
+        self.set_affinity(cpus.inner)
+    }
+}
+
+/// Linux-specific extensions to [`thread::JoinHandle`].
+///
+/// [`thread::JoinHandle`]: ../../../../std/thread/struct.JoinHandle.html
+pub trait JoinHandleExt {
+    /// Restricts the thread to the CPUs in `cpus`.
+    fn set_affinity(&self, cpus: &CpuSet) -> io::Result<()>;
+
+    /// Returns the set of CPUs the thread may run on.
+    fn affinity(&self) -> io::Result<CpuSet>;
+
+    /// Sets the scheduling policy and static priority of the thread.
+    fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> io::Result<()>;
+
+    /// Sets the name the kernel reports for the thread.
+    ///
+    /// This does not change the name returned by [`Thread::name`]. Names
+    /// are limited to 15 bytes.
+    ///
+    /// [`Thread::name`]: ../../../../std/thread/struct.Thread.html#method.name
+    fn set_name(&self, name: &str) -> io::Result<()>;
+}
+
+impl<T> JoinHandleExt for JoinHandle<T> {
+    fn set_affinity(&self, cpus: &CpuSet) -> io::Result<()> {
+        imp::linux::set_affinity(self.as_inner().id(), &cpus.inner)
+    }
+
+    fn affinity(&self) -> io::Result<CpuSet> {
+        imp::linux::affinity(self.as_inner().id()).map(|inner| CpuSet { inner })
+    }
+
+    fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> io::Result<()> {
+        imp::linux::set_sched_policy(self.as_inner().id(), policy.as_raw(), priority)
+    }
+
+    fn set_name(&self, name: &str) -> io::Result<()> {
+        imp::linux::set_name(self.as_inner().id(), &thread_name(name)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuilderExt, CpuSet, JoinHandleExt};
+    use crate::thread;
+
+    #[test]
+    fn cpu_set() {
+        let mut cpus: CpuSet = [0, 3].iter().copied().collect();
+        assert!(cpus.contains(0) && cpus.contains(3) && !cpus.contains(1));
+        assert!(!cpus.contains(CpuSet::CAPACITY));
+        assert_eq!(cpus.count(), 2);
+        cpus.clear(3);
+        assert_eq!(cpus.count(), 1);
+        assert_eq!(format!("{:?}", cpus), "{0}");
+        assert_eq!(CpuSet::new(), CpuSet::default());
+    }
+
+    #[test]
+    fn affinity_round_trip() {
+        let current = super::affinity().unwrap();
+        assert!(current.count() > 0);
+        super::set_affinity(&current).unwrap();
+        assert_eq!(super::affinity().unwrap(), current);
+    }
+
+    #[test]
+    fn spawn_pinned() {
+        let current = super::affinity().unwrap();
+        let cpu = (0..CpuSet::CAPACITY).find(|&cpu| current.contains(cpu)).unwrap();
+        let cpus: CpuSet = Some(cpu).into_iter().collect();
+        let handle = thread::Builder::new()
+            .affinity(cpus)
+            .spawn(move || super::affinity().unwrap())
+            .unwrap();
+        assert_eq!(handle.affinity().unwrap(), cpus);
+        assert_eq!(handle.join().unwrap(), cpus);
+    }
+
+    #[test]
+    fn set_name() {
+        // Renaming the thread running the test would leak into later tests.
+        thread::spawn(|| {
+            super::set_name("worker").unwrap();
+            assert!(super::set_name("a name that is too long").is_err());
+            assert!(super::set_name("nul\0").is_err());
+        })
+        .join()
+        .unwrap();
+    }
+}
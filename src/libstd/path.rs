@@ -81,6 +81,7 @@ use crate::ops::{self, Deref};
 use crate::rc::Rc;
 use crate::str::FromStr;
 use crate::sync::Arc;
+use crate::sys;
 
 use crate::ffi::{OsStr, OsString};
 
@@ -258,6 +259,59 @@ pub fn is_separator(c: char) -> bool {
     c.is_ascii() && is_sep_byte(c as u8)
 }
 
+/// Makes the path absolute without accessing the filesystem.
+///
+/// If the path is relative, the current directory is used as the base
+/// directory. All intermediate components will be normalized according to
+/// the platform's rules, but unlike [`canonicalize`] this does not resolve
+/// symbolic links and may succeed even if the path does not exist.
+///
+/// [`canonicalize`]: ../fs/fn.canonicalize.html
+///
+/// # Platform-specific behavior
+///
+/// On Unix, `.` components and repeated separators are removed, but `..`
+/// components are kept, as they may have a different meaning after symbolic
+/// links are resolved. A leading `//` and a trailing `/` are preserved.
+///
+/// On Windows, this calls the `GetFullPathNameW` function, which normalizes
+/// both `.` and `..` components the same way the system does when opening a
+/// file. Verbatim paths (starting with `\\?\`) are returned unchanged.
+///
+/// Note that, this [may change in the future][changes].
+///
+/// [changes]: ../io/index.html#platform-specific-behavior
+///
+/// # Errors
+///
+/// This function will return an error if the path is empty or if the current
+/// directory is needed and can't be retrieved.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(path_absolute)]
+/// use std::path;
+///
+/// fn main() -> std::io::Result<()> {
+///     let absolute = path::absolute("config/app.toml")?;
+///     assert!(absolute.is_absolute());
+///     assert!(absolute.ends_with("config/app.toml"));
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "path_absolute", issue = "none")]
+pub fn absolute<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
+    let path = path.as_ref();
+    if path.as_os_str().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "cannot make an empty path absolute",
+        ));
+    }
+    sys::path::absolute(path)
+}
+
 /// The primary separator of path components for the current platform.
 ///
 /// For example, `/` on Unix and `\` on Windows.
@@ -1751,6 +1805,14 @@ pub struct Path {
 #[stable(since = "1.7.0", feature = "strip_prefix")]
 pub struct StripPrefixError(());
 
+/// An error returned from [`Path::normalize_lexically`] if a `..` component
+/// would go above the start of the path.
+///
+/// [`Path::normalize_lexically`]: struct.Path.html#method.normalize_lexically
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "normalize_lexically", issue = "none")]
+pub struct NormalizeError(());
+
 impl Path {
     // The following (private!) function allows construction of a path from a u8
     // slice, which is only safe when it is known to follow the OsStr encoding.
@@ -2411,6 +2473,64 @@ impl Path {
         fs::canonicalize(self)
     }
 
+    /// Normalizes the path by removing `.` components and resolving each
+    /// `..` component against the component before it, without accessing the
+    /// filesystem.
+    ///
+    /// Repeated separators are removed as well. A relative path that
+    /// normalizes to nothing, like `a/..`, becomes the empty path.
+    ///
+    /// This only looks at the path itself, so its result may differ from
+    /// what the system would resolve it to when `..` follows a symbolic
+    /// link. Use [`canonicalize`] to resolve an existing path the same way
+    /// the system does.
+    ///
+    /// [`canonicalize`]: #method.canonicalize
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `..` component would go above the start of the
+    /// path: above the root or prefix for an absolute path, or above the
+    /// first component for a relative one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(normalize_lexically)]
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let path = Path::new("config/./local/../app.toml");
+    /// assert_eq!(path.normalize_lexically(), Ok(PathBuf::from("config/app.toml")));
+    ///
+    /// assert!(Path::new("../app.toml").normalize_lexically().is_err());
+    /// assert!(Path::new("/etc/../../app.toml").normalize_lexically().is_err());
+    /// ```
+    #[unstable(feature = "normalize_lexically", issue = "none")]
+    pub fn normalize_lexically(&self) -> Result<PathBuf, NormalizeError> {
+        let mut normalized = PathBuf::new();
+        // The number of normal components in `normalized`, which are the only
+        // ones that a `..` may remove.
+        let mut depth = 0;
+        for component in self.components() {
+            match component {
+                Component::Prefix(..) | Component::RootDir => normalized.push(component),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    if depth == 0 {
+                        return Err(NormalizeError(()));
+                    }
+                    normalized.pop();
+                    depth -= 1;
+                }
+                Component::Normal(name) => {
+                    normalized.push(name);
+                    depth += 1;
+                }
+            }
+        }
+        Ok(normalized)
+    }
+
     /// Reads a symbolic link, returning the file that the link points to.
     ///
     /// This is an alias to [`fs::read_link`].
@@ -2819,6 +2939,16 @@ impl Error for StripPrefixError {
     }
 }
 
+#[unstable(feature = "normalize_lexically", issue = "none")]
+impl fmt::Display for NormalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("parent reference `..` points outside of base directory")
+    }
+}
+
+#[unstable(feature = "normalize_lexically", issue = "none")]
+impl Error for NormalizeError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4215,4 +4345,51 @@ mod tests {
         assert_eq!(&*rc2, path);
         assert_eq!(&*arc2, path);
     }
+
+    #[test]
+    fn test_normalize_lexically() {
+        fn check(path: &str, expected: Option<&str>) {
+            let actual = Path::new(path).normalize_lexically();
+            assert_eq!(actual.ok(), expected.map(PathBuf::from), "normalizing {:?}", path);
+        }
+
+        check("", Some(""));
+        check(".", Some(""));
+        check("a/./b//c", Some("a/b/c"));
+        check("a/b/../c/", Some("a/c"));
+        check("./a/..", Some(""));
+        check("a/../..", None);
+        check("../a", None);
+        check("/a/../b", Some("/b"));
+        check("/..", None);
+        check("/a/../../b", None);
+
+        if cfg!(windows) {
+            check(r"C:\a\..\b", Some(r"C:\b"));
+            check(r"C:a\..", Some("C:"));
+            check(r"C:\..", None);
+            check(r"\\server\share\a\..", Some(r"\\server\share\"));
+            check(r"\\server\share\..", None);
+        }
+    }
+
+    #[test]
+    fn test_absolute() {
+        assert!(absolute("").is_err());
+
+        let cwd = crate::env::current_dir().unwrap();
+        assert_eq!(absolute("a/./b").unwrap(), cwd.join("a").join("b"));
+        assert_eq!(absolute("./a").unwrap(), cwd.join("a"));
+
+        if cfg!(unix) {
+            assert_eq!(absolute("/a/./b/../c").unwrap(), Path::new("/a/b/../c"));
+            assert_eq!(absolute("/a//b/").unwrap().as_os_str(), "/a/b/");
+            assert_eq!(absolute("//a/b").unwrap().as_os_str(), "//a/b");
+            assert_eq!(absolute("///a/b").unwrap().as_os_str(), "/a/b");
+        }
+        if cfg!(windows) {
+            assert_eq!(absolute(r"C:\a\.\b\..\c").unwrap(), Path::new(r"C:\a\c"));
+            assert_eq!(absolute(r"\\?\C:\a\..").unwrap(), Path::new(r"\\?\C:\a\.."));
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::ffi::CStr;
+use crate::ffi::cstr_from_ptr;
 use crate::str;
 
 use libc::c_int;
@@ -18,7 +18,7 @@ pub fn errno() -> i32 {
 pub fn error_string(errno: i32) -> String {
     // cloudlibc's strerror() is guaranteed to be thread-safe. There is
     // thus no need to use strerror_r().
-    str::from_utf8(unsafe { CStr::from_ptr(libc::strerror(errno)) }.to_bytes()).unwrap().to_owned()
+    str::from_utf8(unsafe { cstr_from_ptr(libc::strerror(errno)) }.to_bytes()).unwrap().to_owned()
 }
 
 pub fn exit(code: i32) -> ! {
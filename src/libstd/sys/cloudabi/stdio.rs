@@ -9,6 +9,10 @@ impl Stdin {
     pub fn new() -> io::Result<Stdin> {
         Ok(Stdin(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Read for Stdin {
@@ -21,6 +25,10 @@ impl Stdout {
     pub fn new() -> io::Result<Stdout> {
         Ok(Stdout(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stdout {
@@ -40,6 +48,10 @@ impl Stderr {
     pub fn new() -> io::Result<Stderr> {
         Ok(Stderr(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stderr {
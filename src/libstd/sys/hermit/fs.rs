@@ -48,6 +48,11 @@ pub struct FileType(Void);
 #[derive(Debug)]
 pub struct DirBuilder {}
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DirId(Void);
+
+pub struct DirHandle(Void);
+
 impl FileAttr {
     pub fn size(&self) -> u64 {
         match self.0 {}
@@ -175,6 +180,10 @@ impl DirEntry {
         match self.0 {}
     }
 
+    pub fn open_dir(&self, _follow_links: bool) -> io::Result<(ReadDir, DirId)> {
+        match self.0 {}
+    }
+
     pub fn metadata(&self) -> io::Result<FileAttr> {
         match self.0 {}
     }
@@ -289,6 +298,30 @@ impl File {
         self.fsync()
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
+
     pub fn truncate(&self, _size: u64) -> io::Result<()> {
         Err(Error::from_raw_os_error(22))
     }
@@ -350,10 +383,42 @@ impl DirBuilder {
     }
 }
 
+impl DirHandle {
+    pub fn open(_p: &Path) -> io::Result<DirHandle> {
+        unsupported()
+    }
+
+    pub fn open_file_at(&self, _p: &Path, _opts: &OpenOptions) -> io::Result<File> {
+        match self.0 {}
+    }
+
+    pub fn create_dir_at(&self, _p: &Path) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn remove_at(&self, _p: &Path) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        match self.0 {}
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
 pub fn readdir(_p: &Path) -> io::Result<ReadDir> {
     unsupported()
 }
 
+pub fn open_dir(_p: &Path) -> io::Result<(ReadDir, DirId)> {
+    unsupported()
+}
+
 pub fn unlink(path: &Path) -> io::Result<()> {
     let name = cstr(path)?;
     let _ = unsafe { cvt(abi::unlink(name.as_ptr()))? };
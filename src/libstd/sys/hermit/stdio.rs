@@ -11,6 +11,10 @@ impl Stdin {
         Ok(Stdin)
     }
 
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
+
     pub fn read(&self, data: &mut [u8]) -> io::Result<usize> {
         self.read_vectored(&mut [IoSliceMut::new(data)])
     }
@@ -32,6 +36,10 @@ impl Stdout {
         Ok(Stdout)
     }
 
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
+
     pub fn write(&self, data: &[u8]) -> io::Result<usize> {
         let len;
 
@@ -71,6 +79,10 @@ impl Stderr {
         Ok(Stderr)
     }
 
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
+
     pub fn write(&self, data: &[u8]) -> io::Result<usize> {
         let len;
 
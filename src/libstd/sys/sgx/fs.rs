@@ -24,6 +24,11 @@ pub struct FileType(Void);
 #[derive(Debug)]
 pub struct DirBuilder {}
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DirId(Void);
+
+pub struct DirHandle(Void);
+
 impl FileAttr {
     pub fn size(&self) -> u64 {
         match self.0 {}
@@ -151,6 +156,10 @@ impl DirEntry {
         match self.0 {}
     }
 
+    pub fn open_dir(&self, _follow_links: bool) -> io::Result<(ReadDir, DirId)> {
+        match self.0 {}
+    }
+
     pub fn metadata(&self) -> io::Result<FileAttr> {
         match self.0 {}
     }
@@ -190,6 +199,30 @@ impl File {
         match self.0 {}
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        match self.0 {}
+    }
+
     pub fn truncate(&self, _size: u64) -> io::Result<()> {
         match self.0 {}
     }
@@ -255,10 +288,42 @@ impl fmt::Debug for File {
     }
 }
 
+impl DirHandle {
+    pub fn open(_p: &Path) -> io::Result<DirHandle> {
+        unsupported()
+    }
+
+    pub fn open_file_at(&self, _p: &Path, _opts: &OpenOptions) -> io::Result<File> {
+        match self.0 {}
+    }
+
+    pub fn create_dir_at(&self, _p: &Path) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn remove_at(&self, _p: &Path) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        match self.0 {}
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
 pub fn readdir(_p: &Path) -> io::Result<ReadDir> {
     unsupported()
 }
 
+pub fn open_dir(_p: &Path) -> io::Result<(ReadDir, DirId)> {
+    unsupported()
+}
+
 pub fn unlink(_p: &Path) -> io::Result<()> {
     unsupported()
 }
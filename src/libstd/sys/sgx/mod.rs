@@ -4,7 +4,6 @@
 //! OS level functionality for Fortanix SGX.
 
 use crate::io::ErrorKind;
-use crate::os::raw::c_char;
 use crate::sync::atomic::{AtomicBool, Ordering};
 
 pub mod abi;
@@ -115,15 +114,6 @@ pub fn decode_error_kind(code: i32) -> ErrorKind {
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub enum Void {}
 
-pub unsafe fn strlen(mut s: *const c_char) -> usize {
-    let mut n = 0;
-    while *s != 0 {
-        n += 1;
-        s = s.offset(1);
-    }
-    return n;
-}
-
 pub fn abort_internal() -> ! {
     abi::usercalls::exit(true)
 }
@@ -1,6 +1,8 @@
 use crate::ffi::OsStr;
 use crate::path::Prefix;
 
+pub(crate) use crate::sys_common::path::absolute;
+
 #[inline]
 pub fn is_sep_byte(b: u8) -> bool {
     b == b'/'
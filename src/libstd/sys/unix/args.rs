@@ -74,7 +74,7 @@ impl DoubleEndedIterator for Args {
 ))]
 mod imp {
     use super::Args;
-    use crate::ffi::{CStr, OsString};
+    use crate::ffi::{cstr_from_ptr, OsString};
     use crate::marker::PhantomData;
     use crate::os::unix::prelude::*;
     use crate::ptr;
@@ -139,7 +139,7 @@ mod imp {
             let _guard = LOCK.lock();
             (0..ARGC)
                 .map(|i| {
-                    let cstr = CStr::from_ptr(*ARGV.offset(i) as *const libc::c_char);
+                    let cstr = cstr_from_ptr(*ARGV.offset(i) as *const libc::c_char);
                     OsStringExt::from_vec(cstr.to_bytes().to_vec())
                 })
                 .collect()
@@ -150,7 +150,7 @@ mod imp {
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 mod imp {
     use super::Args;
-    use crate::ffi::CStr;
+    use crate::ffi::cstr_from_ptr;
     use crate::marker::PhantomData;
 
     pub unsafe fn init(_argc: isize, _argv: *const *const u8) {}
@@ -171,7 +171,7 @@ mod imp {
                 (*_NSGetArgc() as isize, *_NSGetArgv() as *const *const libc::c_char);
             (0..argc as isize)
                 .map(|i| {
-                    let bytes = CStr::from_ptr(*argv.offset(i)).to_bytes().to_vec();
+                    let bytes = cstr_from_ptr(*argv.offset(i)).to_bytes().to_vec();
                     OsStringExt::from_vec(bytes)
                 })
                 .collect::<Vec<_>>()
@@ -236,7 +236,7 @@ mod imp {
             for i in 0..cnt {
                 let tmp = objc_msgSend_ul(args, object_at_sel, i as libc::c_ulong);
                 let utf_c_str: *const libc::c_char = mem::transmute(objc_msgSend(tmp, utf8_sel));
-                let bytes = CStr::from_ptr(utf_c_str).to_bytes();
+                let bytes = cstr_from_ptr(utf_c_str).to_bytes();
                 res.push(OsString::from(str::from_utf8(bytes).unwrap()))
             }
         }
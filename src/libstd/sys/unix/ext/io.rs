@@ -68,6 +68,12 @@ impl AsRawFd for fs::File {
         self.as_inner().fd().raw()
     }
 }
+#[unstable(feature = "dir_handle", issue = "none")]
+impl AsRawFd for fs::Dir {
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().fd().raw()
+    }
+}
 #[stable(feature = "from_raw_os", since = "1.1.0")]
 impl FromRawFd for fs::File {
     unsafe fn from_raw_fd(fd: RawFd) -> fs::File {
@@ -30,6 +30,33 @@ use crate::sys::{self, cvt};
 use crate::sys_common::{self, AsInner, FromInner, IntoInner};
 use crate::time::Duration;
 
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod ancillary;
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+pub use self::ancillary::{AncillaryData, AncillaryError, Messages, ScmRights, SocketAncillary};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+pub use self::ancillary::{ScmCredentials, SocketCred};
+
 #[cfg(any(
     target_os = "linux",
     target_os = "android",
@@ -602,6 +629,37 @@ impl UnixStream {
     pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
         self.0.shutdown(how)
     }
+
+    /// Returns the credentials of the process that created the other end of
+    /// this socket, as they were when the connection was established.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peer_credentials_unix_socket)]
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixStream::connect("/tmp/sock")?;
+    ///     let cred = socket.peer_cred()?;
+    ///     println!("connected to uid {} with pid {:?}", cred.uid, cred.pid);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "none")]
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        peer_cred(self)
+    }
 }
 
 #[stable(feature = "unix_socket", since = "1.10.0")]
@@ -1647,6 +1705,53 @@ impl IntoRawFd for UnixDatagram {
     }
 }
 
+/// Credentials of a process, as reported for the peer of a [`UnixStream`].
+///
+/// [`UnixStream`]: struct.UnixStream.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+#[unstable(feature = "peer_credentials_unix_socket", issue = "none")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UCred {
+    /// The effective user ID of the process.
+    pub uid: libc::uid_t,
+    /// The effective group ID of the process.
+    pub gid: libc::gid_t,
+    /// The process ID, on platforms that report it. This is `None` on
+    /// platforms other than Linux and Android.
+    pub pid: Option<libc::pid_t>,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn peer_cred(socket: &UnixStream) -> io::Result<UCred> {
+    let cred: libc::ucred =
+        sys_common::net::getsockopt(&socket.0, libc::SOL_SOCKET, libc::SO_PEERCRED)?;
+    Ok(UCred { uid: cred.uid, gid: cred.gid, pid: Some(cred.pid) })
+}
+
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn peer_cred(socket: &UnixStream) -> io::Result<UCred> {
+    let mut uid = 0;
+    let mut gid = 0;
+    cvt(unsafe { libc::getpeereid(socket.as_raw_fd(), &mut uid, &mut gid) })?;
+    Ok(UCred { uid, gid, pid: None })
+}
+
 #[cfg(all(test, not(target_os = "emscripten")))]
 mod test {
     use crate::io::prelude::*;
@@ -2024,4 +2129,128 @@ mod test {
     fn abstract_namespace_not_allowed() {
         assert!(UnixStream::connect("\0asdf").is_err());
     }
+
+    #[test]
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    fn send_vectored_fds_unix_stream() {
+        let (s1, s2) = or_panic!(UnixStream::pair());
+        let (passed, mut peer) = or_panic!(UnixStream::pair());
+
+        let mut ancillary_buffer = [0; 128];
+        let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+        assert!(ancillary.add_fds(&[passed.as_raw_fd()]));
+        let bufs = [IoSlice::new(b"fd")];
+        assert_eq!(or_panic!(s1.send_vectored_with_ancillary(&bufs, &mut ancillary)), 2);
+        drop(passed);
+
+        let mut buf = [0; 8];
+        let mut ancillary_buffer = [0; 128];
+        let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+        let mut bufs = [IoSliceMut::new(&mut buf)];
+        assert_eq!(or_panic!(s2.recv_vectored_with_ancillary(&mut bufs, &mut ancillary)), 2);
+        assert_eq!(&buf[..2], b"fd");
+        assert!(!ancillary.truncated());
+
+        let mut messages = ancillary.messages();
+        let fds: Vec<RawFd> = match messages.next() {
+            Some(Ok(AncillaryData::ScmRights(fds))) => fds.collect(),
+            _ => panic!("expected an SCM_RIGHTS message"),
+        };
+        assert!(messages.next().is_none());
+        assert_eq!(fds.len(), 1);
+
+        let mut received = unsafe { UnixStream::from_raw_fd(fds[0]) };
+        or_panic!(received.write_all(b"hello"));
+        let mut buf = [0; 5];
+        or_panic!(peer.read_exact(&mut buf));
+        assert_eq!(&buf, b"hello");
+    }
+
+    #[test]
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    fn socket_ancillary_capacity() {
+        let mut ancillary_buffer = [0; 128];
+        let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+        assert!(ancillary.is_empty());
+        let fds = vec![0; ancillary.capacity()];
+        assert!(!ancillary.add_fds(&fds));
+        assert!(ancillary.is_empty());
+        assert!(ancillary.add_fds(&[0, 1]));
+        assert!(!ancillary.is_empty());
+        assert_eq!(ancillary.messages().count(), 1);
+        ancillary.clear();
+        assert_eq!(ancillary.messages().count(), 0);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    fn send_vectored_creds_unix_datagram() {
+        let (s1, s2) = or_panic!(UnixDatagram::pair());
+        or_panic!(s2.set_passcred(true));
+        assert!(or_panic!(s2.passcred()));
+
+        let cred = SocketCred::current();
+        let mut ancillary_buffer = [0; 128];
+        let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+        assert!(ancillary.add_creds(&[cred]));
+        or_panic!(s1.send_vectored_with_ancillary(&[IoSlice::new(b"creds")], &mut ancillary));
+
+        let mut buf = [0; 8];
+        let mut ancillary_buffer = [0; 128];
+        let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+        let mut bufs = [IoSliceMut::new(&mut buf)];
+        assert_eq!(or_panic!(s2.recv_vectored_with_ancillary(&mut bufs, &mut ancillary)), 5);
+
+        let mut messages = ancillary.messages();
+        match messages.next() {
+            Some(Ok(AncillaryData::ScmCredentials(mut creds))) => {
+                let received = creds.next().unwrap();
+                assert_eq!(received.pid(), cred.pid());
+                assert_eq!(received.uid(), cred.uid());
+                assert_eq!(received.gid(), cred.gid());
+                assert!(creds.next().is_none());
+            }
+            _ => panic!("expected an SCM_CREDENTIALS message"),
+        }
+        assert!(messages.next().is_none());
+    }
+
+    #[test]
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    fn peer_cred() {
+        let (s1, _s2) = or_panic!(UnixStream::pair());
+        let cred = or_panic!(s1.peer_cred());
+        assert_eq!(cred.uid, unsafe { libc::geteuid() });
+        assert_eq!(cred.gid, unsafe { libc::getegid() });
+        if cfg!(any(target_os = "android", target_os = "linux")) {
+            assert_eq!(cred.pid, Some(crate::process::id() as libc::pid_t));
+        }
+    }
 }
@@ -0,0 +1,658 @@
+//! Ancillary data, also known as control messages, on Unix domain sockets.
+
+use super::{UnixDatagram, UnixStream, MSG_NOSIGNAL};
+use crate::convert::TryFrom;
+use crate::fmt;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::marker::PhantomData;
+use crate::mem;
+use crate::os::unix::io::RawFd;
+use crate::ptr;
+use crate::slice;
+use crate::sys::cvt;
+use crate::sys::net::Socket;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::sys_common;
+use crate::sys_common::AsInner;
+
+impl UnixStream {
+    /// Receives data and ancillary data from the socket.
+    ///
+    /// On success, returns the number of bytes read. Any file descriptors
+    /// received are owned by the caller, who is responsible for closing them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::io::IoSliceMut;
+    /// use std::os::unix::net::{AncillaryData, SocketAncillary, UnixStream};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixStream::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 128];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     let size = socket
+    ///         .recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary)?;
+    ///     println!("received {} bytes", size);
+    ///     for message in ancillary.messages() {
+    ///         if let Ok(AncillaryData::ScmRights(fds)) = message {
+    ///             for fd in fds {
+    ///                 println!("received file descriptor {}", fd);
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn recv_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        recv_vectored_with_ancillary(&self.0, bufs, ancillary)
+    }
+
+    /// Sends data and ancillary data on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::io::IoSlice;
+    /// use std::os::unix::io::AsRawFd;
+    /// use std::os::unix::net::{SocketAncillary, UnixListener, UnixStream};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixListener::bind("/tmp/service")?;
+    ///     let socket = UnixStream::connect("/tmp/handoff")?;
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_fds(&[listener.as_raw_fd()]);
+    ///     socket.send_vectored_with_ancillary(&[IoSlice::new(b"listener")], &mut ancillary)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn send_vectored_with_ancillary(
+        &self,
+        bufs: &[IoSlice<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        send_vectored_with_ancillary(&self.0, bufs, ancillary)
+    }
+
+    /// Enables or disables receiving the credentials of the sending process
+    /// as `SCM_CREDENTIALS` ancillary data.
+    ///
+    /// This sets the `SO_PASSCRED` option on the socket.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
+        let passcred = passcred as libc::c_int;
+        sys_common::net::setsockopt(&self.0, libc::SOL_SOCKET, libc::SO_PASSCRED, passcred)
+    }
+
+    /// Returns whether the credentials of the sending process are received
+    /// as `SCM_CREDENTIALS` ancillary data.
+    ///
+    /// See [`set_passcred`] for more information.
+    ///
+    /// [`set_passcred`]: #method.set_passcred
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn passcred(&self) -> io::Result<bool> {
+        let passcred: libc::c_int =
+            sys_common::net::getsockopt(&self.0, libc::SOL_SOCKET, libc::SO_PASSCRED)?;
+        Ok(passcred != 0)
+    }
+}
+
+impl UnixDatagram {
+    /// Receives data and ancillary data from the socket.
+    ///
+    /// On success, returns the number of bytes read. Any file descriptors
+    /// received are owned by the caller, who is responsible for closing them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::io::IoSliceMut;
+    /// use std::os::unix::net::{AncillaryData, SocketAncillary, UnixDatagram};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixDatagram::bind("/tmp/sock")?;
+    ///     let mut buf = [0; 128];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     let size = socket
+    ///         .recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary)?;
+    ///     println!("received {} bytes", size);
+    ///     for message in ancillary.messages() {
+    ///         if let Ok(AncillaryData::ScmRights(fds)) = message {
+    ///             for fd in fds {
+    ///                 println!("received file descriptor {}", fd);
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn recv_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        recv_vectored_with_ancillary(&self.0, bufs, ancillary)
+    }
+
+    /// Sends data and ancillary data on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::io::IoSlice;
+    /// use std::os::unix::io::AsRawFd;
+    /// use std::os::unix::net::{SocketAncillary, UnixDatagram, UnixListener};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixListener::bind("/tmp/service")?;
+    ///     let socket = UnixDatagram::unbound()?;
+    ///     socket.connect("/tmp/handoff")?;
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_fds(&[listener.as_raw_fd()]);
+    ///     socket.send_vectored_with_ancillary(&[IoSlice::new(b"listener")], &mut ancillary)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn send_vectored_with_ancillary(
+        &self,
+        bufs: &[IoSlice<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        send_vectored_with_ancillary(&self.0, bufs, ancillary)
+    }
+
+    /// Enables or disables receiving the credentials of the sending process
+    /// as `SCM_CREDENTIALS` ancillary data.
+    ///
+    /// This sets the `SO_PASSCRED` option on the socket.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
+        let passcred = passcred as libc::c_int;
+        sys_common::net::setsockopt(&self.0, libc::SOL_SOCKET, libc::SO_PASSCRED, passcred)
+    }
+
+    /// Returns whether the credentials of the sending process are received
+    /// as `SCM_CREDENTIALS` ancillary data.
+    ///
+    /// See [`set_passcred`] for more information.
+    ///
+    /// [`set_passcred`]: #method.set_passcred
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn passcred(&self) -> io::Result<bool> {
+        let passcred: libc::c_int =
+            sys_common::net::getsockopt(&self.0, libc::SOL_SOCKET, libc::SO_PASSCRED)?;
+        Ok(passcred != 0)
+    }
+}
+
+// Flags for `recvmsg`, making received file descriptors close-on-exec where
+// the platform supports it.
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+const RECVMSG_FLAGS: libc::c_int = libc::MSG_CMSG_CLOEXEC;
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+const RECVMSG_FLAGS: libc::c_int = 0;
+
+fn recv_vectored_with_ancillary(
+    socket: &Socket,
+    bufs: &mut [IoSliceMut<'_>],
+    ancillary: &mut SocketAncillary<'_>,
+) -> io::Result<usize> {
+    unsafe {
+        let mut msg: libc::msghdr = mem::zeroed();
+        msg.msg_iov = bufs.as_mut_ptr().cast();
+        msg.msg_iovlen = bufs.len() as _;
+        // macOS rejects a non-null control pointer with a zero length.
+        if !ancillary.buffer.is_empty() {
+            msg.msg_control = ancillary.buffer.as_mut_ptr().cast();
+            msg.msg_controllen = ancillary.buffer.len() as _;
+        }
+
+        let count = cvt(libc::recvmsg(*socket.as_inner(), &mut msg, RECVMSG_FLAGS))?;
+
+        ancillary.length = msg.msg_controllen as usize;
+        ancillary.truncated = msg.msg_flags & libc::MSG_CTRUNC == libc::MSG_CTRUNC;
+        Ok(count as usize)
+    }
+}
+
+fn send_vectored_with_ancillary(
+    socket: &Socket,
+    bufs: &[IoSlice<'_>],
+    ancillary: &mut SocketAncillary<'_>,
+) -> io::Result<usize> {
+    unsafe {
+        let mut msg: libc::msghdr = mem::zeroed();
+        msg.msg_iov = bufs.as_ptr() as *mut _;
+        msg.msg_iovlen = bufs.len() as _;
+        if ancillary.length > 0 {
+            msg.msg_control = ancillary.buffer.as_mut_ptr().cast();
+            msg.msg_controllen = ancillary.length as _;
+        }
+
+        ancillary.truncated = false;
+        let count = cvt(libc::sendmsg(*socket.as_inner(), &msg, MSG_NOSIGNAL))?;
+        Ok(count as usize)
+    }
+}
+
+/// A buffer for ancillary data, also known as control messages, sent or
+/// received along with the data on a Unix domain socket.
+///
+/// The buffer is borrowed from the caller, so its size determines how much
+/// ancillary data can be sent or received at once. Space for a message is
+/// given by the `CMSG_SPACE` macro of the platform; 128 bytes is enough for
+/// a dozen file descriptors on all supported platforms.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket_ancillary_data)]
+/// use std::io::IoSlice;
+/// use std::os::unix::io::AsRawFd;
+/// use std::os::unix::net::{SocketAncillary, UnixStream};
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = UnixStream::connect("/tmp/sock")?;
+///     let mut ancillary_buffer = [0; 128];
+///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+///     assert!(ancillary.add_fds(&[socket.as_raw_fd()]));
+///     socket.send_vectored_with_ancillary(&[IoSlice::new(b"fd")], &mut ancillary)?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+#[derive(Debug)]
+pub struct SocketAncillary<'a> {
+    buffer: &'a mut [u8],
+    length: usize,
+    truncated: bool,
+}
+
+impl<'a> SocketAncillary<'a> {
+    /// Creates an empty ancillary data buffer backed by `buffer`.
+    ///
+    /// Control messages need to be aligned, so a few bytes at the start of
+    /// `buffer` may be left unused.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        let offset = buffer.as_ptr().align_offset(mem::align_of::<libc::cmsghdr>());
+        let offset = offset.min(buffer.len());
+        SocketAncillary { buffer: &mut buffer[offset..], length: 0, truncated: false }
+    }
+
+    /// Returns the number of bytes available for ancillary data.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if there is no ancillary data in the buffer.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of bytes of ancillary data in the buffer.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns an iterator over the control messages in the buffer.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn messages(&self) -> Messages<'_> {
+        Messages { buffer: &self.buffer[..self.length], current: None }
+    }
+
+    /// Returns `true` if the ancillary data received by the last receive
+    /// call didn't fit in the buffer and was truncated.
+    ///
+    /// File descriptors that didn't fit have been closed by the kernel.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Adds an `SCM_RIGHTS` message transferring the file descriptors `fds`.
+    ///
+    /// The file descriptors stay open in this process; the receiver gets new
+    /// ones referring to the same open files. Returns `false`, leaving the
+    /// buffer unchanged, if there is not enough space for the message.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn add_fds(&mut self, fds: &[RawFd]) -> bool {
+        self.truncated = false;
+        add_to_ancillary_data(self.buffer, &mut self.length, fds, libc::SCM_RIGHTS)
+    }
+
+    /// Adds an `SCM_CREDENTIALS` message with the credentials `creds`.
+    ///
+    /// Unprivileged processes may only send their own credentials. Returns
+    /// `false`, leaving the buffer unchanged, if there is not enough space
+    /// for the message.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn add_creds(&mut self, creds: &[SocketCred]) -> bool {
+        self.truncated = false;
+        add_to_ancillary_data(self.buffer, &mut self.length, creds, libc::SCM_CREDENTIALS)
+    }
+
+    /// Removes all the ancillary data from the buffer.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn clear(&mut self) {
+        self.length = 0;
+        self.truncated = false;
+    }
+}
+
+// Appends a `SOL_SOCKET` control message holding the items of `source` to the
+// first `length` bytes of `buffer`.
+fn add_to_ancillary_data<T>(
+    buffer: &mut [u8],
+    length: &mut usize,
+    source: &[T],
+    cmsg_type: libc::c_int,
+) -> bool {
+    let source_len = match source.len().checked_mul(mem::size_of::<T>()) {
+        Some(len) => match u32::try_from(len) {
+            Ok(len) => len,
+            Err(_) => return false,
+        },
+        None => return false,
+    };
+
+    unsafe {
+        let new_length = match length.checked_add(libc::CMSG_SPACE(source_len) as usize) {
+            Some(new_length) if new_length <= buffer.len() => new_length,
+            _ => return false,
+        };
+        for byte in &mut buffer[*length..new_length] {
+            *byte = 0;
+        }
+        *length = new_length;
+
+        let mut msg: libc::msghdr = mem::zeroed();
+        msg.msg_control = buffer.as_mut_ptr().cast();
+        msg.msg_controllen = *length as _;
+
+        // Find the header of the new, zeroed, message at the end.
+        let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+        let mut last = cmsg;
+        while !cmsg.is_null() {
+            last = cmsg;
+            cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+            // Unlike Linux, some platforms return the same header again when
+            // its length is zero.
+            if cmsg == last {
+                break;
+            }
+        }
+        if last.is_null() {
+            return false;
+        }
+
+        (*last).cmsg_level = libc::SOL_SOCKET;
+        (*last).cmsg_type = cmsg_type;
+        (*last).cmsg_len = libc::CMSG_LEN(source_len) as _;
+        let data = libc::CMSG_DATA(last).cast::<u8>();
+        ptr::copy_nonoverlapping(source.as_ptr().cast::<u8>(), data, source_len as usize);
+    }
+    true
+}
+
+/// An iterator over the control messages in a [`SocketAncillary`].
+///
+/// [`SocketAncillary`]: struct.SocketAncillary.html
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+pub struct Messages<'a> {
+    buffer: &'a [u8],
+    current: Option<&'a libc::cmsghdr>,
+}
+
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+impl<'a> Iterator for Messages<'a> {
+    type Item = Result<AncillaryData<'a>, AncillaryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let mut msg: libc::msghdr = mem::zeroed();
+            msg.msg_control = self.buffer.as_ptr() as *mut _;
+            msg.msg_controllen = self.buffer.len() as _;
+
+            let cmsg = match self.current {
+                Some(current) => libc::CMSG_NXTHDR(&msg, current),
+                None => libc::CMSG_FIRSTHDR(&msg),
+            };
+            let cmsg = cmsg.as_ref()?;
+            // Some platforms return the same header again at the end.
+            if let Some(current) = self.current {
+                if ptr::eq(current, cmsg) {
+                    return None;
+                }
+            }
+            self.current = Some(cmsg);
+            Some(AncillaryData::from_cmsghdr(cmsg))
+        }
+    }
+}
+
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+impl fmt::Debug for Messages<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Messages").field("len", &self.buffer.len()).finish()
+    }
+}
+
+/// A control message read from a [`SocketAncillary`].
+///
+/// [`SocketAncillary`]: struct.SocketAncillary.html
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AncillaryData<'a> {
+    /// File descriptors, sent as an `SCM_RIGHTS` message.
+    ScmRights(ScmRights<'a>),
+    /// Process credentials, sent as an `SCM_CREDENTIALS` message.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    ScmCredentials(ScmCredentials<'a>),
+}
+
+impl<'a> AncillaryData<'a> {
+    unsafe fn from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
+        let data_len = (cmsg.cmsg_len as usize).saturating_sub(libc::CMSG_LEN(0) as usize);
+        let data = slice::from_raw_parts(libc::CMSG_DATA(cmsg).cast::<u8>(), data_len);
+        match (cmsg.cmsg_level, cmsg.cmsg_type) {
+            (libc::SOL_SOCKET, libc::SCM_RIGHTS) => {
+                Ok(AncillaryData::ScmRights(ScmRights(AncillaryDataIter::new(data))))
+            }
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            (libc::SOL_SOCKET, libc::SCM_CREDENTIALS) => {
+                Ok(AncillaryData::ScmCredentials(ScmCredentials(AncillaryDataIter::new(data))))
+            }
+            (cmsg_level, cmsg_type) => Err(AncillaryError::Unknown { cmsg_level, cmsg_type }),
+        }
+    }
+}
+
+/// The error type of [`Messages`] for control messages of unknown types.
+///
+/// [`Messages`]: struct.Messages.html
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AncillaryError {
+    /// A message of a level and type not supported by [`AncillaryData`].
+    ///
+    /// [`AncillaryData`]: enum.AncillaryData.html
+    Unknown {
+        /// The level of the message (`cmsg_level`).
+        cmsg_level: i32,
+        /// The type of the message (`cmsg_type`).
+        cmsg_type: i32,
+    },
+}
+
+// Iterates over the items of type `T` in the data of a control message,
+// which need not be aligned.
+struct AncillaryDataIter<'a, T> {
+    data: &'a [u8],
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T> AncillaryDataIter<'a, T> {
+    fn new(data: &'a [u8]) -> AncillaryDataIter<'a, T> {
+        AncillaryDataIter { data, phantom: PhantomData }
+    }
+}
+
+impl<T> Iterator for AncillaryDataIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.data.len() < mem::size_of::<T>() {
+            return None;
+        }
+        let item = unsafe { ptr::read_unaligned(self.data.as_ptr().cast::<T>()) };
+        self.data = &self.data[mem::size_of::<T>()..];
+        Some(item)
+    }
+}
+
+impl<T> fmt::Debug for AncillaryDataIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AncillaryDataIter").field("len", &self.data.len()).finish()
+    }
+}
+
+/// An iterator over the file descriptors in an `SCM_RIGHTS` message.
+///
+/// The file descriptors are owned by the receiver, who is responsible for
+/// closing them.
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+#[derive(Debug)]
+pub struct ScmRights<'a>(AncillaryDataIter<'a, RawFd>);
+
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+impl Iterator for ScmRights<'_> {
+    type Item = RawFd;
+
+    fn next(&mut self) -> Option<RawFd> {
+        self.0.next()
+    }
+}
+
+/// An iterator over the credentials in an `SCM_CREDENTIALS` message.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+#[derive(Debug)]
+pub struct ScmCredentials<'a>(AncillaryDataIter<'a, libc::ucred>);
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+impl Iterator for ScmCredentials<'_> {
+    type Item = SocketCred;
+
+    fn next(&mut self) -> Option<SocketCred> {
+        self.0.next().map(SocketCred)
+    }
+}
+
+/// The credentials of a process, as sent in an `SCM_CREDENTIALS` message.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+#[derive(Clone, Copy)]
+pub struct SocketCred(libc::ucred);
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl SocketCred {
+    /// Returns the credentials of the current process.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn current() -> SocketCred {
+        unsafe {
+            SocketCred(libc::ucred {
+                pid: libc::getpid(),
+                uid: libc::getuid(),
+                gid: libc::getgid(),
+            })
+        }
+    }
+
+    /// Sets the process ID.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn set_pid(&mut self, pid: libc::pid_t) {
+        self.0.pid = pid;
+    }
+
+    /// Returns the process ID.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn pid(&self) -> libc::pid_t {
+        self.0.pid
+    }
+
+    /// Sets the user ID.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn set_uid(&mut self, uid: libc::uid_t) {
+        self.0.uid = uid;
+    }
+
+    /// Returns the user ID.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn uid(&self) -> libc::uid_t {
+        self.0.uid
+    }
+
+    /// Sets the group ID.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn set_gid(&mut self, gid: libc::gid_t) {
+        self.0.gid = gid;
+    }
+
+    /// Returns the group ID.
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+    pub fn gid(&self) -> libc::gid_t {
+        self.0.gid
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "none")]
+impl fmt::Debug for SocketCred {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SocketCred")
+            .field("pid", &self.0.pid)
+            .field("uid", &self.0.uid)
+            .field("gid", &self.0.gid)
+            .finish()
+    }
+}
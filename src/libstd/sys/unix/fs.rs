@@ -1,6 +1,6 @@
 use crate::os::unix::prelude::*;
 
-use crate::ffi::{CStr, CString, OsStr, OsString};
+use crate::ffi::{cstr_from_ptr, CStr, CString, OsStr, OsString};
 use crate::fmt;
 use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut, SeekFrom};
 use crate::mem;
@@ -14,8 +14,12 @@ use crate::sys_common::{AsInner, FromInner};
 
 use libc::{c_int, mode_t};
 
-#[cfg(any(target_os = "linux", target_os = "emscripten", target_os = "android"))]
+#[cfg(not(target_os = "redox"))]
 use libc::dirfd;
+#[cfg(not(any(target_os = "macos", target_os = "redox")))]
+use libc::{fdopendir, mkdirat, openat, unlinkat};
+#[cfg(not(target_os = "redox"))]
+use libc::AT_REMOVEDIR;
 #[cfg(any(target_os = "linux", target_os = "emscripten"))]
 use libc::fstatat64;
 #[cfg(not(any(
@@ -48,6 +52,9 @@ use libc::{
     dirent64, fstat64, ftruncate64, lseek64, lstat64, off64_t, open64, readdir64_r, stat64,
 };
 
+// Without `openat` and `unlinkat`, removing a tree can't be protected against
+// symbolic links swapped in during the traversal anyway.
+#[cfg(any(target_os = "redox", all(target_os = "macos", not(target_arch = "x86_64"))))]
 pub use crate::sys_common::fs::remove_dir_all;
 
 pub struct File(FileDesc);
@@ -239,6 +246,20 @@ pub struct DirBuilder {
     mode: mode_t,
 }
 
+// Identifies a directory by its device and inode numbers.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DirId {
+    dev: u64,
+    ino: u64,
+}
+
+pub struct DirHandle {
+    fd: FileDesc,
+    // The path the directory was opened with, used to build the paths of
+    // the entries returned by `read_dir`.
+    path: PathBuf,
+}
+
 cfg_has_statx! {{
     impl FileAttr {
         fn from_stat64(stat: stat64) -> Self {
@@ -488,6 +509,36 @@ impl Iterator for ReadDir {
     }
 }
 
+impl ReadDir {
+    // Takes ownership of `fd`, which must refer to a directory opened for
+    // reading as `root`, and also returns the identity of that directory.
+    fn from_fd(fd: FileDesc, root: PathBuf) -> io::Result<(ReadDir, DirId)> {
+        let mut stat: stat64 = unsafe { mem::zeroed() };
+        cvt(unsafe { fstat64(fd.raw(), &mut stat) })?;
+        let ptr = unsafe { fdopendir(fd.raw()) };
+        let ptr = if !ptr.is_null() {
+            // The directory stream now owns the file descriptor.
+            fd.into_raw();
+            ptr
+        } else {
+            let err = Error::last_os_error();
+            if err.raw_os_error() != Some(libc::ENOSYS) {
+                return Err(err);
+            }
+            // Without `fdopendir`, reopen the directory by its path.
+            let p = cstr(&root)?;
+            let ptr = unsafe { libc::opendir(p.as_ptr()) };
+            if ptr.is_null() {
+                return Err(Error::last_os_error());
+            }
+            ptr
+        };
+        let inner = InnerReadDir { dirp: Dir(ptr), root };
+        let id = DirId { dev: stat.st_dev as u64, ino: stat.st_ino as u64 };
+        Ok((ReadDir { inner: Arc::new(inner), end_of_stream: false }, id))
+    }
+}
+
 impl Drop for Dir {
     fn drop(&mut self) {
         let r = unsafe { libc::closedir(self.0) };
@@ -504,6 +555,20 @@ impl DirEntry {
         OsStr::from_bytes(self.name_bytes()).to_os_string()
     }
 
+    // Opens this entry as a directory relative to the directory it was read
+    // from, so that renaming or replacing any of its ancestors in the
+    // meantime can't redirect us elsewhere.
+    pub fn open_dir(&self, follow_links: bool) -> io::Result<(ReadDir, DirId)> {
+        let dir_fd = unsafe { dirfd(self.dir.inner.dirp.0) };
+        let name = CString::new(self.name_bytes())?;
+        let mut flags = DIR_FLAGS;
+        if !follow_links {
+            flags |= libc::O_NOFOLLOW;
+        }
+        let fd = open_at(dir_fd, &self.dir.inner.root, &name, flags, 0)?;
+        ReadDir::from_fd(fd, self.path())
+    }
+
     #[cfg(any(target_os = "linux", target_os = "emscripten", target_os = "android"))]
     pub fn metadata(&self) -> io::Result<FileAttr> {
         let fd = cvt(unsafe { dirfd(self.dir.inner.dirp.0) })?;
@@ -601,7 +666,7 @@ impl DirEntry {
         target_os = "haiku"
     ))]
     fn name_bytes(&self) -> &[u8] {
-        unsafe { CStr::from_ptr(self.entry.d_name.as_ptr()).to_bytes() }
+        unsafe { cstr_from_ptr(self.entry.d_name.as_ptr()).to_bytes() }
     }
     #[cfg(any(
         target_os = "solaris",
@@ -656,6 +721,13 @@ impl OpenOptions {
         self.mode = mode as mode_t;
     }
 
+    fn get_flags(&self) -> io::Result<c_int> {
+        Ok(libc::O_CLOEXEC
+            | self.get_access_mode()?
+            | self.get_creation_mode()?
+            | (self.custom_flags as c_int & !libc::O_ACCMODE))
+    }
+
     fn get_access_mode(&self) -> io::Result<c_int> {
         match (self.read, self.write, self.append) {
             (true, false, false) => Ok(libc::O_RDONLY),
@@ -699,17 +771,24 @@ impl File {
     }
 
     pub fn open_c(path: &CStr, opts: &OpenOptions) -> io::Result<File> {
-        let flags = libc::O_CLOEXEC
-            | opts.get_access_mode()?
-            | opts.get_creation_mode()?
-            | (opts.custom_flags as c_int & !libc::O_ACCMODE);
+        let flags = opts.get_flags()?;
         // The third argument of `open64` is documented to have type `mode_t`. On
         // some platforms (like macOS, where `open64` is actually `open`), `mode_t` is `u16`.
         // However, since this is a variadic function, C integer promotion rules mean that on
         // the ABI level, this still gets passed as `c_int` (aka `u32` on Unix platforms).
         let fd = cvt_r(|| unsafe { open64(path.as_ptr(), flags, opts.mode as c_int) })?;
-        let fd = FileDesc::new(fd);
+        File::from_opened(FileDesc::new(fd))
+    }
 
+    // Like `open_c`, but relative paths are resolved against the directory
+    // `dir` instead of the current working directory.
+    fn open_at_c(dir: &DirHandle, path: &CStr, opts: &OpenOptions) -> io::Result<File> {
+        let flags = opts.get_flags()?;
+        // See `open_c` for the type of the last argument.
+        File::from_opened(open_at(dir.fd.raw(), &dir.path, path, flags, opts.mode as c_int)?)
+    }
+
+    fn from_opened(fd: FileDesc) -> io::Result<File> {
         // Currently the standard library supports Linux 2.6.18 which did not
         // have the O_CLOEXEC flag (passed above). If we're running on an older
         // Linux kernel then the flag is just ignored by the OS. After we open
@@ -824,6 +903,76 @@ impl File {
         }
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_op(LockOp::Exclusive, true).map(drop)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_op(LockOp::Shared, true).map(drop)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.lock_op(LockOp::Exclusive, false)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.lock_op(LockOp::Shared, false)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.lock_op(LockOp::Unlock, false).map(drop)
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.0.is_terminal()
+    }
+
+    // Returns `Ok(false)` if a non-blocking request could not be granted
+    // because of a conflicting lock.
+    #[cfg(not(any(target_os = "solaris", target_os = "illumos")))]
+    fn lock_op(&self, op: LockOp, blocking: bool) -> io::Result<bool> {
+        let mut operation = match op {
+            LockOp::Exclusive => libc::LOCK_EX,
+            LockOp::Shared => libc::LOCK_SH,
+            LockOp::Unlock => libc::LOCK_UN,
+        };
+        if !blocking {
+            operation |= libc::LOCK_NB;
+        }
+        match cvt_r(|| unsafe { libc::flock(self.0.raw(), operation) }) {
+            Ok(_) => Ok(true),
+            Err(ref e) if e.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    // These platforms have no `flock`, so a lock on the whole file is taken
+    // with `fcntl` instead. Unlike `flock` locks, these are held by the process
+    // rather than by the open file description.
+    #[cfg(any(target_os = "solaris", target_os = "illumos"))]
+    fn lock_op(&self, op: LockOp, blocking: bool) -> io::Result<bool> {
+        let mut flock: libc::flock = unsafe { mem::zeroed() };
+        flock.l_type = match op {
+            LockOp::Exclusive => libc::F_WRLCK,
+            LockOp::Shared => libc::F_RDLCK,
+            LockOp::Unlock => libc::F_UNLCK,
+        } as libc::c_short;
+        flock.l_whence = libc::SEEK_SET as libc::c_short;
+        flock.l_start = 0;
+        flock.l_len = 0;
+        let cmd = if blocking { libc::F_SETLKW } else { libc::F_SETLK };
+        match cvt_r(|| unsafe { libc::fcntl(self.0.raw(), cmd, &flock) }) {
+            Ok(_) => Ok(true),
+            Err(ref e)
+                if e.raw_os_error() == Some(libc::EAGAIN)
+                    || e.raw_os_error() == Some(libc::EACCES) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -892,6 +1041,13 @@ impl File {
     }
 }
 
+#[derive(Copy, Clone)]
+enum LockOp {
+    Exclusive,
+    Shared,
+    Unlock,
+}
+
 impl DirBuilder {
     pub fn new() -> DirBuilder {
         DirBuilder { mode: 0o777 }
@@ -908,6 +1064,103 @@ impl DirBuilder {
     }
 }
 
+const DIR_FLAGS: c_int = libc::O_RDONLY | libc::O_DIRECTORY | libc::O_CLOEXEC;
+
+impl DirHandle {
+    pub fn open(p: &Path) -> io::Result<DirHandle> {
+        let path = cstr(p)?;
+        let fd = cvt_r(|| unsafe { open64(path.as_ptr(), DIR_FLAGS) })?;
+        Ok(DirHandle { fd: FileDesc::new(fd), path: p.to_path_buf() })
+    }
+
+    pub fn fd(&self) -> &FileDesc {
+        &self.fd
+    }
+
+    pub fn open_file_at(&self, p: &Path, opts: &OpenOptions) -> io::Result<File> {
+        File::open_at_c(self, &cstr(p)?, opts)
+    }
+
+    pub fn create_dir_at(&self, p: &Path) -> io::Result<()> {
+        let c_path = cstr(p)?;
+        match cvt(unsafe { mkdirat(self.fd.raw(), c_path.as_ptr(), 0o777) }) {
+            Ok(_) => Ok(()),
+            Err(ref e) if e.raw_os_error() == Some(libc::ENOSYS) => {
+                DirBuilder::new().mkdir(&self.path.join(p))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn remove_at(&self, p: &Path) -> io::Result<()> {
+        let p = cstr(p)?;
+        let err = match unlink_at(self.fd.raw(), &self.path, &p, false) {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+        // Unlinking a directory fails with `EISDIR` on Linux and with `EPERM`
+        // elsewhere, in which case it is removed as a directory instead.
+        match err.raw_os_error() {
+            Some(libc::EISDIR) | Some(libc::EPERM) => {}
+            _ => return Err(err),
+        }
+        match unlink_at(self.fd.raw(), &self.path, &p, true) {
+            Ok(()) => Ok(()),
+            // Not a directory after all, so the first error was the real one.
+            Err(ref e) if e.raw_os_error() == Some(libc::ENOTDIR) => Err(err),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        // The directory stream takes ownership of its file descriptor and
+        // moves its offset, so it gets a fresh one of its own.
+        let dot = unsafe { CStr::from_bytes_with_nul_unchecked(b".\0") };
+        let fd = open_at(self.fd.raw(), &self.path, dot, DIR_FLAGS, 0)?;
+        ReadDir::from_fd(fd, self.path.clone()).map(|(dir, _)| dir)
+    }
+}
+
+// Opens `name` relative to the directory `dir`, which was opened as `dir_path`.
+// Without `openat`, the joined path is opened instead.
+fn open_at(
+    dir: c_int,
+    dir_path: &Path,
+    name: &CStr,
+    flags: c_int,
+    mode: c_int,
+) -> io::Result<FileDesc> {
+    match cvt_r(|| unsafe { openat(dir, name.as_ptr(), flags, mode) }) {
+        Ok(fd) => Ok(FileDesc::new(fd)),
+        Err(ref e) if e.raw_os_error() == Some(libc::ENOSYS) => {
+            let path = cstr(&dir_path.join(OsStr::from_bytes(name.to_bytes())))?;
+            let fd = cvt_r(|| unsafe { open64(path.as_ptr(), flags, mode) })?;
+            Ok(FileDesc::new(fd))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+// Removes `name` from the directory `dir`, which was opened as `dir_path`.
+// Without `unlinkat`, the joined path is removed instead.
+fn unlink_at(dir: c_int, dir_path: &Path, name: &CStr, remove_dir: bool) -> io::Result<()> {
+    let flags = if remove_dir { AT_REMOVEDIR } else { 0 };
+    match cvt(unsafe { unlinkat(dir, name.as_ptr(), flags) }) {
+        Ok(_) => Ok(()),
+        Err(ref e) if e.raw_os_error() == Some(libc::ENOSYS) => {
+            let path = dir_path.join(OsStr::from_bytes(name.to_bytes()));
+            if remove_dir { rmdir(&path) } else { unlink(&path) }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("fd", &self.fd.raw()).field("path", &self.path).finish()
+    }
+}
+
 fn cstr(path: &Path) -> io::Result<CString> {
     Ok(CString::new(path.as_os_str().as_bytes())?)
 }
@@ -998,6 +1251,14 @@ pub fn readdir(p: &Path) -> io::Result<ReadDir> {
     }
 }
 
+pub fn open_dir(p: &Path) -> io::Result<(ReadDir, DirId)> {
+    let root = p.to_path_buf();
+    let p = cstr(p)?;
+    let flags = libc::O_RDONLY | libc::O_DIRECTORY | libc::O_CLOEXEC;
+    let fd = cvt_r(|| unsafe { open64(p.as_ptr(), flags) })?;
+    ReadDir::from_fd(FileDesc::new(fd), root)
+}
+
 pub fn unlink(p: &Path) -> io::Result<()> {
     let p = cstr(p)?;
     cvt(unsafe { libc::unlink(p.as_ptr()) })?;
@@ -1023,6 +1284,59 @@ pub fn rmdir(p: &Path) -> io::Result<()> {
     Ok(())
 }
 
+#[cfg(not(any(target_os = "redox", all(target_os = "macos", not(target_arch = "x86_64")))))]
+pub fn remove_dir_all(p: &Path) -> io::Result<()> {
+    // A symbolic link to a directory is removed rather than followed.
+    if lstat(p)?.file_type().is_symlink() {
+        return unlink(p);
+    }
+    // If `p` is replaced by a symbolic link in the meantime, opening it
+    // fails instead of following the link.
+    let path = cstr(p)?;
+    let fd = cvt_r(|| unsafe { open64(path.as_ptr(), DIR_FLAGS | libc::O_NOFOLLOW) })?;
+    let (dir, _) = ReadDir::from_fd(FileDesc::new(fd), p.to_path_buf())?;
+    remove_dir_contents(dir)?;
+    rmdir(p)
+}
+
+// Removes everything inside the directory read by `dir`. All the work is done
+// relative to the directory stream, so that symbolic links swapped in for
+// subdirectories while this runs are removed instead of followed. Each level
+// of the tree only keeps the file descriptor of its stream open.
+#[cfg(not(any(target_os = "redox", all(target_os = "macos", not(target_arch = "x86_64")))))]
+fn remove_dir_contents(dir: ReadDir) -> io::Result<()> {
+    let inner = dir.inner.clone();
+    let dir_fd = unsafe { dirfd(inner.dirp.0) };
+    for child in dir {
+        let child = child?;
+        let name = CString::new(child.name_bytes())?;
+        let result = if child.file_type()?.is_dir() {
+            // Refuses to follow a symbolic link.
+            match child.open_dir(false) {
+                Ok((subdir, _)) => {
+                    remove_dir_contents(subdir)?;
+                    unlink_at(dir_fd, &inner.root, &name, true)
+                }
+                Err(e) => match e.raw_os_error() {
+                    // It was replaced by a symbolic link or a file.
+                    Some(libc::ELOOP) | Some(libc::ENOTDIR) => {
+                        unlink_at(dir_fd, &inner.root, &name, false)
+                    }
+                    _ => Err(e),
+                },
+            }
+        } else {
+            unlink_at(dir_fd, &inner.root, &name, false)
+        };
+        match result {
+            // Somebody else removed it first, which is fine.
+            Err(ref e) if e.kind() == ErrorKind::NotFound => {}
+            result => result?,
+        }
+    }
+    Ok(())
+}
+
 pub fn readlink(p: &Path) -> io::Result<PathBuf> {
     let c_path = cstr(p)?;
     let p = c_path.as_ptr();
@@ -1110,7 +1424,7 @@ pub fn canonicalize(p: &Path) -> io::Result<PathBuf> {
         if r.is_null() {
             return Err(io::Error::last_os_error());
         }
-        buf = CStr::from_ptr(r).to_bytes().to_vec();
+        buf = cstr_from_ptr(r).to_bytes().to_vec();
         libc::free(r as *mut _);
     }
     Ok(PathBuf::from(OsString::from_vec(buf)))
@@ -1357,3 +1671,62 @@ pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     })?;
     Ok(bytes_copied as u64)
 }
+
+// Redox lacks `openat`, `fdopendir`, `mkdirat` and `unlinkat`, and macOS only
+// gained them in 10.10, so x86_64 macOS looks them up at runtime. Where they
+// are missing they fail with `ENOSYS`, and the directory handle code falls
+// back to using paths, which can be redirected by concurrent renames.
+#[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+syscall! { fn openat(fd: c_int, path: *const libc::c_char, flags: c_int, mode: c_int) -> c_int }
+#[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+syscall! { fn mkdirat(fd: c_int, path: *const libc::c_char, mode: mode_t) -> c_int }
+#[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+syscall! { fn unlinkat(fd: c_int, path: *const libc::c_char, flags: c_int) -> c_int }
+
+#[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+unsafe fn fdopendir(fd: c_int) -> *mut libc::DIR {
+    // `readdir_r` is linked to the variant with 64-bit inodes, so this has to be too.
+    static FDOPENDIR: crate::sys::weak::Weak<unsafe extern "C" fn(c_int) -> *mut libc::DIR> =
+        crate::sys::weak::Weak::new("fdopendir$INODE64\0");
+    match FDOPENDIR.get() {
+        Some(fdopendir) => fdopendir(fd),
+        None => {
+            super::os::set_errno(libc::ENOSYS);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[cfg(any(target_os = "redox", all(target_os = "macos", not(target_arch = "x86_64"))))]
+unsafe fn openat(_fd: c_int, _path: *const libc::c_char, _flags: c_int, _mode: c_int) -> c_int {
+    super::os::set_errno(libc::ENOSYS);
+    -1
+}
+
+#[cfg(any(target_os = "redox", all(target_os = "macos", not(target_arch = "x86_64"))))]
+unsafe fn mkdirat(_fd: c_int, _path: *const libc::c_char, _mode: mode_t) -> c_int {
+    super::os::set_errno(libc::ENOSYS);
+    -1
+}
+
+#[cfg(any(target_os = "redox", all(target_os = "macos", not(target_arch = "x86_64"))))]
+unsafe fn unlinkat(_fd: c_int, _path: *const libc::c_char, _flags: c_int) -> c_int {
+    super::os::set_errno(libc::ENOSYS);
+    -1
+}
+
+#[cfg(any(target_os = "redox", all(target_os = "macos", not(target_arch = "x86_64"))))]
+unsafe fn fdopendir(_fd: c_int) -> *mut libc::DIR {
+    super::os::set_errno(libc::ENOSYS);
+    ptr::null_mut()
+}
+
+// Redox has no `dirfd` or `AT_REMOVEDIR` either, but they are only ever
+// passed to the stubs above.
+#[cfg(target_os = "redox")]
+unsafe fn dirfd(_dirp: *mut libc::DIR) -> c_int {
+    -1
+}
+
+#[cfg(target_os = "redox")]
+const AT_REMOVEDIR: c_int = 0;
@@ -5,7 +5,7 @@
 use crate::os::unix::prelude::*;
 
 use crate::error::Error as StdError;
-use crate::ffi::{CStr, CString, OsStr, OsString};
+use crate::ffi::{cstr_from_ptr, CStr, CString, OsStr, OsString};
 use crate::fmt;
 use crate::io;
 use crate::iter;
@@ -113,7 +113,7 @@ pub fn error_string(errno: i32) -> String {
         }
 
         let p = p as *const _;
-        str::from_utf8(CStr::from_ptr(p).to_bytes()).unwrap().to_owned()
+        str::from_utf8(cstr_from_ptr(p).to_bytes()).unwrap().to_owned()
     }
 }
 
@@ -123,7 +123,7 @@ pub fn getcwd() -> io::Result<PathBuf> {
         unsafe {
             let ptr = buf.as_mut_ptr() as *mut libc::c_char;
             if !libc::getcwd(ptr, buf.capacity()).is_null() {
-                let len = CStr::from_ptr(buf.as_ptr() as *const libc::c_char).to_bytes().len();
+                let len = cstr_from_ptr(buf.as_ptr() as *const libc::c_char).to_bytes().len();
                 buf.set_len(len);
                 buf.shrink_to_fit();
                 return Ok(PathBuf::from(OsString::from_vec(buf)));
@@ -316,7 +316,7 @@ pub fn current_exe() -> io::Result<PathBuf> {
         if argv[0].is_null() {
             return Err(io::Error::new(io::ErrorKind::Other, "no current exe available"));
         }
-        let argv0 = CStr::from_ptr(argv[0]).to_bytes();
+        let argv0 = cstr_from_ptr(argv[0]).to_bytes();
         if argv0[0] == b'.' || argv0.iter().any(|b| *b == b'/') {
             crate::fs::canonicalize(OsStr::from_bytes(argv0))
         } else {
@@ -367,7 +367,7 @@ pub fn current_exe() -> io::Result<PathBuf> {
         if path.is_null() {
             Err(io::Error::last_os_error())
         } else {
-            let filename = CStr::from_ptr(path).to_bytes();
+            let filename = cstr_from_ptr(path).to_bytes();
             let path = PathBuf::from(<OsStr as OsStrExt>::from_bytes(filename));
 
             // Prepend a current working directory to the path if
@@ -418,7 +418,7 @@ pub fn current_exe() -> io::Result<PathBuf> {
             use crate::io::ErrorKind;
             Err(io::Error::new(ErrorKind::Other, "Error getting executable path"))
         } else {
-            let name = CStr::from_ptr(info.name.as_ptr()).to_bytes();
+            let name = cstr_from_ptr(info.name.as_ptr()).to_bytes();
             Ok(PathBuf::from(OsStr::from_bytes(name)))
         }
     }
@@ -482,7 +482,7 @@ pub fn env() -> Env {
         let mut result = Vec::new();
         if !environ.is_null() {
             while !(*environ).is_null() {
-                if let Some(key_value) = parse(CStr::from_ptr(*environ).to_bytes()) {
+                if let Some(key_value) = parse(cstr_from_ptr(*environ).to_bytes()) {
                     result.push(key_value);
                 }
                 environ = environ.add(1);
@@ -519,7 +519,7 @@ pub fn getenv(k: &OsStr) -> io::Result<Option<OsString>> {
         let ret = if s.is_null() {
             None
         } else {
-            Some(OsStringExt::from_vec(CStr::from_ptr(s).to_bytes().to_vec()))
+            Some(OsStringExt::from_vec(cstr_from_ptr(s).to_bytes().to_vec()))
         };
         Ok(ret)
     }
@@ -593,7 +593,7 @@ pub fn home_dir() -> Option<PathBuf> {
         ) {
             0 if !result.is_null() => {
                 let ptr = passwd.pw_dir as *const _;
-                let bytes = CStr::from_ptr(ptr).to_bytes().to_vec();
+                let bytes = cstr_from_ptr(ptr).to_bytes().to_vec();
                 Some(OsStringExt::from_vec(bytes))
             }
             _ => None,
@@ -628,7 +628,7 @@ fn glibc_version_cstr() -> Option<&'static CStr> {
         fn gnu_get_libc_version() -> *const libc::c_char
     }
     if let Some(f) = gnu_get_libc_version.get() {
-        unsafe { Some(CStr::from_ptr(f())) }
+        unsafe { Some(cstr_from_ptr(f())) }
     } else {
         None
     }
@@ -10,6 +10,10 @@ impl Stdin {
     pub fn new() -> io::Result<Stdin> {
         Ok(Stdin(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(FileDesc::new(libc::STDIN_FILENO)).is_terminal()
+    }
 }
 
 impl io::Read for Stdin {
@@ -31,6 +35,10 @@ impl Stdout {
     pub fn new() -> io::Result<Stdout> {
         Ok(Stdout(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(FileDesc::new(libc::STDOUT_FILENO)).is_terminal()
+    }
 }
 
 impl io::Write for Stdout {
@@ -56,6 +64,10 @@ impl Stderr {
     pub fn new() -> io::Result<Stderr> {
         Ok(Stderr(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(FileDesc::new(libc::STDERR_FILENO)).is_terminal()
+    }
 }
 
 impl io::Write for Stderr {
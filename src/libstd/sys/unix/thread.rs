@@ -41,7 +41,29 @@ unsafe fn pthread_attr_setstacksize(
 impl Thread {
     // unsafe: see thread::Builder::spawn_unchecked for safety requirements
     pub unsafe fn new(stack: usize, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
-        let p = Box::into_raw(box p);
+        Thread::new_with_attr(stack, p, |_| Ok(()))
+    }
+
+    // unsafe: see thread::Builder::spawn_unchecked for safety requirements
+    #[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+    pub unsafe fn new_pinned(
+        stack: usize,
+        cpus: &linux::CpuSet,
+        p: Box<dyn FnOnce()>,
+    ) -> io::Result<Thread> {
+        Thread::new_with_attr(stack, p, |attr| linux::attr_set_affinity(attr, cpus))
+    }
+
+    // `configure` is given a chance to adjust the attributes of the new
+    // thread right before it is created.
+    unsafe fn new_with_attr<F>(
+        stack: usize,
+        p: Box<dyn FnOnce()>,
+        configure: F,
+    ) -> io::Result<Thread>
+    where
+        F: FnOnce(&mut libc::pthread_attr_t) -> io::Result<()>,
+    {
         let mut native: libc::pthread_t = mem::zeroed();
         let mut attr: libc::pthread_attr_t = mem::zeroed();
         assert_eq!(libc::pthread_attr_init(&mut attr), 0);
@@ -63,6 +85,12 @@ impl Thread {
             }
         };
 
+        if let Err(e) = configure(&mut attr) {
+            assert_eq!(libc::pthread_attr_destroy(&mut attr), 0);
+            return Err(e);
+        }
+
+        let p = Box::into_raw(box p);
         let ret = libc::pthread_create(&mut native, &attr, thread_start, p as *mut _);
         // Note: if the thread creation fails and this assert fails, then p will
         // be leaked. However, an alternative design could cause double-free
@@ -212,6 +240,131 @@ impl Drop for Thread {
     }
 }
 
+/// Linux-specific thread controls, exposed through `std::os::linux::thread`.
+#[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+pub mod linux {
+    use crate::ffi::CStr;
+    use crate::fmt;
+    use crate::io;
+    use crate::mem;
+    use crate::sys::cvt;
+
+    #[derive(Copy, Clone)]
+    pub struct CpuSet(libc::cpu_set_t);
+
+    impl CpuSet {
+        pub const CAPACITY: usize = libc::CPU_SETSIZE as usize;
+
+        pub fn new() -> CpuSet {
+            unsafe {
+                let mut set: libc::cpu_set_t = mem::zeroed();
+                libc::CPU_ZERO(&mut set);
+                CpuSet(set)
+            }
+        }
+
+        pub fn set(&mut self, cpu: usize) {
+            assert!(cpu < CpuSet::CAPACITY, "CPU index out of range");
+            unsafe { libc::CPU_SET(cpu, &mut self.0) }
+        }
+
+        pub fn clear(&mut self, cpu: usize) {
+            assert!(cpu < CpuSet::CAPACITY, "CPU index out of range");
+            unsafe { libc::CPU_CLR(cpu, &mut self.0) }
+        }
+
+        pub fn contains(&self, cpu: usize) -> bool {
+            cpu < CpuSet::CAPACITY && unsafe { libc::CPU_ISSET(cpu, &self.0) }
+        }
+
+        pub fn count(&self) -> usize {
+            (0..CpuSet::CAPACITY).filter(|&cpu| self.contains(cpu)).count()
+        }
+    }
+
+    impl PartialEq for CpuSet {
+        fn eq(&self, other: &CpuSet) -> bool {
+            (0..CpuSet::CAPACITY).all(|cpu| self.contains(cpu) == other.contains(cpu))
+        }
+    }
+
+    impl Eq for CpuSet {}
+
+    impl fmt::Debug for CpuSet {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_set().entries((0..CpuSet::CAPACITY).filter(|&cpu| self.contains(cpu))).finish()
+        }
+    }
+
+    // The pthread functions return the error number instead of setting errno.
+    fn cvt_pthread(ret: libc::c_int) -> io::Result<()> {
+        if ret == 0 { Ok(()) } else { Err(io::Error::from_raw_os_error(ret)) }
+    }
+
+    pub fn set_affinity(thread: libc::pthread_t, cpus: &CpuSet) -> io::Result<()> {
+        cvt_pthread(unsafe {
+            libc::pthread_setaffinity_np(thread, mem::size_of::<libc::cpu_set_t>(), &cpus.0)
+        })
+    }
+
+    pub fn affinity(thread: libc::pthread_t) -> io::Result<CpuSet> {
+        let mut cpus = CpuSet::new();
+        cvt_pthread(unsafe {
+            libc::pthread_getaffinity_np(thread, mem::size_of::<libc::cpu_set_t>(), &mut cpus.0)
+        })?;
+        Ok(cpus)
+    }
+
+    pub unsafe fn attr_set_affinity(
+        attr: &mut libc::pthread_attr_t,
+        cpus: &CpuSet,
+    ) -> io::Result<()> {
+        // Not every libc we support provides this, so look it up at runtime.
+        weak! {
+            fn pthread_attr_setaffinity_np(
+                *mut libc::pthread_attr_t, libc::size_t, *const libc::cpu_set_t
+            ) -> libc::c_int
+        }
+
+        match pthread_attr_setaffinity_np.get() {
+            Some(f) => cvt_pthread(f(attr, mem::size_of::<libc::cpu_set_t>(), &cpus.0)),
+            None => Err(io::Error::from_raw_os_error(libc::ENOSYS)),
+        }
+    }
+
+    pub fn set_sched_policy(
+        thread: libc::pthread_t,
+        policy: libc::c_int,
+        priority: libc::c_int,
+    ) -> io::Result<()> {
+        let param = libc::sched_param { sched_priority: priority };
+        cvt_pthread(unsafe { libc::pthread_setschedparam(thread, policy, &param) })
+    }
+
+    pub fn set_nice(nice: libc::c_int) -> io::Result<()> {
+        // On Linux the nice value is a per-thread attribute, addressed by the
+        // kernel thread id rather than by the process id.
+        unsafe {
+            let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+            cvt(libc::setpriority(libc::PRIO_PROCESS, tid, nice))?;
+        }
+        Ok(())
+    }
+
+    pub fn set_name(thread: libc::pthread_t, name: &CStr) -> io::Result<()> {
+        // pthread_setname_np only appeared in glibc 2.12, so look it up at
+        // runtime instead of linking to it.
+        weak! {
+            fn pthread_setname_np(libc::pthread_t, *const libc::c_char) -> libc::c_int
+        }
+
+        match pthread_setname_np.get() {
+            Some(f) => cvt_pthread(unsafe { f(thread, name.as_ptr()) }),
+            None => Err(io::Error::from_raw_os_error(libc::ENOSYS)),
+        }
+    }
+}
+
 #[cfg(all(
     not(all(target_os = "linux", not(target_env = "musl"))),
     not(target_os = "freebsd"),
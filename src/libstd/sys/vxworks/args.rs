@@ -53,7 +53,7 @@ impl DoubleEndedIterator for Args {
 
 mod imp {
     use super::Args;
-    use crate::ffi::{CStr, OsString};
+    use crate::ffi::{cstr_from_ptr, OsString};
     use crate::marker::PhantomData;
     use crate::ptr;
     use libc;
@@ -85,7 +85,7 @@ mod imp {
             let _guard = LOCK.lock();
             let ret = (0..ARGC)
                 .map(|i| {
-                    let cstr = CStr::from_ptr(*ARGV.offset(i) as *const libc::c_char);
+                    let cstr = cstr_from_ptr(*ARGV.offset(i) as *const libc::c_char);
                     use crate::sys::vxworks::ext::ffi::OsStringExt;
                     OsStringExt::from_vec(cstr.to_bytes().to_vec())
                 })
@@ -36,6 +36,10 @@ impl FileDesc {
         fd
     }
 
+    pub fn is_terminal(&self) -> bool {
+        unsafe { libc::isatty(self.fd) != 0 }
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         let ret = cvt(unsafe {
             libc::read(self.fd, buf.as_mut_ptr() as *mut c_void, cmp::min(buf.len(), max_len()))
@@ -1,5 +1,5 @@
 // copies from linuxx
-use crate::ffi::{CStr, CString, OsStr, OsString};
+use crate::ffi::{cstr_from_ptr, CStr, CString, OsStr, OsString};
 use crate::fmt;
 use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut, SeekFrom};
 use crate::mem;
@@ -72,6 +72,19 @@ pub struct DirBuilder {
     mode: mode_t,
 }
 
+// Identifies a directory by its device and inode numbers.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DirId {
+    dev: u64,
+    ino: u64,
+}
+
+// VxWorks lacks the `*at` family of functions, so this remembers the path
+// and joins onto it.
+pub struct DirHandle {
+    path: PathBuf,
+}
+
 impl FileAttr {
     pub fn size(&self) -> u64 {
         self.stat.st_size as u64
@@ -211,6 +224,10 @@ impl DirEntry {
         OsStr::from_bytes(self.name_bytes()).to_os_string()
     }
 
+    pub fn open_dir(&self, _follow_links: bool) -> io::Result<(ReadDir, DirId)> {
+        open_dir(&self.path())
+    }
+
     pub fn metadata(&self) -> io::Result<FileAttr> {
         lstat(&self.path())
     }
@@ -226,7 +243,7 @@ impl DirEntry {
     fn name_bytes(&self) -> &[u8] {
         unsafe {
             //&*self.name
-            CStr::from_ptr(self.entry.d_name.as_ptr()).to_bytes()
+            cstr_from_ptr(self.entry.d_name.as_ptr()).to_bytes()
         }
     }
 }
@@ -343,6 +360,30 @@ impl File {
         return cvt_r(|| unsafe { ftruncate(self.0.raw(), size as off_t) }).map(drop);
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        Err(lock_unsupported())
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        Err(lock_unsupported())
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.0.is_terminal()
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -415,6 +456,10 @@ impl File {
     }
 }
 
+fn lock_unsupported() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "file locking is not supported on this platform currently")
+}
+
 impl DirBuilder {
     pub fn new() -> DirBuilder {
         DirBuilder { mode: 0o777 }
@@ -480,6 +525,38 @@ impl fmt::Debug for File {
     }
 }
 
+impl DirHandle {
+    pub fn open(p: &Path) -> io::Result<DirHandle> {
+        if !stat(p)?.file_type().is_dir() {
+            return Err(Error::from_raw_os_error(libc::ENOTDIR));
+        }
+        Ok(DirHandle { path: p.to_path_buf() })
+    }
+
+    pub fn open_file_at(&self, p: &Path, opts: &OpenOptions) -> io::Result<File> {
+        File::open(&self.path.join(p), opts)
+    }
+
+    pub fn create_dir_at(&self, p: &Path) -> io::Result<()> {
+        DirBuilder::new().mkdir(&self.path.join(p))
+    }
+
+    pub fn remove_at(&self, p: &Path) -> io::Result<()> {
+        let path = self.path.join(p);
+        if lstat(&path)?.file_type().is_dir() { rmdir(&path) } else { unlink(&path) }
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        readdir(&self.path)
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("path", &self.path).finish()
+    }
+}
+
 pub fn readdir(p: &Path) -> io::Result<ReadDir> {
     let root = p.to_path_buf();
     let p = cstr(p)?;
@@ -494,6 +571,12 @@ pub fn readdir(p: &Path) -> io::Result<ReadDir> {
     }
 }
 
+pub fn open_dir(p: &Path) -> io::Result<(ReadDir, DirId)> {
+    let attr = stat(p)?;
+    let id = DirId { dev: attr.stat.st_dev as u64, ino: attr.stat.st_ino as u64 };
+    Ok((readdir(p)?, id))
+}
+
 pub fn unlink(p: &Path) -> io::Result<()> {
     let p = cstr(p)?;
     cvt(unsafe { libc::unlink(p.as_ptr()) })?;
@@ -600,7 +683,7 @@ pub fn canonicalize(p: &Path) -> io::Result<PathBuf> {
         if r.is_null() {
             return Err(io::Error::last_os_error());
         }
-        buf = CStr::from_ptr(r).to_bytes().to_vec();
+        buf = cstr_from_ptr(r).to_bytes().to_vec();
         libc::free(r as *mut _);
     }
     Ok(PathBuf::from(OsString::from_vec(buf)))
@@ -1,5 +1,5 @@
 use crate::error::Error as StdError;
-use crate::ffi::{CStr, CString, OsStr, OsString};
+use crate::ffi::{cstr_from_ptr, CString, OsStr, OsString};
 use crate::fmt;
 use crate::io;
 use crate::iter;
@@ -72,7 +72,7 @@ pub fn error_string(errno: i32) -> String {
             panic!("strerror_r failure");
         }
         let p = p as *const _;
-        str::from_utf8(CStr::from_ptr(p).to_bytes()).unwrap().to_owned()
+        str::from_utf8(cstr_from_ptr(p).to_bytes()).unwrap().to_owned()
     }
 }
 
@@ -82,7 +82,7 @@ pub fn getcwd() -> io::Result<PathBuf> {
         unsafe {
             let ptr = buf.as_mut_ptr() as *mut libc::c_char;
             if !libc::getcwd(ptr, buf.capacity() as libc::size_t).is_null() {
-                let len = CStr::from_ptr(buf.as_ptr() as *const libc::c_char).to_bytes().len();
+                let len = cstr_from_ptr(buf.as_ptr() as *const libc::c_char).to_bytes().len();
                 buf.set_len(len);
                 buf.shrink_to_fit();
                 return Ok(PathBuf::from(OsString::from_vec(buf)));
@@ -230,7 +230,7 @@ pub fn env() -> Env {
         }
         let mut result = Vec::new();
         while !(*environ).is_null() {
-            if let Some(key_value) = parse(CStr::from_ptr(*environ).to_bytes()) {
+            if let Some(key_value) = parse(cstr_from_ptr(*environ).to_bytes()) {
                 result.push(key_value);
             }
             environ = environ.add(1);
@@ -266,7 +266,7 @@ pub fn getenv(k: &OsStr) -> io::Result<Option<OsString>> {
         let ret = if s.is_null() {
             None
         } else {
-            Some(OsStringExt::from_vec(CStr::from_ptr(s).to_bytes().to_vec()))
+            Some(OsStringExt::from_vec(cstr_from_ptr(s).to_bytes().to_vec()))
         };
         Ok(ret)
     }
@@ -9,6 +9,10 @@ impl Stdin {
     pub fn new() -> io::Result<Stdin> {
         Ok(Stdin(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        unsafe { libc::isatty(libc::STDIN_FILENO) != 0 }
+    }
 }
 
 impl io::Read for Stdin {
@@ -24,6 +28,10 @@ impl Stdout {
     pub fn new() -> io::Result<Stdout> {
         Ok(Stdout(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        unsafe { libc::isatty(libc::STDOUT_FILENO) != 0 }
+    }
 }
 
 impl io::Write for Stdout {
@@ -43,6 +51,10 @@ impl Stderr {
     pub fn new() -> io::Result<Stderr> {
         Ok(Stderr(()))
     }
+
+    pub fn is_terminal(&self) -> bool {
+        unsafe { libc::isatty(libc::STDERR_FILENO) != 0 }
+    }
 }
 
 impl io::Write for Stderr {
@@ -150,6 +150,18 @@ impl WasiFd {
         unsafe { wasi::fd_filestat_get(self.fd).map_err(err2io) }
     }
 
+    // WASI has no `isatty`, so follow wasi-libc: a terminal is a character
+    // device that can be neither seeked nor told.
+    pub fn is_terminal(&self) -> bool {
+        match unsafe { wasi::fd_fdstat_get(self.fd) } {
+            Ok(stat) => {
+                stat.fs_filetype == wasi::FILETYPE_CHARACTER_DEVICE
+                    && stat.fs_rights_base & (wasi::RIGHTS_FD_SEEK | wasi::RIGHTS_FD_TELL) == 0
+            }
+            Err(_) => false,
+        }
+    }
+
     pub fn filestat_set_times(
         &self,
         atim: wasi::Timestamp,
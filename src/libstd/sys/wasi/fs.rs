@@ -66,6 +66,20 @@ pub struct FileType {
 #[derive(Debug)]
 pub struct DirBuilder {}
 
+// Identifies a directory by its device and inode numbers.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DirId {
+    dev: u64,
+    ino: u64,
+}
+
+pub struct DirHandle {
+    dir: File,
+    // The path the directory was opened with, used to build the paths of
+    // the entries returned by `read_dir`.
+    path: PathBuf,
+}
+
 impl FileAttr {
     pub fn size(&self) -> u64 {
         self.meta.size
@@ -217,6 +231,16 @@ impl DirEntry {
         OsString::from_vec(self.name.clone())
     }
 
+    pub fn open_dir(&self, follow_links: bool) -> io::Result<(ReadDir, DirId)> {
+        let mut opts = OpenOptions::new();
+        opts.directory(true);
+        opts.read(true);
+        opts.lookup_flags(if follow_links { wasi::LOOKUPFLAGS_SYMLINK_FOLLOW } else { 0 });
+        let name = OsStr::from_bytes(&self.name);
+        let dir = open_at(&self.inner.dir.fd, name.as_ref(), &opts)?;
+        ReadDir::from_dir(dir, self.path())
+    }
+
     pub fn metadata(&self) -> io::Result<FileAttr> {
         metadata_at(&self.inner.dir.fd, 0, OsStr::from_bytes(&self.name).as_ref())
     }
@@ -387,6 +411,30 @@ impl File {
         self.fd.datasync()
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.fd.is_terminal()
+    }
+
     pub fn truncate(&self, size: u64) -> io::Result<()> {
         self.fd.filestat_set_size(size)
     }
@@ -472,18 +520,71 @@ impl fmt::Debug for File {
     }
 }
 
+impl DirHandle {
+    pub fn open(p: &Path) -> io::Result<DirHandle> {
+        let mut opts = OpenOptions::new();
+        opts.directory(true);
+        opts.read(true);
+        Ok(DirHandle { dir: File::open(p, &opts)?, path: p.to_path_buf() })
+    }
+
+    pub fn open_file_at(&self, p: &Path, opts: &OpenOptions) -> io::Result<File> {
+        open_at(&self.dir.fd, p, opts)
+    }
+
+    pub fn create_dir_at(&self, p: &Path) -> io::Result<()> {
+        self.dir.fd.create_directory(osstr2str(p.as_ref())?)
+    }
+
+    pub fn remove_at(&self, p: &Path) -> io::Result<()> {
+        let path = osstr2str(p.as_ref())?;
+        if metadata_at(&self.dir.fd, 0, p)?.file_type().is_dir() {
+            self.dir.fd.remove_directory(path)
+        } else {
+            self.dir.fd.unlink_file(path)
+        }
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        let mut opts = OpenOptions::new();
+        opts.directory(true);
+        opts.read(true);
+        let dir = open_at(&self.dir.fd, Path::new("."), &opts)?;
+        ReadDir::from_dir(dir, self.path.clone()).map(|(dir, _)| dir)
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("fd", &self.dir.fd.as_raw()).field("path", &self.path).finish()
+    }
+}
+
 pub fn readdir(p: &Path) -> io::Result<ReadDir> {
+    open_dir(p).map(|(dir, _)| dir)
+}
+
+pub fn open_dir(p: &Path) -> io::Result<(ReadDir, DirId)> {
     let mut opts = OpenOptions::new();
     opts.directory(true);
     opts.read(true);
     let dir = File::open(p, &opts)?;
-    Ok(ReadDir {
-        cookie: Some(0),
-        buf: vec![0; 128],
-        offset: 0,
-        cap: 0,
-        inner: Arc::new(ReadDirInner { dir, root: p.to_path_buf() }),
-    })
+    ReadDir::from_dir(dir, p.to_path_buf())
+}
+
+impl ReadDir {
+    fn from_dir(dir: File, root: PathBuf) -> io::Result<(ReadDir, DirId)> {
+        let meta = dir.fd.filestat_get()?;
+        let id = DirId { dev: meta.dev, ino: meta.ino };
+        let dir = ReadDir {
+            cookie: Some(0),
+            buf: vec![0; 128],
+            offset: 0,
+            cap: 0,
+            inner: Arc::new(ReadDirInner { dir, root }),
+        };
+        Ok((dir, id))
+    }
 }
 
 pub fn unlink(p: &Path) -> io::Result<()> {
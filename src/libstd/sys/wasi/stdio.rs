@@ -11,6 +11,10 @@ impl Stdin {
         Ok(Stdin)
     }
 
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(unsafe { WasiFd::from_raw(self.as_raw_fd()) }).is_terminal()
+    }
+
     pub fn read(&self, data: &mut [u8]) -> io::Result<usize> {
         self.read_vectored(&mut [IoSliceMut::new(data)])
     }
@@ -34,6 +38,10 @@ impl Stdout {
         Ok(Stdout)
     }
 
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(unsafe { WasiFd::from_raw(self.as_raw_fd()) }).is_terminal()
+    }
+
     pub fn write(&self, data: &[u8]) -> io::Result<usize> {
         self.write_vectored(&[IoSlice::new(data)])
     }
@@ -61,6 +69,10 @@ impl Stderr {
         Ok(Stderr)
     }
 
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(unsafe { WasiFd::from_raw(self.as_raw_fd()) }).is_terminal()
+    }
+
     pub fn write(&self, data: &[u8]) -> io::Result<usize> {
         self.write_vectored(&[IoSlice::new(data)])
     }
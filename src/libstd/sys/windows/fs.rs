@@ -80,6 +80,31 @@ pub struct FilePermissions {
 #[derive(Debug)]
 pub struct DirBuilder;
 
+// Identifies a directory by its volume and file index, when they are known.
+#[derive(Copy, Clone, Debug)]
+pub struct DirId {
+    volume_serial_number: Option<u32>,
+    file_index: Option<u64>,
+}
+
+// Windows has no portable way to resolve paths relative to a directory
+// handle, so this remembers the path and joins onto it.
+pub struct DirHandle {
+    path: PathBuf,
+}
+
+impl PartialEq for DirId {
+    fn eq(&self, other: &DirId) -> bool {
+        match (self.volume_serial_number, self.file_index) {
+            (Some(volume), Some(index)) => {
+                other.volume_serial_number == Some(volume) && other.file_index == Some(index)
+            }
+            // Unknown identities never compare equal, not even to themselves.
+            _ => false,
+        }
+    }
+}
+
 impl fmt::Debug for ReadDir {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // This will only be called from std::fs::ReadDir, which will add a "ReadDir()" frame.
@@ -141,6 +166,13 @@ impl DirEntry {
         OsString::from_wide(filename)
     }
 
+    // There is no way to open a directory relative to a `FindFirstFileW`
+    // handle, so this goes through the path. Whether a symlink is followed is
+    // up to the caller, which only asks for directories it decided to enter.
+    pub fn open_dir(&self, _follow_links: bool) -> io::Result<(ReadDir, DirId)> {
+        open_dir(&self.path())
+    }
+
     pub fn file_type(&self) -> io::Result<FileType> {
         Ok(FileType::new(
             self.data.dwFileAttributes,
@@ -308,6 +340,48 @@ impl File {
         self.fsync()
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK).map(drop)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_file(0).map(drop)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.lock_file(c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    // Locks the largest possible range, which covers the whole file no
+    // matter how it grows. Returns `Ok(false)` if `LOCKFILE_FAIL_IMMEDIATELY`
+    // was passed and the lock is held elsewhere.
+    fn lock_file(&self, flags: c::DWORD) -> io::Result<bool> {
+        let mut overlapped: c::OVERLAPPED = unsafe { mem::zeroed() };
+        let ret = unsafe { c::LockFileEx(self.handle.raw(), flags, 0, !0, !0, &mut overlapped) };
+        if ret != 0 {
+            return Ok(true);
+        }
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) {
+            Ok(false)
+        } else {
+            Err(err)
+        }
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt(unsafe { c::UnlockFile(self.handle.raw(), 0, 0, !0, !0) })?;
+        Ok(())
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.handle.is_console()
+    }
+
     pub fn truncate(&self, size: u64) -> io::Result<()> {
         let mut info = c::FILE_END_OF_FILE_INFO { EndOfFile: size as c::LARGE_INTEGER };
         let size = mem::size_of_val(&info);
@@ -683,6 +757,39 @@ impl DirBuilder {
     }
 }
 
+impl DirHandle {
+    pub fn open(p: &Path) -> io::Result<DirHandle> {
+        if !stat(p)?.file_type().is_dir() {
+            return Err(io::Error::new(io::ErrorKind::Other, "not a directory"));
+        }
+        Ok(DirHandle { path: p.to_path_buf() })
+    }
+
+    pub fn open_file_at(&self, p: &Path, opts: &OpenOptions) -> io::Result<File> {
+        File::open(&self.path.join(p), opts)
+    }
+
+    pub fn create_dir_at(&self, p: &Path) -> io::Result<()> {
+        DirBuilder::new().mkdir(&self.path.join(p))
+    }
+
+    pub fn remove_at(&self, p: &Path) -> io::Result<()> {
+        let path = self.path.join(p);
+        let file_type = lstat(&path)?.file_type();
+        if file_type.is_dir() || file_type.is_symlink_dir() { rmdir(&path) } else { unlink(&path) }
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        readdir(&self.path)
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("path", &self.path).finish()
+    }
+}
+
 pub fn readdir(p: &Path) -> io::Result<ReadDir> {
     let root = p.to_path_buf();
     let star = p.join("*");
@@ -703,6 +810,12 @@ pub fn readdir(p: &Path) -> io::Result<ReadDir> {
     }
 }
 
+pub fn open_dir(p: &Path) -> io::Result<(ReadDir, DirId)> {
+    let attr = stat(p)?;
+    let id = DirId { volume_serial_number: attr.volume_serial_number, file_index: attr.file_index };
+    Ok((readdir(p)?, id))
+}
+
 pub fn unlink(p: &Path) -> io::Result<()> {
     let p_u16s = to_u16s(p)?;
     cvt(unsafe { c::DeleteFileW(p_u16s.as_ptr()) })?;
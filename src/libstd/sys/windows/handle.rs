@@ -68,6 +68,19 @@ impl RawHandle {
         self.0
     }
 
+    // Only the Windows console is detected; terminal emulators such as
+    // mintty that talk to the program over a pipe are not.
+    #[cfg(not(target_vendor = "uwp"))]
+    pub fn is_console(&self) -> bool {
+        let mut mode = 0;
+        unsafe { c::GetConsoleMode(self.0, &mut mode) != 0 }
+    }
+
+    #[cfg(target_vendor = "uwp")]
+    pub fn is_console(&self) -> bool {
+        false
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         let mut read = 0;
         let len = cmp::min(buf.len(), <c::DWORD>::max_value() as usize) as c::DWORD;
@@ -1,6 +1,9 @@
 use crate::ffi::OsStr;
+use crate::io;
 use crate::mem;
-use crate::path::Prefix;
+use crate::path::{Path, PathBuf, Prefix};
+use crate::ptr;
+use crate::sys::c;
 
 fn os_str_as_u8_slice(s: &OsStr) -> &[u8] {
     unsafe { mem::transmute(s) }
@@ -90,5 +93,20 @@ pub fn parse_prefix(path: &OsStr) -> Option<Prefix<'_>> {
     }
 }
 
+/// Makes a Windows path absolute, the same way the system does when it
+/// opens a file.
+pub(crate) fn absolute(path: &Path) -> io::Result<PathBuf> {
+    // Verbatim paths are never normalized by the system, so neither are they
+    // here.
+    if parse_prefix(path.as_os_str()).map_or(false, |prefix| prefix.is_verbatim()) {
+        return Ok(path.to_path_buf());
+    }
+    let path = super::to_u16s(path)?;
+    super::fill_utf16_buf(
+        |buf, sz| unsafe { c::GetFullPathNameW(path.as_ptr(), sz, buf, ptr::null_mut()) },
+        super::os2path,
+    )
+}
+
 pub const MAIN_SEP_STR: &str = "\\";
 pub const MAIN_SEP: char = '\\';
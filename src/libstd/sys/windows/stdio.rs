@@ -7,7 +7,7 @@ use crate::ptr;
 use crate::str;
 use crate::sys::c;
 use crate::sys::cvt;
-use crate::sys::handle::Handle;
+use crate::sys::handle::{Handle, RawHandle};
 
 // Don't cache handles but get them fresh for every read/write. This allows us to track changes to
 // the value over time (such as if a process calls `SetStdHandle` while it's running). See #40490.
@@ -134,6 +134,10 @@ impl Stdin {
     pub fn new() -> io::Result<Stdin> {
         Ok(Stdin { surrogate: 0 })
     }
+
+    pub fn is_terminal(&self) -> bool {
+        get_handle(c::STD_INPUT_HANDLE).map_or(false, |handle| RawHandle::new(handle).is_console())
+    }
 }
 
 impl io::Read for Stdin {
@@ -258,6 +262,10 @@ impl Stdout {
     pub fn new() -> io::Result<Stdout> {
         Ok(Stdout)
     }
+
+    pub fn is_terminal(&self) -> bool {
+        get_handle(c::STD_OUTPUT_HANDLE).map_or(false, |handle| RawHandle::new(handle).is_console())
+    }
 }
 
 impl io::Write for Stdout {
@@ -274,6 +282,10 @@ impl Stderr {
     pub fn new() -> io::Result<Stderr> {
         Ok(Stderr)
     }
+
+    pub fn is_terminal(&self) -> bool {
+        get_handle(c::STD_ERROR_HANDLE).map_or(false, |handle| RawHandle::new(handle).is_console())
+    }
 }
 
 impl io::Write for Stderr {
@@ -59,6 +59,8 @@ pub mod mutex;
           target_arch = "wasm32",
           all(target_vendor = "fortanix", target_env = "sgx")))]
 pub mod os_str_bytes;
+#[cfg(not(windows))]
+pub mod path;
 pub mod poison;
 pub mod process;
 pub mod remutex;
@@ -0,0 +1,33 @@
+use crate::env;
+use crate::io;
+use crate::path::{Path, PathBuf};
+use crate::sys_common::os_str_bytes::OsStrExt;
+
+/// Makes a POSIX path absolute without changing its meaning.
+pub fn absolute(path: &Path) -> io::Result<PathBuf> {
+    // This mostly collects `Path::components`, except where that would
+    // conflict with pathname resolution as specified by POSIX.
+    let bytes = path.as_os_str().as_bytes();
+
+    // Skip a redundant leading `.` component.
+    let mut components = path.strip_prefix(".").unwrap_or(path).components();
+    let mut absolute = if path.has_root() {
+        // A path starting with exactly two slashes may be interpreted in an
+        // implementation-defined manner, so those are kept.
+        if bytes.starts_with(b"//") && !bytes.starts_with(b"///") {
+            components.next();
+            PathBuf::from("//")
+        } else {
+            PathBuf::new()
+        }
+    } else {
+        env::current_dir()?
+    };
+    absolute.extend(components);
+
+    // A trailing slash requires the path to resolve to a directory.
+    if bytes.ends_with(b"/") {
+        absolute.push("");
+    }
+    Ok(absolute)
+}
@@ -118,6 +118,21 @@ impl<T: 'static> fmt::Debug for LocalKey<T> {
 /// # fn main() {}
 /// ```
 ///
+/// If the initializer is wrapped in `const { ... }`, it is evaluated at
+/// compile time and the value is stored directly in a `#[thread_local]`
+/// static on platforms that support it. This avoids the lazy initialization
+/// check on every access, and no destructor is registered at all when the
+/// type does not need to be dropped:
+///
+/// ```
+/// #![feature(thread_local_const_init)]
+/// use std::cell::Cell;
+/// thread_local! {
+///     pub static COUNTER: Cell<u32> = const { Cell::new(0) };
+/// }
+/// # fn main() {}
+/// ```
+///
 /// See [LocalKey documentation][`std::thread::LocalKey`] for more
 /// information.
 ///
@@ -129,6 +144,17 @@ macro_rules! thread_local {
     // empty (base case for the recursion)
     () => {};
 
+    // process multiple declarations with a const initializer
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty = const { $init:expr }; $($rest:tt)*) => (
+        $crate::__thread_local_inner!($(#[$attr])* $vis $name, $t, const $init);
+        $crate::thread_local!($($rest)*);
+    );
+
+    // handle a single declaration with a const initializer
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty = const { $init:expr }) => (
+        $crate::__thread_local_inner!($(#[$attr])* $vis $name, $t, const $init);
+    );
+
     // process multiple declarations
     ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty = $init:expr; $($rest:tt)*) => (
         $crate::__thread_local_inner!($(#[$attr])* $vis $name, $t, $init);
@@ -147,6 +173,91 @@ macro_rules! thread_local {
 #[allow_internal_unstable(thread_local_internals, cfg_target_thread_local, thread_local)]
 #[allow_internal_unsafe]
 macro_rules! __thread_local_inner {
+    // used to generate the `LocalKey` value for const-initialized thread locals
+    (@key $t:ty, const $init:expr) => {
+        {
+            #[inline]
+            unsafe fn __getit() -> $crate::option::Option<&'static $t> {
+                const _REQUIRE_UNSTABLE: () =
+                    $crate::thread::require_unstable_const_init_thread_local();
+
+                // wasm without atomics maps directly to `static mut`, and
+                // dtors aren't implemented because thread dtors aren't really
+                // a thing on wasm right now.
+                #[cfg(all(target_arch = "wasm32", not(target_feature = "atomics")))]
+                {
+                    static mut VAL: $t = $init;
+                    Some(&VAL)
+                }
+
+                // If the platform has support for `#[thread_local]`, use it.
+                #[cfg(all(
+                    target_thread_local,
+                    not(all(target_arch = "wasm32", not(target_feature = "atomics"))),
+                ))]
+                {
+                    // If a dtor isn't needed we can do something "very raw"
+                    // and just hand out the value.
+                    if !$crate::mem::needs_drop::<$t>() {
+                        #[thread_local]
+                        static mut VAL: $t = $init;
+                        return Some(&VAL);
+                    }
+
+                    #[thread_local]
+                    static mut VAL: $t = $init;
+                    // 0 == dtor not registered
+                    // 1 == dtor registered, dtor not run
+                    // 2 == dtor registered and is running or has run
+                    #[thread_local]
+                    static mut STATE: u8 = 0;
+
+                    unsafe extern "C" fn destroy(ptr: *mut u8) {
+                        let ptr = ptr as *mut $t;
+                        debug_assert_eq!(STATE, 1);
+                        STATE = 2;
+                        $crate::ptr::drop_in_place(ptr);
+                    }
+
+                    match STATE {
+                        // we haven't registered a destructor yet, so do so now.
+                        0 => {
+                            $crate::thread::__FastLocalKeyInner::<$t>::register_dtor(
+                                &mut VAL as *mut $t as *mut u8,
+                                destroy,
+                            );
+                            STATE = 1;
+                            Some(&VAL)
+                        }
+                        // the destructor is registered and the value is valid.
+                        1 => Some(&VAL),
+                        // the destructor has already run, so we can't give access.
+                        _ => None,
+                    }
+                }
+
+                // On platforms without `#[thread_local]` we fall back to the
+                // same implementation as below for os thread locals.
+                #[cfg(all(
+                    not(target_thread_local),
+                    not(all(target_arch = "wasm32", not(target_feature = "atomics"))),
+                ))]
+                {
+                    #[inline]
+                    const fn __init() -> $t { $init }
+                    static __KEY: $crate::thread::__OsLocalKeyInner<$t> =
+                        $crate::thread::__OsLocalKeyInner::new();
+                    __KEY.get(__init)
+                }
+            }
+
+            unsafe {
+                $crate::thread::LocalKey::new(__getit)
+            }
+        }
+    };
+
+    // used to generate the `LocalKey` value for `thread_local!`
     (@key $t:ty, $init:expr) => {
         {
             #[inline]
@@ -180,12 +291,16 @@ macro_rules! __thread_local_inner {
             }
         }
     };
-    ($(#[$attr:meta])* $vis:vis $name:ident, $t:ty, $init:expr) => {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $t:ty, $($init:tt)*) => {
         $(#[$attr])* $vis const $name: $crate::thread::LocalKey<$t> =
-            $crate::__thread_local_inner!(@key $t, $init);
+            $crate::__thread_local_inner!(@key $t, $($init)*);
     }
 }
 
+#[doc(hidden)]
+#[unstable(feature = "thread_local_const_init", issue = "none")]
+pub const fn require_unstable_const_init_thread_local() {}
+
 /// An error returned by [`LocalKey::try_with`](struct.LocalKey.html#method.try_with).
 #[stable(feature = "thread_local_try_with", since = "1.26.0")]
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -404,6 +519,13 @@ pub mod fast {
             Key { inner: LazyKeyInner::new(), dtor_state: Cell::new(DtorState::Unregistered) }
         }
 
+        // note that this is just a publicly-callable function only for the
+        // const-initialized form of thread locals, basically a way to call the
+        // free `register_dtor` function defined elsewhere in libstd.
+        pub unsafe fn register_dtor(a: *mut u8, dtor: unsafe extern "C" fn(*mut u8)) {
+            register_dtor(a, dtor);
+        }
+
         pub unsafe fn get<F: FnOnce() -> T>(&self, init: F) -> Option<&'static T> {
             match self.inner.get() {
                 Some(val) => Some(val),
@@ -583,6 +705,28 @@ mod tests {
         });
     }
 
+    #[test]
+    fn const_smoke_no_dtor() {
+        thread_local!(static FOO: Cell<i32> = const { Cell::new(1) });
+
+        FOO.with(|f| {
+            assert_eq!(f.get(), 1);
+            f.set(2);
+        });
+        let (tx, rx) = channel();
+        let _t = thread::spawn(move || {
+            FOO.with(|f| {
+                assert_eq!(f.get(), 1);
+            });
+            tx.send(()).unwrap();
+        });
+        rx.recv().unwrap();
+
+        FOO.with(|f| {
+            assert_eq!(f.get(), 2);
+        });
+    }
+
     #[test]
     fn states() {
         struct Foo;
@@ -601,6 +745,24 @@ mod tests {
         .expect("thread panicked");
     }
 
+    #[test]
+    fn const_states() {
+        struct Foo;
+        impl Drop for Foo {
+            fn drop(&mut self) {
+                assert!(FOO.try_with(|_| ()).is_err());
+            }
+        }
+        thread_local!(static FOO: Foo = const { Foo });
+
+        thread::spawn(|| {
+            assert!(FOO.try_with(|_| ()).is_ok());
+        })
+        .join()
+        .ok()
+        .expect("thread panicked");
+    }
+
     #[test]
     fn smoke_dtor() {
         thread_local!(static FOO: UnsafeCell<Option<Foo>> = UnsafeCell::new(None));
@@ -615,6 +777,20 @@ mod tests {
         rx.recv().unwrap();
     }
 
+    #[test]
+    fn const_smoke_dtor() {
+        thread_local!(static FOO: UnsafeCell<Option<Foo>> = const { UnsafeCell::new(None) });
+
+        let (tx, rx) = channel();
+        let _t = thread::spawn(move || unsafe {
+            let mut tx = Some(tx);
+            FOO.with(|f| {
+                *f.get() = Some(Foo(tx.take().unwrap()));
+            });
+        });
+        rx.recv().unwrap();
+    }
+
     #[test]
     fn circular() {
         struct S1;
@@ -186,6 +186,10 @@ mod local;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::local::{AccessError, LocalKey};
 
+#[unstable(feature = "thread_local_const_init", issue = "none")]
+#[doc(hidden)]
+pub use self::local::require_unstable_const_init_thread_local;
+
 // The types used by the thread_local! macro to access TLS keys. Note that there
 // are two types, the "OS" type and the "fast" type. The OS thread local key
 // type is accessed via platform-specific API calls and is slow, while the fast
@@ -260,6 +264,9 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread in bytes
     stack_size: Option<usize>,
+    // The CPUs the spawned thread is allowed to run on
+    #[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+    affinity: Option<imp::linux::CpuSet>,
 }
 
 impl Builder {
@@ -283,7 +290,12 @@ impl Builder {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Builder {
-        Builder { name: None, stack_size: None }
+        Builder {
+            name: None,
+            stack_size: None,
+            #[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+            affinity: None,
+        }
     }
 
     /// Names the thread-to-be. Currently the name is used for identification
@@ -339,6 +351,13 @@ impl Builder {
         self
     }
 
+    // Used by `std::os::linux::thread::BuilderExt`.
+    #[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+    pub(crate) fn set_affinity(mut self, cpus: imp::linux::CpuSet) -> Builder {
+        self.affinity = Some(cpus);
+        self
+    }
+
     /// Spawns a new thread by taking ownership of the `Builder`, and returns an
     /// [`io::Result`] to its [`JoinHandle`].
     ///
@@ -455,7 +474,7 @@ impl Builder {
         F: Send + 'a,
         T: Send + 'a,
     {
-        let Builder { name, stack_size } = self;
+        let Builder { name, stack_size, .. } = self;
 
         let stack_size = stack_size.unwrap_or_else(thread::min_stack);
 
@@ -477,24 +496,30 @@ impl Builder {
             *their_packet.get() = Some(try_result);
         };
 
+        // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
+        // through FFI or otherwise used with low-level threading primitives that have no
+        // notion of or way to enforce lifetimes.
+        //
+        // As mentioned in the `Safety` section of this function's documentation, the caller of
+        // this function needs to guarantee that the passed-in lifetime is sufficiently long
+        // for the lifetime of the thread.
+        //
+        // Similarly, the `sys` implementation must guarantee that no references to the closure
+        // exist after the thread has terminated, which is signaled by `Thread::join`
+        // returning.
+        let main =
+            mem::transmute::<Box<dyn FnOnce() + 'a>, Box<dyn FnOnce() + 'static>>(Box::new(main));
+
+        #[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+        let native = match self.affinity {
+            Some(ref cpus) => imp::Thread::new_pinned(stack_size, cpus, main)?,
+            None => imp::Thread::new(stack_size, main)?,
+        };
+        #[cfg(not(all(target_os = "linux", not(target_env = "uclibc"))))]
+        let native = imp::Thread::new(stack_size, main)?;
+
         Ok(JoinHandle(JoinInner {
-            // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
-            // through FFI or otherwise used with low-level threading primitives that have no
-            // notion of or way to enforce lifetimes.
-            //
-            // As mentioned in the `Safety` section of this function's documentation, the caller of
-            // this function needs to guarantee that the passed-in lifetime is sufficiently long
-            // for the lifetime of the thread.
-            //
-            // Similarly, the `sys` implementation must guarantee that no references to the closure
-            // exist after the thread has terminated, which is signaled by `Thread::join`
-            // returning.
-            native: Some(imp::Thread::new(
-                stack_size,
-                mem::transmute::<Box<dyn FnOnce() + 'a>, Box<dyn FnOnce() + 'static>>(Box::new(
-                    main,
-                )),
-            )?),
+            native: Some(native),
             thread: my_thread,
             packet: Packet(my_packet),
         }))
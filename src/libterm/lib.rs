@@ -37,9 +37,12 @@
 )]
 #![deny(missing_docs)]
 #![cfg_attr(windows, feature(libc))]
+#![cfg_attr(windows, feature(is_terminal))]
 
 use std::io::prelude::*;
 use std::io::{self, Stderr, Stdout};
+#[cfg(windows)]
+use std::io::IsTerminal;
 
 pub use terminfo::TerminfoTerminal;
 #[cfg(windows)]
@@ -68,7 +71,13 @@ pub fn stdout() -> Option<Box<StdoutTerminal>> {
 pub fn stdout() -> Option<Box<StdoutTerminal>> {
     TerminfoTerminal::new(io::stdout())
         .map(|t| Box::new(t) as Box<StdoutTerminal>)
-        .or_else(|| WinConsole::new(io::stdout()).ok().map(|t| Box::new(t) as Box<StdoutTerminal>))
+        .or_else(|| {
+            let out = io::stdout();
+            if !out.is_terminal() {
+                return None;
+            }
+            WinConsole::new(out).ok().map(|t| Box::new(t) as Box<StdoutTerminal>)
+        })
 }
 
 #[cfg(not(windows))]
@@ -84,7 +93,13 @@ pub fn stderr() -> Option<Box<StderrTerminal>> {
 pub fn stderr() -> Option<Box<StderrTerminal>> {
     TerminfoTerminal::new(io::stderr())
         .map(|t| Box::new(t) as Box<StderrTerminal>)
-        .or_else(|| WinConsole::new(io::stderr()).ok().map(|t| Box::new(t) as Box<StderrTerminal>))
+        .or_else(|| {
+            let out = io::stderr();
+            if !out.is_terminal() {
+                return None;
+            }
+            WinConsole::new(out).ok().map(|t| Box::new(t) as Box<StderrTerminal>)
+        })
 }
 
 /// Terminal color definitions
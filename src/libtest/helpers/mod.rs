@@ -3,6 +3,5 @@
 
 pub mod concurrency;
 pub mod exit_code;
-pub mod isatty;
 pub mod metrics;
 pub mod sink;
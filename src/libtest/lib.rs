@@ -24,6 +24,7 @@
 #![feature(rustc_private)]
 #![feature(nll)]
 #![feature(bool_to_option)]
+#![feature(is_terminal)]
 #![feature(set_stdio)]
 #![feature(panic_unwind)]
 #![feature(staged_api)]
@@ -0,0 +1,25 @@
+// check-pass
+// edition:2018
+// Check that the associated type an `async fn` desugars into does not clash
+// with an associated type of the same name declared by the user.
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features, non_camel_case_types)]
+
+trait Service {
+    type call;
+
+    async fn call(&self) -> Self::call;
+}
+
+struct Unit;
+
+impl Service for Unit {
+    type call = u8;
+
+    async fn call(&self) -> u8 {
+        0
+    }
+}
+
+fn main() {}
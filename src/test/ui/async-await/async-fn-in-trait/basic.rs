@@ -0,0 +1,47 @@
+// check-pass
+// edition:2018
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+use std::future::Future;
+
+trait Service {
+    async fn call(&self, request: &str) -> usize;
+}
+
+struct Len;
+
+impl Service for Len {
+    async fn call(&self, request: &str) -> usize {
+        request.len()
+    }
+}
+
+struct Twice<S>(S);
+
+impl<S: Service> Service for Twice<S> {
+    async fn call(&self, request: &str) -> usize {
+        self.0.call(request).await + self.0.call(request).await
+    }
+}
+
+async fn call_all<S: Service>(service: &S, requests: &[&str]) -> usize {
+    let mut total = 0;
+    for request in requests {
+        total += service.call(request).await;
+    }
+    total
+}
+
+fn assert_future<F: Future<Output = usize>>(_: F) {}
+
+fn is_send<T: Send>(_: T) {}
+
+fn main() {
+    assert_future(Len.call("hello"));
+    assert_future(call_all(&Twice(Len), &["a", "bc"]));
+
+    // The future of a concrete implementation is known to be `Send`.
+    is_send(Len.call("hello"));
+}
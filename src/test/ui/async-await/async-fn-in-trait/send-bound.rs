@@ -0,0 +1,17 @@
+// edition:2018
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+trait Service {
+    async fn call(&self) -> u32;
+}
+
+fn is_send<T: Send>(_: T) {}
+
+fn spawn<S: Service>(service: &S) {
+    is_send(service.call());
+    //~^ ERROR cannot be sent between threads safely
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// edition:2018
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+trait Service {
+    async fn with_default(&self) -> u32 { 0 }
+    //~^ ERROR `async fn` in traits cannot have a default body
+
+    async fn generic<T>(&self, value: T) -> T;
+    //~^ ERROR `async fn` in traits cannot have type or const parameters
+}
+
+fn main() {}
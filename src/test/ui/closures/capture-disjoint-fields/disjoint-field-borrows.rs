@@ -0,0 +1,51 @@
+// run-pass
+
+// Closures that borrow disjoint fields of a variable don't conflict with each
+// other, nor with uses of the other fields outside the closure.
+
+#![feature(capture_disjoint_fields)]
+#![allow(incomplete_features)]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+struct Wrapper {
+    point: Point,
+    name: String,
+}
+
+fn bump(w: &mut Wrapper) {
+    let mut c = || w.point.x += 1;
+    let name = &w.name;
+    c();
+    assert_eq!(name, "w");
+}
+
+fn main() {
+    let mut p = Point { x: 10, y: 10 };
+
+    let mut c = || p.x += 10;
+    let py = &mut p.y;
+    c();
+    *py = 20;
+    assert_eq!((p.x, p.y), (20, 20));
+
+    let mut t = (String::from("a"), 1);
+    let mut inc = || t.1 += 1;
+    let print = || t.0.len();
+    inc();
+    assert_eq!(print(), 1);
+    assert_eq!(t.1, 2);
+
+    let mut w = Wrapper { point: Point { x: 0, y: 0 }, name: String::from("w") };
+    bump(&mut w);
+    let mut outer = || {
+        let mut inner = || w.point.y += 1;
+        inner();
+    };
+    let x = &w.point.x;
+    outer();
+    assert_eq!((*x, w.point.y), (1, 1));
+}
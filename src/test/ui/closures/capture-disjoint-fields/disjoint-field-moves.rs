@@ -0,0 +1,36 @@
+// run-pass
+
+// A `move` closure only moves the fields it uses out of a variable, leaving
+// the other fields usable.
+
+#![feature(capture_disjoint_fields)]
+#![allow(incomplete_features)]
+
+struct Person {
+    name: String,
+    age: u32,
+    email: String,
+}
+
+fn main() {
+    let p = Person {
+        name: String::from("Ferris"),
+        age: 10,
+        email: String::from("ferris@example.com"),
+    };
+
+    let c = move || p.name.len();
+    assert_eq!(p.age, 10);
+    assert_eq!(p.email, "ferris@example.com");
+    assert_eq!(c(), 6);
+
+    let t = (String::from("moved"), String::from("kept"));
+    let consume = move || t.0;
+    assert_eq!(t.1, "kept");
+    assert_eq!(consume(), "moved");
+
+    let b = Box::new((String::from("boxed"), 1));
+    // Moving out of a `Box` captures the whole box.
+    let from_box = move || b.0;
+    assert_eq!(from_box(), "boxed");
+}
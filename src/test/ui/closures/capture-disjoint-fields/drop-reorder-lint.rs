@@ -0,0 +1,46 @@
+// Capturing only part of a variable by value changes when the rest of it is
+// dropped, which `disjoint_capture_drop_reorder` points out.
+
+#![deny(disjoint_capture_drop_reorder)]
+
+struct Foo(i32);
+
+impl Drop for Foo {
+    fn drop(&mut self) {
+        println!("{} dropped", self.0);
+    }
+}
+
+fn moves_one_field() {
+    let t = (Foo(0), Foo(1));
+    let c = move || { let _t = t.0; };
+    //~^ ERROR drop order affected for closure because of `capture_disjoint_fields`
+    c();
+}
+
+fn moves_fields_of_two_variables() {
+    let t = (Foo(0), Foo(1));
+    let u = (Foo(2), Foo(3));
+    let c = move || { let _t = t.0; let _u = u.1; };
+    //~^ ERROR drop order affected for closure because of `capture_disjoint_fields`
+    c();
+}
+
+fn moves_whole_variable() {
+    let t = (Foo(0), Foo(1));
+    let c = move || { let _t = t; };
+    c();
+}
+
+fn borrows_one_field() {
+    let t = (Foo(0), Foo(1));
+    let c = || { let _t = &t.0; };
+    c();
+}
+
+fn main() {
+    moves_one_field();
+    moves_fields_of_two_variables();
+    moves_whole_variable();
+    borrows_one_field();
+}
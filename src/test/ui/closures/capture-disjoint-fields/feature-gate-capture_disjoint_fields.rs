@@ -0,0 +1,16 @@
+// Without `capture_disjoint_fields`, a closure captures the whole variable even
+// when only a field of it is used.
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let mut p = Point { x: 10, y: 10 };
+
+    let mut c = || p.x += 10;
+    let py = &mut p.y; //~ ERROR cannot borrow `p.y` as mutable more than once at a time
+    c();
+    *py = 20;
+}
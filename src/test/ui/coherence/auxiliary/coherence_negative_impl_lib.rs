@@ -0,0 +1,11 @@
+#![crate_type="lib"]
+#![feature(negative_impls, rustc_attrs)]
+
+#[rustc_strict_coherence]
+pub trait Strict {}
+
+impl !Strict for i16 {}
+
+pub trait Plain {}
+
+impl !Plain for i16 {}
@@ -0,0 +1,27 @@
+// Tests that coherence relies on the negative impls of an upstream trait only
+// if the trait opts in with `#[rustc_strict_coherence]`.
+
+// aux-build:coherence_negative_impl_lib.rs
+
+extern crate coherence_negative_impl_lib;
+
+use coherence_negative_impl_lib::{Plain, Strict};
+
+trait Foo {}
+impl<T> Foo for T where T: Strict {}
+impl Foo for i16 {}
+impl Foo for i32 {}
+//~^ ERROR E0119
+
+trait Bar {}
+impl<T> Bar for T where T: Plain {}
+impl Bar for i16 {}
+//~^ ERROR E0119
+
+// `impl !Clone for &mut T` in libcore is not a promise either.
+trait Baz {}
+impl<T: Clone> Baz for T {}
+impl Baz for &mut u8 {}
+//~^ ERROR E0119
+
+fn main() {}
@@ -0,0 +1,24 @@
+// run-pass
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn test<const N: usize>() -> [u8; N - 1]
+where
+    [u8; N - 1]: Default,
+{
+    Default::default()
+}
+
+fn with_bound<const N: usize>() -> usize
+where
+    [u8; N + 1]:,
+{
+    let arr: [u8; N + 1] = [0; N + 1];
+    arr.len()
+}
+
+fn main() {
+    let x = test::<33>();
+    assert_eq!(x, [0; 32]);
+    assert_eq!(with_bound::<3>(), 4);
+}
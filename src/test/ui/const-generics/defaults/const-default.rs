@@ -0,0 +1,23 @@
+// run-pass
+#![allow(incomplete_features)]
+#![feature(const_generics, const_generics_defaults)]
+
+pub struct ConstDefault<const N: usize = 3>;
+
+impl<const N: usize> ConstDefault<N> {
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+pub struct Mixed<T, const N: usize = 4> {
+    inner: [T; N],
+}
+
+fn main() {
+    let s: ConstDefault = ConstDefault;
+    assert_eq!(s.len(), 3);
+
+    let m: Mixed<u8> = Mixed { inner: [0; 4] };
+    assert_eq!(m.inner.len(), 4);
+}
@@ -0,0 +1,7 @@
+#![allow(incomplete_features)]
+#![feature(const_generics, const_generics_defaults)]
+
+pub fn foo<const N: usize = 3>() {}
+//~^ ERROR defaults for const parameters are only allowed in
+
+fn main() {}
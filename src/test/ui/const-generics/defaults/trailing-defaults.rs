@@ -0,0 +1,7 @@
+#![allow(incomplete_features)]
+#![feature(const_generics, const_generics_defaults)]
+
+pub struct A<const N: usize = 3, const M: usize>;
+//~^ ERROR const parameters with a default must be trailing
+
+fn main() {}
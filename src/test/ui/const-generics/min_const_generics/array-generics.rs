@@ -0,0 +1,33 @@
+// run-pass
+#![feature(min_const_generics)]
+
+struct Wrapper<T, const N: usize> {
+    inner: [T; N],
+}
+
+impl<T: Copy + Default, const N: usize> Wrapper<T, N> {
+    fn new() -> Self {
+        Wrapper { inner: [T::default(); N] }
+    }
+
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+fn first<T: Copy, const N: usize>(arr: [T; N]) -> Option<T> {
+    arr.iter().copied().next()
+}
+
+fn flag<const B: bool, const C: char>() -> (bool, char) {
+    (B, C)
+}
+
+fn main() {
+    let w: Wrapper<u8, 4> = Wrapper::new();
+    assert_eq!(w.len(), 4);
+    assert_eq!(w.inner, [0; 4]);
+    assert_eq!(first([3, 4, 5]), Some(3));
+    assert_eq!(first::<u8, 0>([]), None);
+    assert_eq!(flag::<true, 'x'>(), (true, 'x'));
+}
@@ -0,0 +1,26 @@
+#![feature(min_const_generics)]
+
+pub fn test<const N: usize>() {}
+
+pub fn ok<const N: usize>() -> [u8; { N }] {
+    test::<N>();
+    test::<{ N }>();
+    todo!()
+}
+
+pub fn break1<const N: usize>() -> [u8; N + 1] {
+    //~^ ERROR generic parameters must not be used inside of non trivial constant values
+    todo!()
+}
+
+pub fn break2<const N: usize>() {
+    test::<{ N + 1 }>();
+    //~^ ERROR generic parameters must not be used inside of non trivial constant values
+}
+
+pub fn break3<T>() -> [u8; std::mem::size_of::<T>()] {
+    //~^ ERROR generic parameters must not be used inside of non trivial constant values
+    todo!()
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#![feature(min_const_generics)]
+
+pub struct Foo<const N: [u8; 0]>;
+//~^ ERROR `[u8; 0]` is forbidden
+
+pub struct Bar<const N: ()>;
+//~^ ERROR `()` is forbidden
+
+#[derive(PartialEq, Eq)]
+pub struct No;
+
+pub struct Fez<const N: No>;
+//~^ ERROR `No` is forbidden
+
+pub struct Faz<const N: &'static u8>;
+//~^ ERROR `&'static u8` is forbidden
+
+fn main() {}
@@ -0,0 +1,20 @@
+// run-pass
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_raw_ptr_deref)]
+#![feature(const_mut_refs)]
+use std::intrinsics;
+
+const FOO: i32 = foo();
+
+const fn foo() -> i32 {
+    unsafe {
+        let x = intrinsics::const_allocate(4, 4) as *mut i32;
+        *x = 20;
+        *x + 1
+    }
+}
+
+fn main() {
+    assert_eq!(FOO, 21);
+}
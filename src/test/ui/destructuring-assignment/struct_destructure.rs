@@ -0,0 +1,33 @@
+// run-pass
+
+#![feature(destructuring_assignment)]
+
+struct Struct<S, T> {
+    a: S,
+    b: T,
+}
+
+struct TupleStruct<S, T>(S, T);
+
+enum Enum<S, T> {
+    SingleVariant(S, T),
+}
+
+fn main() {
+    let (mut a, mut b);
+    Struct { a, b } = Struct { a: 0, b: 1 };
+    assert_eq!((a, b), (0, 1));
+    Struct { a: b, b: a } = Struct { a: 1, b: 2 };
+    assert_eq!((a, b), (2, 1));
+    Struct { a, .. } = Struct { a: 3, b: 4 };
+    assert_eq!((a, b), (3, 1));
+    Struct { a: _, b } = Struct { a: 5, b: 6 };
+    assert_eq!((a, b), (3, 6));
+
+    TupleStruct(a, b) = TupleStruct(7, 8);
+    assert_eq!((a, b), (7, 8));
+    TupleStruct(b, ..) = TupleStruct(9, 10);
+    assert_eq!((a, b), (7, 9));
+    Enum::SingleVariant(a, b) = Enum::SingleVariant(11, 12);
+    assert_eq!((a, b), (11, 12));
+}
@@ -0,0 +1,13 @@
+#![feature(destructuring_assignment)]
+struct Struct {
+    a: u32,
+    b: u32,
+}
+
+fn main() {
+    let mut a;
+    let d = Struct { a: 0, b: 1 };
+    Struct { a, ..d } = Struct { a: 1, b: 2 };
+    //~^ ERROR functional record updates are not allowed in destructuring assignments
+    Struct { a, .. }; //~ ERROR base expression required after `..`
+}
@@ -0,0 +1,7 @@
+// edition:2018
+
+trait Service {
+    async fn call(&self) -> u32; //~ ERROR functions in traits cannot be declared `async`
+}
+
+fn main() {}
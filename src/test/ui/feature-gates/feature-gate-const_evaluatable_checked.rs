@@ -0,0 +1,13 @@
+#![feature(const_generics)]
+#![allow(incomplete_features)]
+
+pub fn test<const N: usize>()
+where
+    [u8; N + 1]:,
+    //~^ ERROR constant expression depends on a generic parameter
+{
+    let _: [u8; N + 1];
+    //~^ ERROR constant expression depends on a generic parameter
+}
+
+fn main() {}
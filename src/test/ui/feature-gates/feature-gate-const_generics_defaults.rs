@@ -0,0 +1,7 @@
+#![allow(incomplete_features)]
+#![feature(const_generics)]
+
+struct A<const N: usize = 3>;
+//~^ ERROR const parameter defaults are experimental
+
+fn main() {}
@@ -0,0 +1,4 @@
+fn main() {
+    let (mut a, mut b) = (0, 1);
+    (a, b) = (2, 3); //~ ERROR destructuring assignments are unstable
+}
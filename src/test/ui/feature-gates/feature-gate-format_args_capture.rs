@@ -0,0 +1,4 @@
+fn main() {
+    let x = 1;
+    format!("{x}"); //~ ERROR there is no argument named `x`
+}
@@ -0,0 +1,6 @@
+fn main() {
+    match Some(1) {
+        Some(x) if let 1 = x => {} //~ ERROR `if let` guards are experimental
+        _ => {}
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = const { 1 + 2 }; //~ ERROR inline-const is experimental
+}
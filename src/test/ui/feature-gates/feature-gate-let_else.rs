@@ -0,0 +1,4 @@
+fn main() {
+    let Some(x) = Some(1) else { return }; //~ ERROR `let...else` statements are unstable
+    let _ = x;
+}
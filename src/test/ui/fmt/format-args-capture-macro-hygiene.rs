@@ -0,0 +1,6 @@
+#![feature(format_args_capture)]
+
+fn main() {
+    format!(concat!("{foo}")); //~ ERROR there is no argument named `foo`
+    format!(concat!("{ba", "r} {}"), 1); //~ ERROR there is no argument named `bar`
+}
@@ -0,0 +1,16 @@
+#![feature(format_args_capture)]
+
+fn main() {
+    format!("{} {foo} {} {bar} {}", 1, 2, 3);
+    //~^ ERROR cannot find value `foo` in this scope
+    //~| ERROR cannot find value `bar` in this scope
+
+    format!("{foo}"); //~ ERROR cannot find value `foo` in this scope
+
+    format!(r##"
+
+        {foo}
+
+    "##);
+    //~^^^ ERROR cannot find value `foo` in this scope
+}
@@ -0,0 +1,22 @@
+// run-pass
+
+#![feature(format_args_capture)]
+
+fn main() {
+    let user = "alice";
+    let addr = "127.0.0.1";
+    assert_eq!(format!("{user} logged in from {addr}"), "alice logged in from 127.0.0.1");
+
+    // Explicit named arguments take precedence over captured variables.
+    assert_eq!(format!("{user}", user = "bob"), "bob");
+
+    // A captured variable can be used several times, with different formatting traits.
+    let x = 255;
+    assert_eq!(format!("{x} {x:?} {x:x} {x:#X}"), "255 255 ff 0xFF");
+
+    // Width and precision can be captured as well.
+    let width = 5;
+    let precision = 2;
+    let y = 1.5;
+    assert_eq!(format!("{y:width$.precision$}"), " 1.50");
+}
@@ -0,0 +1,16 @@
+// run-pass
+
+#![allow(incomplete_features)]
+#![feature(inline_const)]
+
+use std::cell::Cell;
+
+fn main() {
+    let a: [Cell<i32>; 4] = [const { Cell::new(0) }; 4];
+    a[1].set(3);
+    assert_eq!(a[0].get(), 0);
+    assert_eq!(a[1].get(), 3);
+
+    let v: [Vec<u8>; 3] = [const { Vec::new() }; 3];
+    assert!(v.iter().all(|v| v.is_empty()));
+}
@@ -0,0 +1,15 @@
+// run-pass
+
+#![allow(incomplete_features)]
+#![feature(inline_const)]
+
+use std::mem;
+
+fn size_of<T>() -> usize {
+    const { mem::size_of::<T>() }
+}
+
+fn main() {
+    assert_eq!(size_of::<u8>(), 1);
+    assert_eq!(size_of::<u64>(), 8);
+}
@@ -0,0 +1,23 @@
+// run-pass
+
+#![allow(incomplete_features)]
+#![feature(inline_const)]
+
+const MMIO_BIT1: u8 = 4;
+const MMIO_BIT2: u8 = 5;
+
+fn classify(x: u8) -> &'static str {
+    match x {
+        const { 1 << MMIO_BIT1 } => "bit1",
+        const { 1 << MMIO_BIT2 } => "bit2",
+        0 ..= const { 1 << MMIO_BIT1 } => "low",
+        _ => "other",
+    }
+}
+
+fn main() {
+    assert_eq!(classify(16), "bit1");
+    assert_eq!(classify(32), "bit2");
+    assert_eq!(classify(3), "low");
+    assert_eq!(classify(200), "other");
+}
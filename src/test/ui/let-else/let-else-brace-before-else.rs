@@ -0,0 +1,10 @@
+#![feature(let_else)]
+
+fn main() {
+    let Some(1) = { Some(1) } else { //~ ERROR right curly brace `}` before `else`
+        return;
+    };
+    let true = true && false else { //~ ERROR a `&&` expression cannot be directly assigned
+        return;
+    };
+}
@@ -0,0 +1,7 @@
+// check-pass
+
+#![feature(let_else)]
+
+fn main() {
+    let _x = 1 else { return }; //~ WARN irrefutable let-else pattern
+}
@@ -0,0 +1,71 @@
+// run-pass
+
+#![feature(let_else)]
+
+use std::cell::RefCell;
+
+struct Droppy<'a> {
+    name: &'static str,
+    log: &'a RefCell<Vec<&'static str>>,
+}
+
+impl Droppy<'_> {
+    fn get(&self, some: bool) -> Option<u32> {
+        if some { Some(1) } else { None }
+    }
+}
+
+impl Drop for Droppy<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+fn temporaries_drop_before_tail() {
+    let log = RefCell::new(vec![]);
+    {
+        let Some(x) = Droppy { name: "temp", log: &log }.get(true) else { unreachable!() };
+        log.borrow_mut().push("tail");
+        assert_eq!(x, 1);
+    }
+    assert_eq!(*log.borrow(), ["temp", "tail"]);
+}
+
+fn temporaries_drop_before_else(log: &RefCell<Vec<&'static str>>) {
+    let Some(_) = Droppy { name: "temp", log }.get(false) else {
+        log.borrow_mut().push("else");
+        return;
+    };
+    unreachable!();
+}
+
+fn borrowed_temporaries_live_for_tail() {
+    let log = RefCell::new(vec![]);
+    {
+        let Some(ref droppy) = Some(Droppy { name: "borrowed", log: &log }) else {
+            unreachable!()
+        };
+        log.borrow_mut().push("tail");
+        assert_eq!(droppy.name, "borrowed");
+    }
+    assert_eq!(*log.borrow(), ["tail", "borrowed"]);
+}
+
+fn annotation_is_a_coercion_site() {
+    let [a, ..]: &[u8] = &[1u8, 2] else { unreachable!() };
+    assert_eq!(*a, 1);
+
+    let [first, ..]: &[String] = &[String::from("first")] else { unreachable!() };
+    assert_eq!(first, "first");
+}
+
+fn main() {
+    temporaries_drop_before_tail();
+
+    let log = RefCell::new(vec![]);
+    temporaries_drop_before_else(&log);
+    assert_eq!(*log.borrow(), ["temp", "else"]);
+
+    borrowed_temporaries_live_for_tail();
+    annotation_is_a_coercion_site();
+}
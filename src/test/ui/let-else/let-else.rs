@@ -0,0 +1,33 @@
+// run-pass
+
+#![feature(let_else)]
+
+fn sum_even(xs: &[u32]) -> u32 {
+    let mut sum = 0;
+    for &x in xs {
+        let 0 = x % 2 else { continue };
+        sum += x;
+    }
+    sum
+}
+
+fn parse(s: &str) -> Result<u32, String> {
+    let Ok(n) = s.parse::<u32>() else {
+        return Err(format!("invalid number: {:?}", s));
+    };
+    Ok(n * 2)
+}
+
+fn first_pair(v: &[(u8, char)]) -> Option<char> {
+    let [(1, c), ..]: &[(u8, char)] = v else { return None };
+    Some(c)
+}
+
+fn main() {
+    assert_eq!(sum_even(&[1, 2, 3, 4]), 6);
+    assert_eq!(parse("21"), Ok(42));
+    assert_eq!(parse("x"), Err("invalid number: \"x\"".to_string()));
+    assert_eq!(first_pair(&[(1, 'a'), (2, 'b')]), Some('a'));
+    assert_eq!(first_pair(&[(2, 'b')]), None);
+    assert_eq!(first_pair(&[]), None);
+}
@@ -0,0 +1,8 @@
+#![feature(if_let_guard)]
+
+fn main() {
+    let _ = match Some(Some(1)) {
+        Some(x) if let Some(y) = x => y,
+        _ => y, //~ ERROR cannot find value `y`
+    };
+}
@@ -0,0 +1,57 @@
+// run-pass
+// Check that the temporaries of an `if let` guard, and the parts of its
+// scrutinee that are not moved into bindings, are dropped at the end of the
+// guard: before the arm body when it succeeds, and before the next arm when
+// it fails.
+
+#![feature(if_let_guard)]
+
+use std::cell::RefCell;
+
+struct LogDrop<'a>(&'static str, &'a RefCell<Vec<&'static str>>);
+
+impl LogDrop<'_> {
+    fn pass<T>(&self, value: T) -> T {
+        value
+    }
+}
+
+impl Drop for LogDrop<'_> {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+fn main() {
+    let log = RefCell::new(Vec::new());
+    let d = |name| LogDrop(name, &log);
+    let push = |name| log.borrow_mut().push(name);
+
+    match 1 {
+        1 if let Some(b) = d("temp").pass(Some(d("binding"))) => {
+            push("body");
+            drop(b);
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(*log.borrow(), ["temp", "body", "binding"]);
+    log.borrow_mut().clear();
+
+    match 2 {
+        2 if let (Some(b), _) = (Some(d("binding")), d("unbound")) => {
+            push("body");
+            drop(b);
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(*log.borrow(), ["unbound", "body", "binding"]);
+    log.borrow_mut().clear();
+
+    match 3 {
+        3 if let Some(_) = d("failed").pass(None::<u8>) => unreachable!(),
+        3 if let (Some(_), true) = (Some(d("unmatched")), false) => unreachable!(),
+        3 if { push("next guard"); true } => push("body"),
+        _ => unreachable!(),
+    }
+    assert_eq!(*log.borrow(), ["failed", "unmatched", "next guard", "body"]);
+}
@@ -0,0 +1,22 @@
+#![feature(if_let_guard)]
+
+fn move_in_guard(x: Option<String>) -> usize {
+    match x {
+        Some(s) if let Some(t) = Some(s) => t.len(),
+        //~^ ERROR cannot move out of `s` in pattern guard
+        _ => 0,
+    }
+}
+
+fn mut_borrow_in_guard(x: Option<Vec<u8>>) -> u8 {
+    match x {
+        Some(mut v) if let Some(b) = v.pop() => b,
+        //~^ ERROR cannot borrow `v` as mutable, as it is immutable for the pattern guard
+        _ => 0,
+    }
+}
+
+fn main() {
+    move_in_guard(None);
+    mut_borrow_in_guard(None);
+}
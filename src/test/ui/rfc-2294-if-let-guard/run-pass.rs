@@ -0,0 +1,39 @@
+// run-pass
+
+#![feature(if_let_guard)]
+
+enum Command {
+    Set(String),
+    Clear,
+}
+
+fn parse(c: &Command) -> Option<u32> {
+    match c {
+        Command::Set(s) if let Ok(n) = s.parse::<u32>() => Some(n),
+        Command::Set(_) => None,
+        Command::Clear => Some(0),
+    }
+}
+
+fn first_even(v: &[u32]) -> Option<u32> {
+    match v {
+        [first, ..] if let 0 = first % 2 => Some(*first),
+        [_, rest @ ..] if let Some(x) = first_even(rest) => Some(x),
+        _ => None,
+    }
+}
+
+fn main() {
+    assert_eq!(parse(&Command::Set("42".to_string())), Some(42));
+    assert_eq!(parse(&Command::Set("x".to_string())), None);
+    assert_eq!(parse(&Command::Clear), Some(0));
+    assert_eq!(first_even(&[1, 3, 4, 5]), Some(4));
+    assert_eq!(first_even(&[1, 3]), None);
+
+    // The guard is lowered once for each alternative of an or-pattern.
+    let x: Result<u8, u8> = Err(3);
+    match x {
+        Ok(n) | Err(n) if let Some(m) = n.checked_mul(2) => assert_eq!(m, 6),
+        _ => unreachable!(),
+    }
+}
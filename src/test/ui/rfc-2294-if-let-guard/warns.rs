@@ -0,0 +1,10 @@
+#![feature(if_let_guard)]
+#![deny(irrefutable_let_patterns)]
+
+fn main() {
+    match Some(()) {
+        Some(x) if let () = x => {}
+        //~^ ERROR irrefutable if-let guard
+        _ => {}
+    }
+}
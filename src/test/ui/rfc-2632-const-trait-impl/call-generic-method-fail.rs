@@ -0,0 +1,23 @@
+#![allow(incomplete_features)]
+#![feature(const_trait_impl)]
+#![feature(const_fn)]
+
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+pub struct W<T>(pub T);
+
+// Bounds on the impl are not `const`, even if the method is.
+impl<T: Zero> W<T> {
+    pub const fn zero() -> T {
+        T::zero()
+        //~^ ERROR calls in constant functions are limited to constant functions
+    }
+}
+
+pub const fn zero<T: Zero>() -> T {
+    T::zero() // ok
+}
+
+fn main() {}
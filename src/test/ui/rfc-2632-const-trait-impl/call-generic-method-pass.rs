@@ -0,0 +1,38 @@
+// run-pass
+
+#![allow(incomplete_features)]
+#![feature(const_trait_impl)]
+
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+struct S(i32);
+
+impl const Zero for S {
+    fn zero() -> Self {
+        S(0)
+    }
+}
+
+impl const PartialEq for S {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0 == rhs.0
+    }
+}
+
+const fn zero<T: Zero>() -> T {
+    T::zero()
+}
+
+const fn equals_self<T: PartialEq>(t: &T) -> bool {
+    *t == *t
+}
+
+const ZERO: S = zero();
+const EQ: bool = equals_self(&S(1));
+
+fn main() {
+    assert_eq!(ZERO.0, 0);
+    assert!(EQ);
+}
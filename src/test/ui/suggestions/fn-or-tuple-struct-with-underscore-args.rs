@@ -8,12 +8,12 @@ trait T {
 
 fn main() {
     let _: usize = foo(_, _);
-    //~^ ERROR expected expression
-    //~| ERROR expected expression
+    //~^ ERROR in expressions, `_` can only be used
+    //~| ERROR in expressions, `_` can only be used
     let _: S = S(_, _);
-    //~^ ERROR expected expression
-    //~| ERROR expected expression
+    //~^ ERROR in expressions, `_` can only be used
+    //~| ERROR in expressions, `_` can only be used
     let _: usize = T::baz(_, _);
-    //~^ ERROR expected expression
-    //~| ERROR expected expression
+    //~^ ERROR in expressions, `_` can only be used
+    //~| ERROR in expressions, `_` can only be used
 }
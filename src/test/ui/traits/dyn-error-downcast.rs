@@ -0,0 +1,31 @@
+// run-pass
+// Check that the downcasting methods of boxed errors can be named through
+// the trait object type, not only called on a `Box`.
+
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+struct MyError;
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("my error")
+    }
+}
+
+impl Error for MyError {}
+
+fn main() {
+    let b: Box<dyn Error> = Box::new(MyError);
+    assert_eq!(*<dyn Error>::downcast::<MyError>(b).unwrap(), MyError);
+
+    let b: Box<dyn Error + Send> = Box::new(MyError);
+    assert_eq!(*<dyn Error + Send>::downcast::<MyError>(b).unwrap(), MyError);
+
+    let b: Box<dyn Error + Send + Sync> = Box::new(MyError);
+    assert!(<dyn Error + Send + Sync>::downcast::<fmt::Error>(b).is_err());
+
+    let b: Box<dyn Error + Send + Sync> = Box::new(MyError);
+    assert_eq!(*b.downcast::<MyError>().unwrap(), MyError);
+}
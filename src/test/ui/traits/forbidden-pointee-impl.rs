@@ -0,0 +1,12 @@
+#![feature(ptr_metadata)]
+
+use std::ptr::Pointee;
+
+struct NewType;
+
+impl Pointee for NewType {
+    //~^ ERROR explicit impls for the `Pointee` trait are not permitted
+    type Metadata = usize;
+}
+
+fn main() {}
@@ -143,7 +143,7 @@ impl<'a, 'tcx> Delegate<'tcx> for EscapeDelegate<'a, 'tcx> {
                     return;
                 }
 
-                if is_non_trait_box(cmt.ty) && !self.is_large_box(cmt.ty) {
+                if is_non_trait_box(cmt.ty()) && !self.is_large_box(cmt.ty()) {
                     self.set.insert(cmt.hir_id);
                 }
                 return;
@@ -106,8 +106,9 @@ impl<'a, 'tcx> DivergenceVisitor<'a, 'tcx> {
             ExprKind::Match(ref e, arms, _) => {
                 self.visit_expr(e);
                 for arm in arms {
-                    if let Some(Guard::If(if_expr)) = arm.guard {
-                        self.visit_expr(if_expr)
+                    match arm.guard {
+                        Some(Guard::If(if_expr)) | Some(Guard::IfLet(_, if_expr)) => self.visit_expr(if_expr),
+                        None => {},
                     }
                     // make sure top level arm expressions aren't linted
                     self.maybe_walk_expr(&*arm.body);
@@ -362,6 +362,18 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                                 self.current = if_expr_pat;
                                 self.visit_expr(if_expr);
                             },
+                            hir::Guard::IfLet(ref if_let_pat, ref if_let_expr) => {
+                                let if_let_pat_pat = self.next("pat");
+                                let if_let_expr_pat = self.next("expr");
+                                println!(
+                                    "    if let Guard::IfLet(ref {}, ref {}) = {};",
+                                    if_let_pat_pat, if_let_expr_pat, guard_pat
+                                );
+                                self.current = if_let_expr_pat;
+                                self.visit_expr(if_let_expr);
+                                self.current = if_let_pat_pat;
+                                self.visit_pat(if_let_pat);
+                            },
                         }
                     }
                     self.current = format!("{}[{}].pat", arms_pat, i);
@@ -497,6 +509,10 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 println!("    // unimplemented: field checks");
             },
             // FIXME: compute length (needs type info)
+            ExprKind::ConstBlock(_) => {
+                println!("ConstBlock(_) = {};", current);
+                println!("// unimplemented: const block check");
+            },
             ExprKind::Repeat(ref value, _) => {
                 let value_pat = self.next("value");
                 println!("Repeat(ref {}, _) = {};", value_pat, current);
@@ -720,6 +736,7 @@ fn desugaring_name(des: hir::MatchSource) -> String {
             contains_else_clause
         ),
         hir::MatchSource::AwaitDesugar => "MatchSource::AwaitDesugar".to_string(),
+        hir::MatchSource::LetElseDesugar => "MatchSource::LetElseDesugar".to_string(),
     }
 }
 
@@ -72,7 +72,7 @@ const EXCEPTION_PATHS: &[&str] = &[
     // pointer regardless of the target architecture. As a result,
     // we must use `#[cfg(windows)]` to conditionally compile the
     // correct `VaList` structure for windows.
-    "src/libcore/ffi.rs",
+    "src/libcore/ffi/mod.rs",
     // non-std crates
     "src/test",
     "src/tools",